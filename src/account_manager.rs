@@ -0,0 +1,337 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{
+    api_exchange::{self, OrderSide, OrderType},
+    api_withdraw,
+    constant::TwoFactorType,
+    credential::{self, CredentialProvider},
+    response::{
+        AccountsInfo, DepositRecord, OrderInfo, ResponseError, TwoFactorEvent, TwoFactorTracker,
+        TwoFactorTransfer, WithdrawChance, WithdrawalRecord,
+    },
+};
+
+/// Group of exchange APIs sharing a request limit per account
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitGroup {
+    /// Placing and canceling orders, 8 requests per second
+    Order,
+    /// Every other exchange API, 30 requests per second
+    Default,
+}
+
+impl RateLimitGroup {
+    fn requests_per_second(&self) -> usize {
+        match self {
+            Self::Order => 8,
+            Self::Default => 30,
+        }
+    }
+}
+
+/// Sliding window limiter holding a bucket per [RateLimitGroup]
+struct RateLimiter {
+    window: Duration,
+    buckets: Mutex<HashMap<RateLimitGroup, VecDeque<Instant>>>,
+}
+
+impl RateLimiter {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait until a request of `group` can be sent and take its slot
+    async fn acquire(&self, group: RateLimitGroup) {
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap();
+                let bucket = buckets.entry(group).or_default();
+                let now = Instant::now();
+
+                while bucket
+                    .front()
+                    .is_some_and(|sent_at| now.duration_since(*sent_at) >= self.window)
+                {
+                    bucket.pop_front();
+                }
+
+                if bucket.len() < group.requests_per_second() {
+                    bucket.push_back(now);
+                    return;
+                }
+
+                self.window - now.duration_since(bucket[0])
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Upbit account signing its requests with its own credentials and rate limit buckets
+pub struct Account {
+    name: String,
+    provider: Arc<dyn CredentialProvider>,
+    limiter: RateLimiter,
+}
+
+impl Account {
+    pub fn new(name: &str, provider: impl CredentialProvider + 'static) -> Self {
+        Self {
+            name: name.to_owned(),
+            provider: Arc::new(provider),
+            limiter: RateLimiter::new(Duration::from_secs(1)),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Run `future` as this account, after waiting for a slot in the bucket of `group`
+    ///
+    /// Every API called in `future` is signed with the credentials of this account.
+    pub async fn run<T, F>(&self, group: RateLimitGroup, future: F) -> Result<T, ResponseError>
+    where
+        F: Future<Output = Result<T, ResponseError>>,
+    {
+        self.limiter.acquire(group).await;
+
+        let credentials = self.provider.credentials()?;
+
+        credential::scope(credentials, future).await
+    }
+
+    /// [api_exchange::get_account_info] of this account
+    pub async fn get_account_info(&self) -> Result<Vec<AccountsInfo>, ResponseError> {
+        self.run(RateLimitGroup::Default, api_exchange::get_account_info())
+            .await
+    }
+
+    /// [api_exchange::order_by_price] of this account
+    pub async fn order_by_price(
+        &self,
+        market_id: &str,
+        side: OrderSide,
+        price: f64,
+        price_desired: f64,
+        ord_type: OrderType,
+        identifier: Option<&str>,
+    ) -> Result<OrderInfo, ResponseError> {
+        self.run(
+            RateLimitGroup::Order,
+            api_exchange::order_by_price(
+                market_id,
+                side,
+                price,
+                price_desired,
+                ord_type,
+                identifier,
+            ),
+        )
+        .await
+    }
+
+    /// [api_withdraw::get_withdraw_chance] of this account
+    pub async fn get_withdraw_chance(
+        &self,
+        currency: &str,
+        net_type: &str,
+    ) -> Result<WithdrawChance, ResponseError> {
+        self.run(
+            RateLimitGroup::Default,
+            api_withdraw::get_withdraw_chance(currency, net_type),
+        )
+        .await
+    }
+
+    /// Move KRW to `to` through the bank account linked to both accounts
+    ///
+    /// KRW is withdrawn from this account, and deposited to `to` once the withdrawal is approved and done.
+    /// The returned deposit still waits for the two factor approval of `to`.
+    pub async fn transfer_krw(
+        &self,
+        to: &Account,
+        amount: f64,
+        two_factor_type: TwoFactorType,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<(WithdrawalRecord, DepositRecord), ResponseError> {
+        let withdrawal = self
+            .run(
+                RateLimitGroup::Default,
+                api_withdraw::withdraw_krw(amount, two_factor_type),
+            )
+            .await?;
+
+        let mut tracker = TwoFactorTracker::new(withdrawal, poll_interval, timeout);
+        let mut last_event = None;
+
+        while let Some(event) = tracker
+            .next_with(|uuid| async move {
+                self.run(RateLimitGroup::Default, WithdrawalRecord::fetch(&uuid))
+                    .await
+            })
+            .await?
+        {
+            last_event = Some(event);
+        }
+
+        let withdrawal = match last_event {
+            Some(TwoFactorEvent::Approved(withdrawal)) => withdrawal,
+            Some(TwoFactorEvent::TimedOut(withdrawal)) => {
+                return Err(crate::response::response_error_custom_timeout(format!(
+                    "KRW withdrawal {} of {} is not done in {timeout:?}",
+                    withdrawal.uuid, self.name
+                )))
+            }
+            event => {
+                return Err(crate::response::response_error_custom_withdrawal_rejected(
+                    format!("KRW withdrawal of {} is not done: {event:?}", self.name),
+                ))
+            }
+        };
+
+        let deposit = to
+            .run(
+                RateLimitGroup::Default,
+                crate::api_deposit::deposit_krw(amount, two_factor_type),
+            )
+            .await?;
+
+        Ok((withdrawal, deposit))
+    }
+}
+
+/// Balance of a currency summed across every account
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AggregatedBalance {
+    pub balance: f64,
+    pub locked: f64,
+    /// Balance and locked amount of each account holding the currency
+    pub accounts: BTreeMap<String, (f64, f64)>,
+}
+
+/// Registry of named [Account]s
+#[derive(Default)]
+pub struct AccountManager {
+    accounts: BTreeMap<String, Account>,
+}
+
+impl AccountManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an account, replacing the one of the same name
+    pub fn register(&mut self, account: Account) -> Option<Account> {
+        self.accounts.insert(account.name.clone(), account)
+    }
+
+    pub fn unregister(&mut self, name: &str) -> Option<Account> {
+        self.accounts.remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Account> {
+        self.accounts.get(name)
+    }
+
+    pub fn accounts(&self) -> impl Iterator<Item = &Account> {
+        self.accounts.values()
+    }
+
+    /// [Account::get_account_info] of every account, by account name
+    pub async fn get_account_info_all(
+        &self,
+    ) -> Result<BTreeMap<String, Vec<AccountsInfo>>, ResponseError> {
+        let mut account_infos = BTreeMap::new();
+
+        for account in self.accounts.values() {
+            account_infos.insert(account.name.clone(), account.get_account_info().await?);
+        }
+
+        Ok(account_infos)
+    }
+
+    /// Balances of every currency summed across every account
+    pub async fn aggregate_balances(
+        &self,
+    ) -> Result<BTreeMap<String, AggregatedBalance>, ResponseError> {
+        Ok(aggregate(&self.get_account_info_all().await?))
+    }
+}
+
+fn aggregate(
+    account_infos: &BTreeMap<String, Vec<AccountsInfo>>,
+) -> BTreeMap<String, AggregatedBalance> {
+    let mut balances = BTreeMap::<String, AggregatedBalance>::new();
+
+    for (name, account_info) in account_infos {
+        for asset in account_info {
+            let balance = balances.entry(asset.currency.clone()).or_default();
+
+            balance.balance += asset.balance;
+            balance.locked += asset.locked;
+            balance
+                .accounts
+                .insert(name.clone(), (asset.balance, asset.locked));
+        }
+    }
+
+    balances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(currency: &str, balance: f64, locked: f64) -> AccountsInfo {
+        AccountsInfo {
+            currency: currency.to_owned(),
+            balance,
+            locked,
+            avg_buy_price: 0.0,
+            avg_buy_price_modified: false,
+            unit_currency: "KRW".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_aggregate_balances() {
+        let account_infos = BTreeMap::from([
+            (
+                "momentum".to_owned(),
+                vec![asset("KRW", 100000.0, 5000.0), asset("BTC", 0.1, 0.0)],
+            ),
+            ("arbitrage".to_owned(), vec![asset("KRW", 50000.0, 0.0)]),
+        ]);
+
+        let balances = aggregate(&account_infos);
+
+        assert_eq!(balances["KRW"].balance, 150000.0);
+        assert_eq!(balances["KRW"].locked, 5000.0);
+        assert_eq!(balances["KRW"].accounts["arbitrage"], (50000.0, 0.0));
+        assert_eq!(balances["BTC"].accounts.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter() {
+        let limiter = RateLimiter::new(Duration::from_millis(100));
+        let started_at = Instant::now();
+
+        for _ in 0..RateLimitGroup::Order.requests_per_second() {
+            limiter.acquire(RateLimitGroup::Order).await;
+        }
+        limiter.acquire(RateLimitGroup::Default).await;
+        assert!(started_at.elapsed() < Duration::from_millis(100));
+
+        limiter.acquire(RateLimitGroup::Order).await;
+        assert!(started_at.elapsed() >= Duration::from_millis(100));
+    }
+}
@@ -1,7 +1,7 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
-use reqwest::{Response, Url};
 
-use crate::request::RequestWithQuery;
+use crate::request::{RequestParams, RequestWithQuery};
+use crate::transport::{HttpRequest, HttpResponse};
 
 #[allow(unused_imports)]
 use super::{
@@ -18,10 +18,7 @@ impl CoinAddressGen {
         net_type: &str,
     ) -> Result<Self, ResponseError> {
         let res = Self::request(currency, net_type).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -35,23 +32,22 @@ impl CoinAddressGen {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(currency: &str, net_type: &str) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSITS_GENERATE_COIN_ADDRESS}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
-        let token_string = Self::set_token_with_query(url.as_str())?;
+    async fn request(currency: &str, net_type: &str) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
+
+        params
+            .append("currency", currency)
+            .append("net_type", net_type);
 
-        url.query_pairs_mut()
-            .append_pair("currency", currency)
-            .append_pair("net_type", net_type);
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .post(url.as_str())
+        HttpRequest::post(format!("{URL_SERVER}{URL_DEPOSITS_GENERATE_COIN_ADDRESS}"))
             .header(ACCEPT, "application/json")
             .header(CONTENT_TYPE, "application/json")
             .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -65,48 +61,48 @@ mod tests {
 
     #[tokio::test]
     async fn test_generate_deposit_address() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = CoinAddressGen::request("ETH", "ETH").await.unwrap();
-        let res_serialized = res.text().await.unwrap();
+        crate::fixture::test_scope("generate_deposit_address", async {
+            let res = CoinAddressGen::request("ETH", "ETH").await.unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "currency": "",
-            "net_type": "",
-            "deposit_address": "",
-            "secondary_address": "",
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_generate_deposit_address] Missing keys: {missing_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_generate_deposit_address] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "currency": "",
+                "net_type": "",
+                "deposit_address": "",
+                "secondary_address": "",
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_generate_deposit_address] Missing keys: {missing_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_generate_deposit_address] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_generate_deposit_address] Extra keys: {extra_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_generate_deposit_address] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_generate_deposit_address] Extra keys: {extra_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_generate_deposit_address] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
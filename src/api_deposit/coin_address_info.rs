@@ -1,7 +1,8 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
-use reqwest::{Response, Url};
+use reqwest::Url;
 
 use crate::request::RequestWithQuery;
+use crate::transport::{HttpRequest, HttpResponse};
 
 use super::{
     super::constant::{URL_DEPOSITS_COIN_ADDRESS, URL_SERVER},
@@ -14,10 +15,7 @@ impl CoinAddressResponse {
         net_type: &str,
     ) -> Result<Self, ResponseError> {
         let res = Self::request(currency, net_type).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -36,7 +34,7 @@ impl CoinAddressResponse {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(currency: &str, net_type: &str) -> Result<Response, ResponseError> {
+    async fn request(currency: &str, net_type: &str) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSITS_COIN_ADDRESS}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
@@ -46,13 +44,11 @@ impl CoinAddressResponse {
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -66,52 +62,48 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_coin_address_info() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = CoinAddressResponse::request("ETH", "ETH").await.unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
+        crate::fixture::test_scope("get_coin_address_info", async {
+            let res = CoinAddressResponse::request("ETH", "ETH").await.unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "currency": "",
-            "net_type": "",
-            "deposit_address": "",
-            "secondary_address": "",
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_get_coin_address_info] Missing keys: {missing_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_get_coin_address_info] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "currency": "",
+                "net_type": "",
+                "deposit_address": "",
+                "secondary_address": "",
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_get_coin_address_info] Missing keys: {missing_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_get_coin_address_info] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_get_coin_address_info] Extra keys: {extra_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_get_coin_address_info] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_get_coin_address_info] Extra keys: {extra_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_get_coin_address_info] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
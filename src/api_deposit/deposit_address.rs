@@ -0,0 +1,98 @@
+use std::time::{Duration, Instant};
+
+use super::super::response::{
+    CoinAddressGen, CoinAddressResponse, DepositAddress, ResponseError, ResponseErrorState,
+};
+
+/// Interval between the checks of an address being created
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+impl DepositAddress {
+    /// Get the deposit address, generating it and waiting until it is created if missing
+    pub async fn ensure_deposit_address(
+        currency: &str,
+        net_type: &str,
+        timeout: Duration,
+    ) -> Result<Self, ResponseError> {
+        let started_at = Instant::now();
+
+        if let Some(deposit_address) = Self::find(currency, net_type).await? {
+            return Ok(deposit_address);
+        }
+
+        let coin_address_gen = CoinAddressGen::generate_deposit_address(currency, net_type).await?;
+
+        if let Some(deposit_address) = Self::from_coin_address_gen(coin_address_gen, net_type) {
+            return Ok(deposit_address);
+        }
+
+        loop {
+            let Some(remaining) = timeout.checked_sub(started_at.elapsed()) else {
+                return Err(crate::response::response_error_custom_timeout(format!(
+                    "deposit address of {currency}({net_type}) is not created in {timeout:?}"
+                )));
+            };
+
+            tokio::time::sleep(POLL_INTERVAL.min(remaining)).await;
+
+            if let Some(deposit_address) = Self::find(currency, net_type).await? {
+                return Ok(deposit_address);
+            }
+        }
+    }
+
+    async fn find(currency: &str, net_type: &str) -> Result<Option<Self>, ResponseError> {
+        match CoinAddressResponse::get_coin_address_info(currency, net_type).await {
+            Ok(coin_address) => Ok(Self::from_coin_address(coin_address)),
+            Err(e) if matches!(e.state, ResponseErrorState::CoinAddressNotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deposit_address_from_responses() {
+        let coin_address: CoinAddressResponse = serde_json::from_value(serde_json::json!({
+            "currency": "XRP",
+            "net_type": "XRP",
+            "deposit_address": "rN9qNpgnBaZwqCg8CvUZRPqCcPkY7wfWiS",
+            "secondary_address": "3057887915",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            DepositAddress::from_coin_address(coin_address)
+                .unwrap()
+                .secondary_address
+                .as_deref(),
+            Some("3057887915")
+        );
+
+        let coin_address: CoinAddressResponse = serde_json::from_value(serde_json::json!({
+            "currency": "ETH",
+            "net_type": "ETH",
+            "deposit_address": null,
+            "secondary_address": null,
+        }))
+        .unwrap();
+
+        assert_eq!(DepositAddress::from_coin_address(coin_address), None);
+
+        let coin_address_gen = CoinAddressGen {
+            response: serde_json::from_value(serde_json::json!({
+                "success": true,
+                "message": "BTC 입금주소를 생성중입니다.",
+            }))
+            .unwrap(),
+        };
+
+        assert_eq!(
+            DepositAddress::from_coin_address_gen(coin_address_gen, "BTC"),
+            None
+        );
+    }
+}
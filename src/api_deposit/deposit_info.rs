@@ -1,23 +1,50 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
-use reqwest::{Response, Url};
+use reqwest::Url;
 
 use crate::request::RequestWithQuery;
-use crate::response::{TransactionInfo, TransactionInfoSource};
+use crate::transport::{HttpRequest, HttpResponse};
+use crate::response::{
+    DepositRecord, TransactionInfoSource, TwoFactorStatus, TwoFactorTransfer,
+};
 
 use super::{
     super::constant::{URL_DEPOSIT, URL_SERVER},
     super::response::ResponseError,
+    DepositState,
 };
 
-impl TransactionInfo {
+impl TwoFactorTransfer for DepositRecord {
+    type State = DepositState;
+
+    fn state(&self) -> Self::State {
+        self.state
+    }
+
+    fn two_factor_status(&self) -> TwoFactorStatus {
+        match self.state {
+            DepositState::Accepted | DepositState::Done => TwoFactorStatus::Approved,
+            DepositState::Canceled
+            | DepositState::Rejected
+            | DepositState::Refunding
+            | DepositState::Refunded
+            | DepositState::Failed => TwoFactorStatus::Rejected,
+            DepositState::Processing | DepositState::TravelRuleSuspected => {
+                TwoFactorStatus::Pending
+            }
+        }
+    }
+
+    async fn fetch(uuid: &str) -> Result<Self, ResponseError> {
+        Self::get_deposit_info_by_uuid(uuid).await
+    }
+}
+
+impl DepositRecord {
     pub async fn get_deposit_info_by_currency(
         currency: &str,
     ) -> Result<Self, ResponseError> {
         let res = Self::request_deposit_by_currency(currency).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -33,10 +60,7 @@ impl TransactionInfo {
         uuid: &str,
     ) -> Result<Self, ResponseError> {
         let res = Self::request_deposit_by_uuid(uuid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -52,10 +76,7 @@ impl TransactionInfo {
         txid: &str,
     ) -> Result<Self, ResponseError> {
         let res = Self::request_deposit_by_txid(txid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -69,7 +90,7 @@ impl TransactionInfo {
 
     async fn request_deposit_by_currency(
         currency: &str
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSIT}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
@@ -77,18 +98,16 @@ impl TransactionInfo {
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_deposit_by_uuid(
         uuid: &str,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSIT}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
@@ -96,19 +115,17 @@ impl TransactionInfo {
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
 
     }
 
     async fn request_deposit_by_txid(
         txid: &str,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSIT}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
@@ -116,43 +133,16 @@ impl TransactionInfo {
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_status_response(res_serialized: &str) -> Result<Self, ResponseError> {
-        serde_json::from_str(res_serialized)
-            .map(|x: TransactionInfoSource| Self {
-                r#type: x.r#type(),
-                uuid: x.uuid(),
-                currency: x.currency(),
-                net_type: x.net_type(),
-                txid: x.txid(),
-                state: x.state(),
-                created_at: x.created_at(),
-                done_at: x.done_at(),
-                amount: x.amount(),
-                fee: x.fee(),
-                transaction_type: x.transaction_type(),
-
-                holder: None,
-                bank: None,
-                fiat_amount: None,
-                memo: None,
-                fiat_currency: None,
-                confirmations: None,
-                krw_amount: None,
-                network_name: None,
-                cancelable: None,
-                blockchain_url: None,
-                state_i18n: None,
-                address: None,
-            })
+        serde_json::from_str::<TransactionInfoSource>(res_serialized)
+            .map(Self::from)
             .map_err(crate::response::response_error_from_json)
     }
 }
@@ -163,65 +153,61 @@ mod tests {
 
     use serde_json::Value;
 
-    use crate::response::TransactionInfo;
+    use crate::response::DepositRecord;
 
     #[tokio::test]
     async fn test_get_deposit_info_by_currency() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = TransactionInfo::request_deposit_by_currency("KRW")
-            .await
-            .unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
-
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+        crate::fixture::test_scope("get_deposit_info", async {
+            let res = DepositRecord::request_deposit_by_currency("KRW")
+                .await
+                .unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "type": "",
-            "uuid": "",
-            "currency": "",
-            "net_type": "",
-            "txid": "",
-            "state": "",
-            "created_at": "",
-            "done_at": "",
-            "amount": "",
-            "fee": "",
-            "transaction_type": ""
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_get_deposit_info] Missing keys: {missing_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_get_deposit_info] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "type": "",
+                "uuid": "",
+                "currency": "",
+                "net_type": "",
+                "txid": "",
+                "state": "",
+                "created_at": "",
+                "done_at": "",
+                "amount": "",
+                "fee": "",
+                "transaction_type": ""
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_get_deposit_info] Missing keys: {missing_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_get_deposit_info] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_get_deposit_info] Extra keys: {extra_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_get_deposit_info] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_get_deposit_info] Extra keys: {extra_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_get_deposit_info] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
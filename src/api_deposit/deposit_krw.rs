@@ -1,24 +1,21 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
-use reqwest::{Response, Url};
+use crate::transport::{HttpRequest, HttpResponse};
 
 use crate::constant::TwoFactorType;
 
 use super::super::{
     constant::{URL_DEPOSITS_KRW, URL_SERVER},
-    request::RequestWithQuery,
-    response::{ResponseError, TransactionInfo, TransactionInfoSource},
+    request::{RequestParams, RequestWithQuery},
+    response::{DepositRecord, ResponseError, TransactionInfoSource},
 };
 
-impl TransactionInfo {
+impl DepositRecord {
     pub async fn deposit_krw(
         amount: f64,
         two_factor_type: TwoFactorType,
     ) -> Result<Self, ResponseError> {
         let res = Self::request_deposit_krw(amount, two_factor_type).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -27,57 +24,30 @@ impl TransactionInfo {
                 .unwrap());
         }
 
-        serde_json::from_str(&res_serialized)
-            .map(|x: TransactionInfoSource| Self {
-                r#type: x.r#type(),
-                uuid: x.uuid(),
-                currency: x.currency(),
-                net_type: x.net_type(),
-                txid: x.txid(),
-                state: x.state(),
-                created_at: x.created_at(),
-                done_at: x.done_at(),
-                amount: x.amount(),
-                fee: x.fee(),
-                transaction_type: x.transaction_type(),
-
-                holder: None,
-                bank: None,
-                fiat_amount: None,
-                memo: None,
-                fiat_currency: None,
-                confirmations: None,
-                krw_amount: None,
-                network_name: None,
-                cancelable: None,
-                blockchain_url: None,
-                state_i18n: None,
-                address: None,
-            })
+        serde_json::from_str::<TransactionInfoSource>(&res_serialized)
+            .map(Self::from)
             .map_err(crate::response::response_error_from_json)
     }
 
     async fn request_deposit_krw(
         amount: f64,
         two_factor_type: TwoFactorType,
-    ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSITS_KRW}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
+    ) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
 
-        url.query_pairs_mut()
-            .append_pair("amount", &format!("{amount}"))
-            .append_pair("two_factor_type", &two_factor_type.to_string());
+        params
+            .append("amount", format!("{amount}"))
+            .append("two_factor_type", two_factor_type);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .post(url.as_str())
+        HttpRequest::post(format!("{URL_SERVER}{URL_DEPOSITS_KRW}"))
             .header(ACCEPT, "application/json")
             .header(CONTENT_TYPE, "application/json")
             .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -87,65 +57,61 @@ mod tests {
 
     use serde_json::Value;
 
-    use crate::{constant::TwoFactorType, response::TransactionInfo};
+    use crate::{constant::TwoFactorType, response::DepositRecord};
 
     #[tokio::test]
     async fn test_deposit_krw() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = TransactionInfo::request_deposit_krw(10000.0, TwoFactorType::Naver)
-            .await
-            .unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
-
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+        crate::fixture::test_scope("deposit_krw", async {
+            let res = DepositRecord::request_deposit_krw(10000.0, TwoFactorType::Naver)
+                .await
+                .unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "type": "",
-            "uuid": "",
-            "currency": "",
-            "net_type": "",
-            "txid": "",
-            "state": "",
-            "created_at": "",
-            "done_at": "",
-            "amount": "",
-            "fee": "",
-            "transaction_type": ""
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_deposit_krw] Missing keys: {missing_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_deposit_krw] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "type": "",
+                "uuid": "",
+                "currency": "",
+                "net_type": "",
+                "txid": "",
+                "state": "",
+                "created_at": "",
+                "done_at": "",
+                "amount": "",
+                "fee": "",
+                "transaction_type": ""
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_deposit_krw] Missing keys: {missing_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_deposit_krw] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_deposit_krw] Extra keys: {extra_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_deposit_krw] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_deposit_krw] Extra keys: {extra_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_deposit_krw] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -1,8 +1,12 @@
 use std::fmt::Display;
+use std::time::Duration;
 
 use crate::{
     constant::{OrderBy, TwoFactorType},
-    response::{CoinAddressGen, CoinAddressResponse, ResponseError, TransactionInfo},
+    response::{
+        CoinAddressGen, CoinAddressResponse, DepositAddress, DepositRecord, ResponseError,
+        TransferCheckpoint, TransferHistory, TravelRuleVasp, TravelRuleVerification, TwoFactorTracker,
+    },
 };
 
 use serde::{Deserialize, Serialize};
@@ -12,12 +16,15 @@ use sqlx::Type;
 mod coin_address_generation;
 mod coin_address_info;
 mod coin_address_info_list;
+mod deposit_address;
 mod deposit_info;
 mod deposit_info_list;
 mod deposit_krw;
+mod travel_rule_vasps;
+mod travel_rule_verification;
 
 /// List of kind of Deposit state
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
 pub enum DepositState {
     /// ???
     Done,
@@ -55,19 +62,20 @@ impl Display for DepositState {
     }
 }
 
+/// Accepts the uppercase values of the response as well as their lowercase form
 impl From<&str> for DepositState {
     fn from(value: &str) -> Self {
-        match value {
+        match value.to_ascii_uppercase().as_str() {
             "DONE" => Self::Done,
             "PROCESSING" => Self::Processing,
             "ACCEPTED" => Self::Accepted,
-            "CANCELLED" => Self::Canceled,
+            "CANCELLED" | "CANCELED" => Self::Canceled,
             "REJECTED" => Self::Rejected,
             "TRAVEL_RULE_SUSPECTED" => Self::TravelRuleSuspected,
             "REFUNDING" => Self::Refunding,
             "REFUNDED" => Self::Refunded,
             "FAILED" => Self::Failed,
-            a => panic!("Unexpected value: {}", a),
+            _ => panic!("Unexpected value: {}", value),
         }
     }
 }
@@ -169,8 +177,33 @@ pub async fn get_deposit_info_list(
     limit: u32,
     page: u32,
     order_by: OrderBy,
-) -> Result<Vec<TransactionInfo>, ResponseError> {
-    TransactionInfo::get_deposit_info_list(currency, state, uuids, txids, limit, page, order_by).await
+) -> Result<Vec<DepositRecord>, ResponseError> {
+    DepositRecord::get_deposit_info_list(currency, state, uuids, txids, limit, page, order_by).await
+}
+
+/// 입금 기록 전체를 모든 상태에 걸쳐 `from` 커서로 순회한다. (iterate over every deposit record across every state with the `from` cursor.)
+///
+/// # Example
+/// ```rust
+/// use response::TransferCheckpoint;
+///
+/// let mut deposit_history = api_deposit::get_deposit_history(None, TransferCheckpoint::default());
+///
+/// while let Some(deposit) = deposit_history.next().await? {
+///     // ...
+/// }
+///
+/// // store it to resume the sync later
+/// let checkpoint = deposit_history.checkpoint().clone();
+/// ```
+/// - parameters
+/// > `currency` ex) KRW, BTC, ETH etc. every currency of the accounts if `None` <br>
+/// > `checkpoint` checkpoint of the previous sync, `TransferCheckpoint::default()` to sync from the beginning <br>
+pub fn get_deposit_history(
+    currency: Option<&str>,
+    checkpoint: TransferCheckpoint,
+) -> TransferHistory<DepositRecord> {
+    TransferHistory::new(currency, checkpoint)
 }
 
 /// 개별 입금 조회.
@@ -215,8 +248,8 @@ pub async fn get_deposit_info_list(
 /// | transaction_type | 입금 유형<br> default : 일반입금<br>internal : 바로입금 | String
 pub async fn get_deposit_info_by_currency(
     currency: &str,
-) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::get_deposit_info_by_currency(currency).await
+) -> Result<DepositRecord, ResponseError> {
+    DepositRecord::get_deposit_info_by_currency(currency).await
 }
 
 /// 개별 입금 조회.
@@ -260,8 +293,8 @@ pub async fn get_deposit_info_by_currency(
 /// | transaction_type | 입금 유형<br> default : 일반입금<br>internal : 바로입금 | String
 pub async fn get_deposit_info_by_uuid(
     uuid: &str,
-) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::get_deposit_info_by_uuid(uuid).await
+) -> Result<DepositRecord, ResponseError> {
+    DepositRecord::get_deposit_info_by_uuid(uuid).await
 }
 
 /// 개별 입금 조회.
@@ -305,8 +338,8 @@ pub async fn get_deposit_info_by_uuid(
 /// | transaction_type | 입금 유형<br> default : 일반입금<br>internal : 바로입금 | String
 pub async fn get_deposit_info_by_txid(
     txid: &str,
-) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::get_deposit_info_by_txid(txid).await
+) -> Result<DepositRecord, ResponseError> {
+    DepositRecord::get_deposit_info_by_txid(txid).await
 }
 
 /// 원화를 입금한다.
@@ -352,8 +385,51 @@ pub async fn get_deposit_info_by_txid(
 pub async fn deposit_krw(
     amount: f64,
     two_factor_type: TwoFactorType,
-) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::deposit_krw(amount, two_factor_type).await
+) -> Result<DepositRecord, ResponseError> {
+    DepositRecord::deposit_krw(amount, two_factor_type).await
+}
+
+/// 원화를 입금하고, 카카오/네이버 인증 후 입금이 완료되거나 거절될 때까지 추적한다. (deposit KRW and track it until it is approved, rejected or timed out.)
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use response::TwoFactorEvent;
+///
+/// let mut deposit_tracker = api_deposit::deposit_krw_tracked(
+///     10000.0,
+///     TwoFactorType::Kakao,
+///     Duration::from_secs(3),
+///     Duration::from_secs(300),
+/// ).await?;
+///
+/// while let Some(event) = deposit_tracker.next().await? {
+///     match event {
+///         TwoFactorEvent::Requested(deposit) => {}, // ask the user to approve it
+///         TwoFactorEvent::StateChanged(deposit) => {},
+///         TwoFactorEvent::Approved(deposit) => {},
+///         TwoFactorEvent::Rejected(deposit) => {},
+///         TwoFactorEvent::TimedOut(deposit) => {},
+///     }
+/// }
+/// ```
+/// - parameters
+/// > `amount` amount of deposit <br>
+/// > `two_factor_type`
+/// >> * `TwoFactorType::Kakao` indicates to use two factor identification via kakao <br>
+/// >> * `TwoFactorType::Naver` indicates to use two factor identification via naver <br>
+///
+/// > `poll_interval` interval of checking the deposit with `get_deposit_info_by_uuid` <br>
+/// > `timeout` how long to wait for the approval before `TwoFactorEvent::TimedOut` <br>
+pub async fn deposit_krw_tracked(
+    amount: f64,
+    two_factor_type: TwoFactorType,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> Result<TwoFactorTracker<DepositRecord>, ResponseError> {
+    let deposit = DepositRecord::deposit_krw(amount, two_factor_type).await?;
+
+    Ok(TwoFactorTracker::new(deposit, poll_interval, timeout))
 }
 
 /// 개별 입금 주소 조회
@@ -416,10 +492,170 @@ pub async fn get_coin_address_info_list() -> Result<Vec<CoinAddressResponse>, Re
     CoinAddressResponse::get_coin_address_info_list().await
 }
 
-/// # Currently not working
+/// 트래블룰 검증이 가능한 거래소(VASP) 목록을 조회한다. (inquiry VASPs available for travel rule verification.)
+///
+/// # Example
+/// ```rust
+/// let vasps = api_deposit::get_travel_rule_vasps().await;
+/// ```
+/// # Response
+/// ```json
+/// [
+///     {
+///         "vasp_name": "Coinone",
+///         "vasp_uuid": "00000000-0000-0000-0000-000000000001",
+///         "depositable": true,
+///         "withdrawable": true
+///     }
+/// ]
+/// ```
+/// # Response Description
+/// | field                  | description                   | type         |
+/// |:-----------------------|:------------------------------|:-------------|
+/// | vasp_name | 거래소 이름 | String |
+/// | vasp_uuid | 거래소 고유 아이디 | String |
+/// | depositable | 입금 가능 여부 | Boolean |
+/// | withdrawable | 출금 가능 여부 | Boolean |
+pub async fn get_travel_rule_vasps() -> Result<Vec<TravelRuleVasp>, ResponseError> {
+    TravelRuleVasp::get_travel_rule_vasps().await
+}
+
+/// 입금 UUID로 트래블룰 검증을 요청한다. (verify travel rule of a deposit by its uuid.)
+///
+/// # Example
+/// ```rust
+/// let verification = api_deposit::verify_travel_rule_by_uuid(
+///     "94332e99-3a87-4a35-ad98-28b0c969f830",
+///     "00000000-0000-0000-0000-000000000001",
+/// ).await;
+/// ```
+/// - parameters
+/// > `deposit_uuid` uuid of deposit to verify<br>
+/// > `vasp_uuid` uuid of VASP the deposit comes from, listed by [get_travel_rule_vasps]<br>
+/// # Response
+/// ```json
+/// {
+///     "deposit_uuid": "94332e99-3a87-4a35-ad98-28b0c969f830",
+///     "verification_result": "verified",
+///     "deposit_state": "ACCEPTED"
+/// }
+/// ```
+/// # Response Description
+/// | field                  | description                   | type         |
+/// |:-----------------------|:------------------------------|:-------------|
+/// | deposit_uuid | 입금의 고유 아이디 | String |
+/// | verification_result | 검증 결과<br> - verified : 검증 성공<br> - failed : 검증 실패 | String |
+/// | deposit_state | 입금 상태 | String |
+pub async fn verify_travel_rule_by_uuid(
+    deposit_uuid: &str,
+    vasp_uuid: &str,
+) -> Result<TravelRuleVerification, ResponseError> {
+    TravelRuleVerification::verify_travel_rule_by_uuid(deposit_uuid, vasp_uuid).await
+}
+
+/// 입금 TxID로 트래블룰 검증을 요청한다. (verify travel rule of a deposit by its txid.)
+///
+/// # Example
+/// ```rust
+/// let verification = api_deposit::verify_travel_rule_by_txid(
+///     "00000000-0000-0000-0000-000000000001",
+///     "0x6b7f1a1d8c7ed3b2e2cfbd7c1b3cde8c8a4e4d7b2b1c5a9d0e3f2a1b0c9d8e7f",
+///     "ETH",
+///     "ETH",
+/// ).await;
+/// ```
+/// - parameters
+/// > `vasp_uuid` uuid of VASP the deposit comes from, listed by [get_travel_rule_vasps]<br>
+/// > `txid` txid of deposit to verify<br>
+/// > `currency` ex) BTC, ETH etc. <br>
+/// > `net_type` ex) BTC, ETH etc. <br>
+/// # Response
+/// ```json
+/// {
+///     "deposit_uuid": "94332e99-3a87-4a35-ad98-28b0c969f830",
+///     "verification_result": "verified",
+///     "deposit_state": "ACCEPTED"
+/// }
+/// ```
+/// # Response Description
+/// | field                  | description                   | type         |
+/// |:-----------------------|:------------------------------|:-------------|
+/// | deposit_uuid | 입금의 고유 아이디 | String |
+/// | verification_result | 검증 결과<br> - verified : 검증 성공<br> - failed : 검증 실패 | String |
+/// | deposit_state | 입금 상태 | String |
+pub async fn verify_travel_rule_by_txid(
+    vasp_uuid: &str,
+    txid: &str,
+    currency: &str,
+    net_type: &str,
+) -> Result<TravelRuleVerification, ResponseError> {
+    TravelRuleVerification::verify_travel_rule_by_txid(vasp_uuid, txid, currency, net_type).await
+}
+
+/// 입금 주소 생성을 요청한다.
+///
+/// 주소 생성은 비동기로 진행되므로 첫 응답은 생성 중 메시지일 수 있다. 생성이 끝날 때까지 기다리려면 [ensure_deposit_address]를 사용한다.
+/// (address is created asynchronously, so the first response may only tell it is being created. use [ensure_deposit_address] to wait until it is created.)
+///
+/// # Example
+/// ```rust
+/// let coin_address_gen = api_deposit::generate_deposit_address("ETH", "ETH").await;
+/// ```
+/// - parameters
+/// > `currency` ex) BTC, ETH etc. <br>
+/// > `net_type` ex) BTC, ETH etc.
+/// # Response
+/// ```json
+/// {
+///   "success": true,
+///   "message": "BTC 입금주소를 생성중입니다."
+/// }
+/// ```
+/// ```json
+/// {
+///   "currency": "BTC",
+///   "net_type": "BTC",
+///   "deposit_address": "3EusRwybuZUhVDeHL7gh3HSLmbhLcy7NqD",
+///   "secondary_address": null
+/// }
+/// ```
+/// # Response Description
+/// | field                  | description                   | type         |
+/// |:-----------------------|:------------------------------|:-------------|
+/// | success | 요청 성공 여부 | Boolean |
+/// | message | 요청 결과에 대한 메세지 | String |
+/// | currency | 화폐를 의미하는 영문 대문자 코드 | String |
+/// | net_type | 입금 네트워크 | String |
+/// | deposit_address | 입금 주소 | String |
+/// | secondary_address | 2차 입금 주소 | String |
 pub async fn generate_deposit_address(
     currency: &str,
     net_type: &str,
 ) -> Result<CoinAddressGen, ResponseError> {
     CoinAddressGen::generate_deposit_address(currency, net_type).await
 }
+
+/// 입금 주소를 조회하고, 없으면 생성을 요청한 뒤 생성될 때까지 기다린다. (get the deposit address, generating it and waiting until it is created if missing.)
+///
+/// # Example
+/// ```rust
+/// let deposit_address = api_deposit::ensure_deposit_address("XRP", "XRP", std::time::Duration::from_secs(30)).await;
+/// ```
+/// - parameters
+/// > `currency` ex) BTC, ETH etc. <br>
+/// > `net_type` ex) BTC, ETH etc. <br>
+/// > `timeout` how long to wait for the address to be created. fails with `ResponseErrorState::CustomErrorTimeout` when exceeded <br>
+/// # Response Description
+/// | field                  | description                   | type         |
+/// |:-----------------------|:------------------------------|:-------------|
+/// | currency | 화폐를 의미하는 영문 대문자 코드 | String |
+/// | net_type | 입금 네트워크 | String |
+/// | deposit_address | 입금 주소 | String |
+/// | secondary_address | 2차 입금 주소 (memo, tag) | Option\<String\> |
+pub async fn ensure_deposit_address(
+    currency: &str,
+    net_type: &str,
+    timeout: Duration,
+) -> Result<DepositAddress, ResponseError> {
+    DepositAddress::ensure_deposit_address(currency, net_type, timeout).await
+}
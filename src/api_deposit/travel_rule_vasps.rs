@@ -0,0 +1,128 @@
+use reqwest::header::{ACCEPT, AUTHORIZATION};
+use reqwest::Url;
+
+use crate::request::Request;
+use crate::transport::{HttpRequest, HttpResponse};
+
+use super::{
+    super::constant::{URL_SERVER, URL_TRAVEL_RULE_VASPS},
+    super::response::{ResponseError, TravelRuleVasp},
+};
+
+impl TravelRuleVasp {
+    pub async fn get_travel_rule_vasps() -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request_vasps().await?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .ok()
+                .unwrap());
+        }
+
+        serde_json::from_str(&res_serialized).map_err(crate::response::response_error_from_json)
+    }
+
+    async fn request_vasps() -> Result<HttpResponse, ResponseError> {
+        let url = Url::parse(&format!("{URL_SERVER}{URL_TRAVEL_RULE_VASPS}"))
+            .map_err(crate::response::response_error_internal_url_parse_error)?;
+        let token_string = Self::set_token()?;
+
+        HttpRequest::get(url.as_str())
+            .header(ACCEPT, "application/json")
+            .header(AUTHORIZATION, &token_string)
+            .send()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::{json, Value};
+
+    use crate::response::TravelRuleVasp;
+
+    #[tokio::test]
+    async fn test_get_travel_rule_vasps() {
+        crate::fixture::test_scope("get_travel_rule_vasps", async {
+            let res = TravelRuleVasp::request_vasps().await.unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
+
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "vasp_name": "",
+                "vasp_uuid": "",
+                "depositable": "",
+                "withdrawable": "",
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{index}]."));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_travel_rule_vasps] Missing keys in item[{index}]: {missing_keys:?}"
+                        );
+                        assert!(false);
+                    } else {
+                        println!("[test_get_travel_rule_vasps] No keys are missing in item[{index}]");
+                    }
+
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_travel_rule_vasps] Extra keys in item[{index}]: {extra_keys:?}"
+                        );
+                        assert!(false);
+                    } else {
+                        println!("[test_get_travel_rule_vasps] No extra keys found in item[{index}]");
+                    }
+                }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
+            }
+
+            assert!(true);
+        })
+        .await;
+    }
+
+    fn compare_keys(
+        json: &Value,
+        expected: &HashMap<&str, Value>,
+        path: &str,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut missing_keys = Vec::new();
+        let mut extra_keys = Vec::new();
+
+        if let Some(actual_map) = json.as_object() {
+            for key in expected.keys() {
+                if !actual_map.contains_key(*key) {
+                    missing_keys.push(format!("{path}{key}"));
+                }
+            }
+            for key in actual_map.keys() {
+                if !expected.contains_key(key.as_str()) {
+                    extra_keys.push(format!("{path}{key}"));
+                }
+            }
+        }
+
+        (missing_keys, extra_keys)
+    }
+}
@@ -0,0 +1,194 @@
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+
+use crate::request::{RequestParams, RequestWithQuery};
+use crate::transport::{HttpRequest, HttpResponse};
+
+use super::{
+    super::constant::{URL_SERVER, URL_TRAVEL_RULE_DEPOSIT_TXID, URL_TRAVEL_RULE_DEPOSIT_UUID},
+    super::response::{ResponseError, TravelRuleVerification, TravelRuleVerificationSource},
+};
+
+impl TravelRuleVerification {
+    pub async fn verify_travel_rule_by_uuid(
+        deposit_uuid: &str,
+        vasp_uuid: &str,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_verify_by_uuid(deposit_uuid, vasp_uuid).await?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .ok()
+                .unwrap());
+        }
+
+        Self::deserialize_verification_response(&res_serialized)
+    }
+
+    pub async fn verify_travel_rule_by_txid(
+        vasp_uuid: &str,
+        txid: &str,
+        currency: &str,
+        net_type: &str,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_verify_by_txid(vasp_uuid, txid, currency, net_type).await?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .ok()
+                .unwrap());
+        }
+
+        Self::deserialize_verification_response(&res_serialized)
+    }
+
+    async fn request_verify_by_uuid(
+        deposit_uuid: &str,
+        vasp_uuid: &str,
+    ) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
+
+        params
+            .append("deposit_uuid", deposit_uuid)
+            .append("vasp_uuid", vasp_uuid);
+
+        let token_string = Self::set_token_with_params(&params)?;
+
+        HttpRequest::post(format!("{URL_SERVER}{URL_TRAVEL_RULE_DEPOSIT_UUID}"))
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/json")
+            .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
+            .send()
+            .await
+    }
+
+    async fn request_verify_by_txid(
+        vasp_uuid: &str,
+        txid: &str,
+        currency: &str,
+        net_type: &str,
+    ) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
+
+        params
+            .append("vasp_uuid", vasp_uuid)
+            .append("txid", txid)
+            .append("currency", currency)
+            .append("net_type", net_type);
+
+        let token_string = Self::set_token_with_params(&params)?;
+
+        HttpRequest::post(format!("{URL_SERVER}{URL_TRAVEL_RULE_DEPOSIT_TXID}"))
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/json")
+            .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
+            .send()
+            .await
+    }
+
+    fn deserialize_verification_response(res_serialized: &str) -> Result<Self, ResponseError> {
+        serde_json::from_str(res_serialized)
+            .map(|x: TravelRuleVerificationSource| Self {
+                deposit_uuid: x.deposit_uuid(),
+                verification_result: x.verification_result(),
+                deposit_state: x.deposit_state(),
+            })
+            .map_err(crate::response::response_error_from_json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::{json, Value};
+
+    use crate::response::{TravelRuleVasp, TravelRuleVerification};
+
+    #[tokio::test]
+    async fn test_verify_travel_rule_by_uuid() {
+        crate::fixture::test_scope("verify_travel_rule_by_uuid", async {
+            // deposit of the fixture, unless another one is given to record
+            let deposit_uuid = std::env::var("TEST_DEPOSIT_UUID")
+                .unwrap_or_else(|_| "94332e99-3a87-4a35-ad98-28b0c969f830".to_owned());
+            let vasp = TravelRuleVasp::get_travel_rule_vasps()
+                .await
+                .unwrap()
+                .into_iter()
+                .find(|vasp| vasp.depositable)
+                .expect("No depositable VASP found");
+
+            let res =
+                TravelRuleVerification::request_verify_by_uuid(&deposit_uuid, &vasp.vasp_uuid)
+                    .await
+                    .unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
+
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!({
+                "deposit_uuid": "",
+                "verification_result": "",
+                "deposit_state": "",
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_verify_travel_rule_by_uuid] Missing keys: {missing_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_verify_travel_rule_by_uuid] No keys are missing");
+            }
+
+            if !extra_keys.is_empty() {
+                println!("[test_verify_travel_rule_by_uuid] Extra keys: {extra_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_verify_travel_rule_by_uuid] No extra keys found.");
+            }
+
+            assert!(true);
+        })
+        .await;
+    }
+
+    fn compare_keys(
+        json: &Value,
+        expected: &HashMap<&str, Value>,
+        path: &str,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut missing_keys = Vec::new();
+        let mut extra_keys = Vec::new();
+
+        if let Some(actual_map) = json.as_object() {
+            for key in expected.keys() {
+                if !actual_map.contains_key(*key) {
+                    missing_keys.push(format!("{path}{key}"));
+                }
+            }
+            for key in actual_map.keys() {
+                if !expected.contains_key(key.as_str()) {
+                    extra_keys.push(format!("{path}{key}"));
+                }
+            }
+        }
+
+        (missing_keys, extra_keys)
+    }
+}
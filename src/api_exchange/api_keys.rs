@@ -0,0 +1,169 @@
+use reqwest::header::{ACCEPT, AUTHORIZATION};
+
+use crate::request::Request;
+use crate::transport::{HttpRequest, HttpResponse};
+
+use super::{
+    super::constant::{URL_API_KEYS, URL_SERVER},
+    super::response::ResponseError,
+    super::response::{ApiKey, ApiKeySource},
+};
+
+impl ApiKey {
+    pub async fn get_api_keys() -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request().await?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .ok()
+                .unwrap());
+        }
+
+        Self::deserialize_api_keys_response(&res_serialized)
+    }
+
+    /// Get the configured API key if it is expired or expires within `days` days
+    ///
+    /// With the `tracing` feature, a warning event is also emitted for the key.
+    /// Fails with [crate::response::ResponseErrorState::CustomErrorNoDataPresent] if the configured access key is not listed.
+    pub async fn check_api_key_expiry(days: u64) -> Result<Option<Self>, ResponseError> {
+        let access_key = crate::credential::credentials()?.access_key;
+        let api_keys = Self::get_api_keys().await?;
+
+        let api_key = api_keys
+            .into_iter()
+            .find(|api_key| api_key.access_key == access_key)
+            .ok_or_else(|| {
+                crate::response::response_error_custom_no_data_present("access key not found")
+            })?;
+        let expiring = Some(api_key).filter(|api_key| api_key.expires_within(days));
+
+        #[cfg(feature = "tracing")]
+        if let Some(api_key) = &expiring {
+            tracing::warn!(
+                expire_at = %api_key.expire_at,
+                expires_in_secs = api_key.expires_in().map_or(0, |x| x.as_secs()),
+                "the configured API key expires within {days} days"
+            );
+        }
+
+        Ok(expiring)
+    }
+
+    async fn request() -> Result<HttpResponse, ResponseError> {
+        let token_string = Self::set_token()?;
+
+        HttpRequest::get(format!("{URL_SERVER}{URL_API_KEYS}"))
+            .header(ACCEPT, "application/json")
+            .header(AUTHORIZATION, &token_string)
+            .send()
+            .await
+    }
+
+    fn deserialize_api_keys_response(res_serialized: &str) -> Result<Vec<Self>, ResponseError> {
+        serde_json::from_str(res_serialized)
+            .map(|i: Vec<ApiKeySource>| i.into_iter().map(Self::from).collect::<Vec<Self>>())
+            .map_err(crate::response::response_error_from_json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use serde_json::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_api_keys() {
+        let res = ApiKey::deserialize_api_keys_response(
+            r#"[
+                {
+                    "access_key": "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+                    "expire_at": "2099-03-24T11:26:40+09:00"
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            res[0].access_key,
+            "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx"
+        );
+        assert!(res[0].expires_in().is_some());
+        assert!(!res[0].expires_within(30));
+    }
+
+    #[tokio::test]
+    async fn test_check_api_key_expiry_of_unlisted_key() {
+        let interactions: Vec<crate::fixture::Interaction> =
+            serde_json::from_str(include_str!("../../tests/fixtures/get_api_keys.json")).unwrap();
+
+        let result = crate::transport::scope(
+            crate::fixture::ReplayTransport::new(interactions),
+            crate::credential::scope(
+                crate::credential::Credentials::new("unlisted access key", "secret key"),
+                ApiKey::check_api_key_expiry(14),
+            ),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err().state,
+            crate::response::ResponseErrorState::CustomErrorNoDataPresent
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_api_keys() {
+        crate::fixture::test_scope("get_api_keys", async {
+            let res = ApiKey::request().await.unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
+
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_keys = ["access_key", "expire_at"]
+                .iter()
+                .cloned()
+                .collect::<HashSet<&str>>();
+
+            if let Value::Array(json) = json {
+                if json.is_empty() {
+                    println!("[get_api_keys] The JSON is empty.");
+                }
+
+                if let Value::Object(map) = &json[0] {
+                    let json_keys = map.keys().map(|k| k.as_str()).collect::<HashSet<&str>>();
+                    let unexpected_keys =
+                        json_keys.difference(&expected_keys).collect::<HashSet<_>>();
+                    let missing_keys = expected_keys.difference(&json_keys).collect::<HashSet<_>>();
+
+                    if !unexpected_keys.is_empty() {
+                        println!("[get_api_keys] Unexpected keys found: {unexpected_keys:?}");
+                        assert!(false);
+                    } else {
+                        println!("[get_api_keys] No unexpected keys found.");
+                    }
+
+                    if !missing_keys.is_empty() {
+                        println!("[get_api_keys] Missing keys: {missing_keys:?}");
+                        assert!(false);
+                    } else {
+                        println!("[get_api_keys] No keys are missing.");
+                    }
+                } else {
+                    println!("[get_api_keys] The JSON is not an object.");
+                }
+            }
+
+            assert!(true);
+        })
+        .await;
+    }
+}
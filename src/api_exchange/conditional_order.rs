@@ -0,0 +1,543 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api_quotation::TickerSnapshot;
+use crate::journal::Journal;
+
+use super::{
+    super::response::{OrderInfo, ResponseError, ResponseErrorBody, ResponseErrorState},
+    price_checker, OrderSide, OrderType,
+};
+
+/// Trigger and execution of a [ConditionalOrder]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ConditionalOrderKind {
+    /// Market order once the price crosses `trigger_price` against the position.
+    /// (ask: price <= trigger_price, bid: price >= trigger_price)
+    StopMarket { trigger_price: f64 },
+    /// Limit order at `limit_price` once the price crosses `trigger_price` against the position.
+    StopLimit {
+        trigger_price: f64,
+        limit_price: f64,
+    },
+    /// Market order once the price reaches `trigger_price` in favor of the position.
+    /// (ask: price >= trigger_price, bid: price <= trigger_price)
+    TakeProfit { trigger_price: f64 },
+    /// Market order once the price retraces by `trail_rate` from the best price seen since armed.
+    /// ex) `0.05` for 5%
+    TrailingStop {
+        trail_rate: f64,
+        extreme_price: Option<f64>,
+    },
+}
+
+/// Order held locally until its trigger condition is met
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ConditionalOrder {
+    /// Unique id, also sent as the `identifier` of the fired order
+    pub id: String,
+    pub market: String,
+    pub side: OrderSide,
+    /// Volume in the base currency. Market bids spend `volume * price` at the time of trigger
+    pub volume: f64,
+    pub kind: ConditionalOrderKind,
+    /// Id of the other order of an OCO pair, which is disarmed once this one fires
+    pub oco_with: Option<String>,
+}
+
+impl ConditionalOrder {
+    /// Update the trailing price and tell whether the order has to fire at `price`
+    fn is_triggered(&mut self, price: f64) -> bool {
+        let side = self.side;
+
+        match &mut self.kind {
+            ConditionalOrderKind::StopMarket { trigger_price }
+            | ConditionalOrderKind::StopLimit { trigger_price, .. } => match side {
+                OrderSide::Ask => price <= *trigger_price,
+                OrderSide::Bid => price >= *trigger_price,
+            },
+            ConditionalOrderKind::TakeProfit { trigger_price } => match side {
+                OrderSide::Ask => price >= *trigger_price,
+                OrderSide::Bid => price <= *trigger_price,
+            },
+            ConditionalOrderKind::TrailingStop {
+                trail_rate,
+                extreme_price,
+            } => {
+                let extreme = match (side, *extreme_price) {
+                    (_, None) => price,
+                    (OrderSide::Ask, Some(extreme)) => f64::max(extreme, price),
+                    (OrderSide::Bid, Some(extreme)) => f64::min(extreme, price),
+                };
+                *extreme_price = Some(extreme);
+
+                match side {
+                    OrderSide::Ask => price <= extreme * (1.0 - *trail_rate),
+                    OrderSide::Bid => price >= extreme * (1.0 + *trail_rate),
+                }
+            }
+        }
+    }
+}
+
+/// Conditional order which has been sent to the exchange
+#[derive(Debug)]
+pub struct FiredOrder {
+    pub conditional_order: ConditionalOrder,
+    /// Price which triggered the order
+    pub trigger_price: f64,
+    pub result: Result<OrderInfo, ResponseError>,
+}
+
+/// Holds stop, take-profit, trailing-stop and OCO orders and fires them when triggered
+///
+/// Armed orders are optionally persisted as a json file, so restarts do not lose them.
+/// A triggered order is removed from the file before it is sent, so it fires at most once.
+pub struct ConditionalOrderEngine {
+    orders: Journal<ConditionalOrder>,
+}
+
+impl ConditionalOrderEngine {
+    /// Engine kept in memory only
+    pub fn in_memory() -> Self {
+        Self {
+            orders: Journal::in_memory(),
+        }
+    }
+
+    /// Open the engine state stored at `path`, creating it on first write if it does not exist
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ResponseError> {
+        Ok(Self {
+            orders: Journal::open(path)?,
+        })
+    }
+
+    pub fn arm(&mut self, order: ConditionalOrder) -> Result<(), ResponseError> {
+        self.arm_all(vec![order])
+    }
+
+    /// Arm two orders so that firing either one disarms the other
+    ///
+    /// Neither order is armed if one of them cannot be.
+    pub fn arm_oco(
+        &mut self,
+        mut first: ConditionalOrder,
+        mut second: ConditionalOrder,
+    ) -> Result<(), ResponseError> {
+        first.oco_with = Some(second.id.clone());
+        second.oco_with = Some(first.id.clone());
+
+        self.arm_all(vec![first, second])
+    }
+
+    /// Disarm the order together with its OCO counterpart
+    pub fn disarm(&mut self, id: &str) -> Result<Option<ConditionalOrder>, ResponseError> {
+        let mut records = self.orders.records.clone();
+        let order = records.remove(id);
+
+        if let Some(oco_with) = order.as_ref().and_then(|x| x.oco_with.as_ref()) {
+            records.remove(oco_with);
+        }
+
+        self.orders.replace(records)?;
+
+        Ok(order)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ConditionalOrder> {
+        self.orders.records.get(id)
+    }
+
+    pub fn armed(&self) -> impl Iterator<Item = &ConditionalOrder> {
+        self.orders.records.values()
+    }
+
+    /// Feed the latest trade price of a market, returning the orders triggered by it
+    ///
+    /// Triggered orders and their OCO counterparts are disarmed; the caller is responsible for sending them.
+    /// Nothing is disarmed if the file cannot be written.
+    pub fn on_price(
+        &mut self,
+        market_id: &str,
+        price: f64,
+    ) -> Result<Vec<ConditionalOrder>, ResponseError> {
+        Ok(self
+            .on_prices(&[(market_id, price)])?
+            .into_iter()
+            .map(|(order, _)| order)
+            .collect())
+    }
+
+    /// Fetch the tickers of every market with armed orders and fire the triggered ones
+    ///
+    /// The orders triggered by every ticker are disarmed at once before any of them is sent,
+    /// so every order sent is returned with its result.
+    pub async fn poll(&mut self) -> Result<Vec<FiredOrder>, ResponseError> {
+        let markets = self
+            .orders
+            .records
+            .values()
+            .map(|order| order.market.clone())
+            .collect::<BTreeSet<String>>();
+
+        if markets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let markets = markets.iter().map(String::as_str).collect::<Vec<&str>>();
+        let tickers = TickerSnapshot::get_ticker_snapshot_list(&markets).await?;
+        let prices = tickers
+            .iter()
+            .map(|ticker| (ticker.market.as_str(), ticker.trade_price))
+            .collect::<Vec<(&str, f64)>>();
+        let mut fired = Vec::new();
+
+        for (conditional_order, trigger_price) in self.on_prices(&prices)? {
+            let result = Self::fire(&conditional_order, trigger_price).await;
+
+            fired.push(FiredOrder {
+                conditional_order,
+                trigger_price,
+                result,
+            });
+        }
+
+        Ok(fired)
+    }
+
+    /// Poll every `interval` until no order is armed, handing each fired order to `on_fired`
+    pub async fn watch(
+        &mut self,
+        interval: Duration,
+        mut on_fired: impl FnMut(FiredOrder),
+    ) -> Result<(), ResponseError> {
+        while !self.orders.records.is_empty() {
+            for fired_order in self.poll().await? {
+                on_fired(fired_order);
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+
+        Ok(())
+    }
+
+    async fn fire(order: &ConditionalOrder, price: f64) -> Result<OrderInfo, ResponseError> {
+        let identifier = Some(order.id.as_str());
+
+        match (&order.kind, order.side) {
+            (ConditionalOrderKind::StopLimit { limit_price, .. }, side) => {
+                OrderInfo::order_by_price(
+                    &order.market,
+                    side,
+                    order.volume,
+                    price_checker(*limit_price),
+                    OrderType::Limit,
+                    identifier,
+                )
+                .await
+            }
+            (_, OrderSide::Ask) => {
+                OrderInfo::order_ask_at_market_price(
+                    &order.market,
+                    OrderSide::Ask,
+                    order.volume,
+                    OrderType::Market,
+                    identifier,
+                )
+                .await
+            }
+            (_, OrderSide::Bid) => {
+                OrderInfo::order_bid_at_market_price(
+                    &order.market,
+                    OrderSide::Bid,
+                    f64::trunc(order.volume * price),
+                    OrderType::Price,
+                    identifier,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Disarm the orders triggered by the trade prices of markets, with the price which triggered each
+    ///
+    /// The orders stay armed, in memory and in the file, unless the file is written.
+    fn on_prices(
+        &mut self,
+        prices: &[(&str, f64)],
+    ) -> Result<Vec<(ConditionalOrder, f64)>, ResponseError> {
+        let mut records = self.orders.records.clone();
+        let mut triggered = Vec::new();
+
+        for &(market_id, price) in prices {
+            for order in records.values_mut() {
+                if order.market == market_id && order.is_triggered(price) {
+                    triggered.push((order.id.clone(), price));
+                }
+            }
+        }
+
+        let mut fired = Vec::new();
+
+        for (id, price) in triggered {
+            // the OCO counterpart may have been removed by an order triggered by the same price
+            if let Some(order) = records.remove(&id) {
+                if let Some(oco_with) = &order.oco_with {
+                    records.remove(oco_with);
+                }
+
+                fired.push((order, price));
+            }
+        }
+
+        self.orders.replace(records)?;
+
+        Ok(fired)
+    }
+
+    /// Arm every order or none of them, rejecting ids which are already armed
+    fn arm_all(&mut self, orders: Vec<ConditionalOrder>) -> Result<(), ResponseError> {
+        let ids = orders
+            .iter()
+            .map(|order| order.id.clone())
+            .collect::<Vec<String>>();
+
+        for (i, id) in ids.iter().enumerate() {
+            if self.orders.records.contains_key(id) || ids[..i].contains(id) {
+                return Err(ResponseError {
+                    state: ResponseErrorState::DuplicatedIdentifier,
+                    error: ResponseErrorBody {
+                        name: "duplicated_identifier".to_owned(),
+                        message: format!("conditional order {id} is already armed"),
+                    },
+                });
+            }
+        }
+
+        let mut records = self.orders.records.clone();
+
+        for order in orders {
+            records.insert(order.id.clone(), order);
+        }
+
+        self.orders.replace(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conditional_order(id: &str, kind: ConditionalOrderKind) -> ConditionalOrder {
+        ConditionalOrder {
+            id: id.to_owned(),
+            market: "KRW-ETH".to_owned(),
+            side: OrderSide::Ask,
+            volume: 0.1,
+            kind,
+            oco_with: None,
+        }
+    }
+
+    #[test]
+    fn test_oco_stop_loss_and_take_profit() {
+        let mut engine = ConditionalOrderEngine::in_memory();
+
+        engine
+            .arm_oco(
+                conditional_order(
+                    "stop",
+                    ConditionalOrderKind::StopMarket {
+                        trigger_price: 2_900_000.0,
+                    },
+                ),
+                conditional_order(
+                    "profit",
+                    ConditionalOrderKind::TakeProfit {
+                        trigger_price: 3_300_000.0,
+                    },
+                ),
+            )
+            .unwrap();
+
+        assert!(engine.on_price("KRW-ETH", 3_000_000.0).unwrap().is_empty());
+        assert!(engine.on_price("KRW-BTC", 2_000_000.0).unwrap().is_empty());
+
+        let fired = engine.on_price("KRW-ETH", 3_350_000.0).unwrap();
+
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].id, "profit");
+        assert_eq!(engine.armed().count(), 0);
+    }
+
+    #[test]
+    fn test_trailing_stop() {
+        let mut engine = ConditionalOrderEngine::in_memory();
+
+        engine
+            .arm(conditional_order(
+                "trailing",
+                ConditionalOrderKind::TrailingStop {
+                    trail_rate: 0.1,
+                    extreme_price: None,
+                },
+            ))
+            .unwrap();
+
+        assert!(engine.on_price("KRW-ETH", 3_000_000.0).unwrap().is_empty());
+        assert!(engine.on_price("KRW-ETH", 4_000_000.0).unwrap().is_empty());
+        assert!(engine.on_price("KRW-ETH", 3_700_000.0).unwrap().is_empty());
+        assert_eq!(
+            engine.get("trailing").unwrap().kind,
+            ConditionalOrderKind::TrailingStop {
+                trail_rate: 0.1,
+                extreme_price: Some(4_000_000.0)
+            }
+        );
+
+        let fired = engine.on_price("KRW-ETH", 3_600_000.0).unwrap();
+        assert_eq!(fired.len(), 1);
+    }
+
+    #[test]
+    fn test_engine_persistence() {
+        let path = std::env::temp_dir().join(format!(
+            "upbit-conditional-orders-{}.json",
+            uuid::Uuid::new_v4()
+        ));
+        let mut engine = ConditionalOrderEngine::open(&path).unwrap();
+
+        engine
+            .arm(conditional_order(
+                "stop",
+                ConditionalOrderKind::StopLimit {
+                    trigger_price: 2_900_000.0,
+                    limit_price: 2_880_000.0,
+                },
+            ))
+            .unwrap();
+        assert!(engine
+            .arm(conditional_order(
+                "stop",
+                ConditionalOrderKind::StopMarket { trigger_price: 1.0 }
+            ))
+            .is_err());
+
+        let mut engine = ConditionalOrderEngine::open(&path).unwrap();
+        assert_eq!(engine.armed().count(), 1);
+
+        engine.on_price("KRW-ETH", 2_800_000.0).unwrap();
+        assert_eq!(
+            ConditionalOrderEngine::open(&path).unwrap().armed().count(),
+            0
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_arm_oco_is_atomic() {
+        let stop = || {
+            conditional_order(
+                "stop",
+                ConditionalOrderKind::StopMarket {
+                    trigger_price: 2_900_000.0,
+                },
+            )
+        };
+        let profit = || {
+            conditional_order(
+                "profit",
+                ConditionalOrderKind::TakeProfit {
+                    trigger_price: 3_300_000.0,
+                },
+            )
+        };
+
+        let mut engine = ConditionalOrderEngine::in_memory();
+        engine.arm(profit()).unwrap();
+
+        let result = engine.arm_oco(stop(), profit());
+        assert_eq!(
+            result.unwrap_err().state,
+            ResponseErrorState::DuplicatedIdentifier
+        );
+        assert!(engine.get("stop").is_none());
+        assert_eq!(engine.get("profit").unwrap().oco_with, None);
+        assert!(engine.arm_oco(stop(), stop()).is_err());
+
+        // the directory of the file does not exist, so the flush fails
+        let path = std::env::temp_dir()
+            .join(uuid::Uuid::new_v4().to_string())
+            .join("conditional-orders.json");
+        let mut engine = ConditionalOrderEngine::open(path).unwrap();
+
+        assert!(engine.arm_oco(stop(), profit()).is_err());
+        assert_eq!(engine.armed().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_write_keeps_orders_armed() {
+        let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        std::fs::create_dir(&dir).unwrap();
+        let mut engine = ConditionalOrderEngine::open(dir.join("conditional-orders.json")).unwrap();
+        engine
+            .arm(conditional_order(
+                "stop",
+                ConditionalOrderKind::StopMarket {
+                    trigger_price: 3_600_000.0,
+                },
+            ))
+            .unwrap();
+
+        // the file can no longer be written once its directory is gone
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(engine.on_price("KRW-ETH", 3_500_000.0).is_err());
+        assert!(engine.get("stop").is_some());
+
+        let transport = crate::fixture::ReplayTransport::new(vec![crate::fixture::Interaction {
+            method: "GET".to_owned(),
+            url: "https://api.upbit.com/v1/ticker?markets=KRW-ETH".to_owned(),
+            body: None,
+            status: 200,
+            headers: Default::default(),
+            response: serde_json::json!([{
+                "market": "KRW-ETH",
+                "trade_date": "20250617",
+                "trade_time": "010059",
+                "trade_date_kst": "20250617",
+                "trade_time_kst": "100059",
+                "trade_timestamp": 1750122059881u64,
+                "opening_price": 3600576.0,
+                "high_price": 3614864.0,
+                "low_price": 3554140.0,
+                "trade_price": 3572000.0,
+                "prev_closing_price": 3602004.8,
+                "change": "FALL",
+                "change_price": 30004.8,
+                "change_rate": 0.0083287063,
+                "signed_change_price": -30004.8,
+                "signed_change_rate": -0.0083287063,
+                "trade_volume": 0.00139921,
+                "acc_trade_price": 68745712893.2146,
+                "acc_trade_price_24h": 135492338412.8723,
+                "acc_trade_volume": 19231.71420856,
+                "acc_trade_volume_24h": 37812.50348157,
+                "highest_52_week_price": 5715200.0,
+                "highest_52_week_date": "2024-12-16",
+                "lowest_52_week_price": 1964600.0000000002,
+                "lowest_52_week_date": "2025-04-09",
+                "timestamp": 1750122059896u64,
+            }]),
+        }]);
+        let result = crate::transport::scope(transport, engine.poll()).await;
+
+        assert!(result.is_err());
+        assert!(engine.get("stop").is_some());
+    }
+}
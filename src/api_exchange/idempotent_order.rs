@@ -0,0 +1,301 @@
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::journal::Journal;
+
+use super::{
+    super::response::{OrderInfo, OrderStatus, ResponseError, ResponseErrorState},
+    OrderSide, OrderType,
+};
+
+/// Generates order identifiers tagged with a strategy prefix
+///
+/// Identifiers look like `{prefix}-{uuid}` so they never collide across processes or restarts.
+#[derive(Debug, Clone)]
+pub struct OrderIdentifierGenerator {
+    prefix: String,
+}
+
+impl OrderIdentifierGenerator {
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+        }
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn generate(&self) -> String {
+        format!("{}-{}", self.prefix, Uuid::new_v4().simple())
+    }
+
+    /// Whether the identifier was generated with this prefix
+    pub fn owns(&self, identifier: &str) -> bool {
+        identifier
+            .strip_prefix(&self.prefix)
+            .is_some_and(|rest| rest.starts_with('-'))
+    }
+}
+
+/// Order submitted but not confirmed by the exchange yet
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PendingOrder {
+    pub identifier: String,
+    pub market: String,
+    pub side: OrderSide,
+    pub price: f64,
+    pub price_desired: f64,
+    pub ord_type: OrderType,
+}
+
+/// Journal of [PendingOrder]s, optionally persisted as a json file
+///
+/// Every change is flushed to the file before returning, so pending orders survive a crash.
+#[derive(Debug)]
+pub struct OrderJournal {
+    journal: Journal<PendingOrder>,
+}
+
+impl OrderJournal {
+    /// Journal kept in memory only
+    pub fn in_memory() -> Self {
+        Self {
+            journal: Journal::in_memory(),
+        }
+    }
+
+    /// Open the journal stored at `path`, creating it on first write if it does not exist
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ResponseError> {
+        Ok(Self {
+            journal: Journal::open(path)?,
+        })
+    }
+
+    pub fn insert(&mut self, order: PendingOrder) -> Result<(), ResponseError> {
+        self.journal.records.insert(order.identifier.clone(), order);
+        self.journal.flush()
+    }
+
+    pub fn remove(&mut self, identifier: &str) -> Result<Option<PendingOrder>, ResponseError> {
+        let order = self.journal.records.remove(identifier);
+        self.journal.flush()?;
+
+        Ok(order)
+    }
+
+    pub fn get(&self, identifier: &str) -> Option<&PendingOrder> {
+        self.journal.records.get(identifier)
+    }
+
+    pub fn pending(&self) -> impl Iterator<Item = &PendingOrder> {
+        self.journal.records.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.journal.records.is_empty()
+    }
+}
+
+/// Places orders at most once, even when the outcome of a request is unknown
+///
+/// Each order gets a generated identifier which is journaled before submission.
+/// When a request fails without a definite answer from the exchange, the order is looked up
+/// by its identifier and only resubmitted with the same identifier if the exchange has never seen it.
+pub struct IdempotentOrderPlacer {
+    generator: OrderIdentifierGenerator,
+    journal: OrderJournal,
+    max_retries: u32,
+    retry_delay: Duration,
+}
+
+impl IdempotentOrderPlacer {
+    pub fn new(prefix: &str, journal: OrderJournal) -> Self {
+        Self {
+            generator: OrderIdentifierGenerator::new(prefix),
+            journal,
+            max_retries: 3,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Number of resubmissions allowed after the order is confirmed missing. `3` by default
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Delay before looking up an ambiguous submission. `1s` by default
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    pub fn generator(&self) -> &OrderIdentifierGenerator {
+        &self.generator
+    }
+
+    pub fn journal(&self) -> &OrderJournal {
+        &self.journal
+    }
+
+    /// Same as [super::order_by_price] with a generated identifier
+    pub async fn order_by_price(
+        &mut self,
+        market_id: &str,
+        side: OrderSide,
+        price: f64,
+        price_desired: f64,
+        ord_type: OrderType,
+    ) -> Result<OrderInfo, ResponseError> {
+        let pending = PendingOrder {
+            identifier: self.generator.generate(),
+            market: market_id.to_owned(),
+            side,
+            price,
+            price_desired,
+            ord_type,
+        };
+
+        self.journal.insert(pending.clone())?;
+        self.submit(&pending).await
+    }
+
+    /// Look up every journaled order, returning the ones which reached the exchange
+    ///
+    /// Orders the exchange has never seen are dropped from the journal without being resubmitted.
+    pub async fn resolve_pending(&mut self) -> Result<Vec<OrderInfo>, ResponseError> {
+        let identifiers = self
+            .journal
+            .pending()
+            .map(|order| order.identifier.clone())
+            .collect::<Vec<String>>();
+        let mut order_infos = Vec::new();
+
+        for identifier in identifiers {
+            match OrderStatus::get_order_status_by_identifier(&identifier).await {
+                Ok(order_status) => {
+                    self.journal.remove(&identifier)?;
+                    order_infos.push(order_status.order_info);
+                }
+                Err(e) if matches!(e.state, ResponseErrorState::OrderNotFound) => {
+                    self.journal.remove(&identifier)?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(order_infos)
+    }
+
+    async fn submit(&mut self, pending: &PendingOrder) -> Result<OrderInfo, ResponseError> {
+        let mut retries = 0;
+
+        loop {
+            let res = super::order_by_price(
+                &pending.market,
+                pending.side,
+                pending.price,
+                pending.price_desired,
+                pending.ord_type,
+                Some(&pending.identifier),
+            )
+            .await;
+
+            let e = match res {
+                Ok(order_info) => {
+                    self.journal.remove(&pending.identifier)?;
+                    return Ok(order_info);
+                }
+                Err(e) if is_ambiguous(&e) => e,
+                Err(e) if matches!(e.state, ResponseErrorState::DuplicatedIdentifier) => e,
+                Err(e) => {
+                    self.journal.remove(&pending.identifier)?;
+                    return Err(e);
+                }
+            };
+
+            tokio::time::sleep(self.retry_delay).await;
+
+            match OrderStatus::get_order_status_by_identifier(&pending.identifier).await {
+                Ok(order_status) => {
+                    self.journal.remove(&pending.identifier)?;
+                    return Ok(order_status.order_info);
+                }
+                Err(lookup) if matches!(lookup.state, ResponseErrorState::OrderNotFound) => {
+                    if retries >= self.max_retries {
+                        self.journal.remove(&pending.identifier)?;
+                        return Err(e);
+                    }
+
+                    retries += 1;
+
+                    #[cfg(feature = "tracing")]
+                    tracing::info!(
+                        identifier = %pending.identifier,
+                        retries,
+                        error = ?e.state,
+                        "retrying order"
+                    );
+                }
+                // still unknown whether the order has been placed, so it stays in the journal
+                Err(_) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Whether the order may have been placed despite the error
+fn is_ambiguous(e: &ResponseError) -> bool {
+    matches!(
+        e.state,
+        ResponseErrorState::InternalReqwestError
+            | ResponseErrorState::InternalJsonParseError
+            | ResponseErrorState::ServerError
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_identifier() {
+        let generator = OrderIdentifierGenerator::new("grid");
+        let identifier = generator.generate();
+
+        assert!(identifier.starts_with("grid-"));
+        assert!(generator.owns(&identifier));
+        assert!(!generator.owns("gridbot-0"));
+        assert_ne!(identifier, generator.generate());
+    }
+
+    #[test]
+    fn test_order_journal_persistence() {
+        let path = std::env::temp_dir().join(format!("upbit-journal-{}.json", Uuid::new_v4()));
+        let mut journal = OrderJournal::open(&path).unwrap();
+
+        journal
+            .insert(PendingOrder {
+                identifier: "grid-1".to_owned(),
+                market: "KRW-ETH".to_owned(),
+                side: OrderSide::Bid,
+                price: 5000.0,
+                price_desired: 1_435_085.0,
+                ord_type: OrderType::Limit,
+            })
+            .unwrap();
+
+        let mut journal = OrderJournal::open(&path).unwrap();
+        assert_eq!(journal.get("grid-1").map(|x| x.side), Some(OrderSide::Bid));
+
+        journal.remove("grid-1").unwrap();
+        assert!(OrderJournal::open(&path).unwrap().is_empty());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
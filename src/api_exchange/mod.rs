@@ -1,17 +1,36 @@
 pub mod accounts;
+pub mod api_keys;
+pub mod conditional_order;
+pub mod idempotent_order;
 pub mod order;
 pub mod order_cancel;
+pub mod order_cancel_and_new;
 pub mod order_chance;
 pub mod order_status;
 pub mod order_status_list;
+pub mod order_tracker;
+pub mod order_validator;
+pub mod wallet_status;
 
 use std::fmt::Display;
 
+pub use conditional_order::{
+    ConditionalOrder, ConditionalOrderEngine, ConditionalOrderKind, FiredOrder,
+};
+pub use idempotent_order::{
+    IdempotentOrderPlacer, OrderIdentifierGenerator, OrderJournal, PendingOrder,
+};
+pub use order_tracker::{OrderEvent, OrderTracker, TrackedOrder};
+pub use order_validator::{OrderProposal, OrderRejection, OrderValidation, OrderValidator};
+
 use serde::{Deserialize, Serialize};
 
 use crate::constant::OrderBy;
 
-use super::response::{AccountsInfo, OrderChance, OrderInfo, OrderStatus, ResponseError};
+use super::response::{
+    AccountsInfo, ApiKey, OrderCancelAndNew, OrderChance, OrderInfo, OrderStatus, ResponseError,
+    WalletStatus,
+};
 
 #[cfg(feature = "sqlx-type")]
 use sqlx::Type;
@@ -349,6 +368,160 @@ pub async fn cancel_order_by_identifier(identifier: &str) -> Result<OrderInfo, R
     OrderInfo::cancel_order_by_identifier(identifier).await
 }
 
+/// 주문을 취소하고 신규 주문을 요청한다. (Cancel an order and place a new one in a single request.)
+///
+/// # Example
+/// ```
+/// let order_info = api_exchange::cancel_and_new_order_by_uuid("cdd92199-2897-4e14-9448-f923320408ad", OrderType::Limit, None, Some(1_335_000.0), None).await;
+/// ```
+/// - parameters
+/// > `prev_order_uuid` uuid of order to cancel <br>
+/// > `new_ord_type`
+/// >> *  `OrderType::Limit` 지정가 주문<br>
+/// >> *  `OrderType::Price` 시장가 주문(매수)<br>
+/// >> *  `OrderType::Market` 시장가 주문(매도)<br>
+/// >> *  `OrderType::Best` 최유리 주문<br>
+///
+/// > `new_volume` (optional) volume of the new order. `None` reuses the remaining volume of the canceled order (`remain_only`)<br>
+/// > `new_price` (optional) price of the new order. truncated by [price_checker]<br>
+/// > `new_identifier` (optional) arbitrary identifier of the new order<br>
+///
+/// # Response
+/// ```json
+/// {
+///    "uuid": "cdd92199-2897-4e14-9448-f923320408ad",
+///    "side": "bid",
+///    "ord_type": "limit",
+///    "price": "100.0",
+///    "state": "wait",
+///    "market": "KRW-BTC",
+///    "created_at": "2018-04-10T15:42:23+09:00",
+///    "volume": "0.01",
+///    "remaining_volume": "0.01",
+///    "reserved_fee": "0.0015",
+///    "remaining_fee": "0.0015",
+///    "paid_fee": "0.0",
+///    "locked": "1.0015",
+///    "executed_volume": "0.0",
+///    "trades_count": 0,
+///    "new_order_uuid": "1f0a0a1b-5b6e-4a1c-9d0e-3c4a4f7e8b9d",
+///    "new_order_identifier": "test_identifier_new"
+///  }
+/// ```
+/// # Response Description
+/// | field             | description                   | type         |
+/// |:------------------|:------------------------------|:-------------|
+/// | uuid              | 취소된 주문의 고유 아이디       | String |
+/// | side              | 주문 종류                     | String |
+/// | ord_type          | 주문 방식                     | String |
+/// | price             | 주문 당시 화폐 가격           | NumberString |
+/// | state             | 주문 상태                     | String |
+/// | market            | 마켓의 유일키                 | String |
+/// | created_at        | 주문 생성 시간                | String |
+/// | volume            | 사용자가 입력한 주문 양       | NumberString |
+/// | remaining_volume  | 체결 후 남은 주문 양          | NumberString |
+/// | reserved_fee      | 수수료로 예약된 비용          | NumberString |
+/// | remaining_fee     | 남은 수수료                   | NumberString |
+/// | paid_fee          | 사용된 수수료                | NumberString |
+/// | locked            | 거래에 사용중인 비용          | NumberString |
+/// | executed_volume   | 체결된 양                    | NumberString |
+/// | trades_count      | 해당 주문에 걸린 체결 수      | Integer |
+/// | new_order_uuid    | 신규 주문의 고유 아이디        | String |
+/// | new_order_identifier | 신규 주문의 사용자 지정 아이디 | String |
+pub async fn cancel_and_new_order_by_uuid(
+    prev_order_uuid: &str,
+    new_ord_type: OrderType,
+    new_volume: Option<f64>,
+    new_price: Option<f64>,
+    new_identifier: Option<&str>,
+) -> Result<OrderCancelAndNew, ResponseError> {
+    OrderCancelAndNew::cancel_and_new_order_by_uuid(
+        prev_order_uuid,
+        new_ord_type,
+        new_volume,
+        new_price.map(price_checker),
+        new_identifier,
+    )
+    .await
+}
+
+/// 주문을 취소하고 신규 주문을 요청한다. (Cancel an order and place a new one in a single request.)
+///
+/// # Example
+/// ```
+/// let order_info = api_exchange::cancel_and_new_order_by_identifier("test_identifier", OrderType::Limit, None, Some(1_335_000.0), Some("test_identifier_new")).await;
+/// ```
+/// - parameters
+/// > `prev_order_identifier` identifier of order to cancel <br>
+/// > `new_ord_type`
+/// >> *  `OrderType::Limit` 지정가 주문<br>
+/// >> *  `OrderType::Price` 시장가 주문(매수)<br>
+/// >> *  `OrderType::Market` 시장가 주문(매도)<br>
+/// >> *  `OrderType::Best` 최유리 주문<br>
+///
+/// > `new_volume` (optional) volume of the new order. `None` reuses the remaining volume of the canceled order (`remain_only`)<br>
+/// > `new_price` (optional) price of the new order. truncated by [price_checker]<br>
+/// > `new_identifier` (optional) arbitrary identifier of the new order<br>
+///
+/// # Response
+/// ```json
+/// {
+///    "uuid": "cdd92199-2897-4e14-9448-f923320408ad",
+///    "side": "bid",
+///    "ord_type": "limit",
+///    "price": "100.0",
+///    "state": "wait",
+///    "market": "KRW-BTC",
+///    "created_at": "2018-04-10T15:42:23+09:00",
+///    "volume": "0.01",
+///    "remaining_volume": "0.01",
+///    "reserved_fee": "0.0015",
+///    "remaining_fee": "0.0015",
+///    "paid_fee": "0.0",
+///    "locked": "1.0015",
+///    "executed_volume": "0.0",
+///    "trades_count": 0,
+///    "new_order_uuid": "1f0a0a1b-5b6e-4a1c-9d0e-3c4a4f7e8b9d",
+///    "new_order_identifier": "test_identifier_new"
+///  }
+/// ```
+/// # Response Description
+/// | field             | description                   | type         |
+/// |:------------------|:------------------------------|:-------------|
+/// | uuid              | 취소된 주문의 고유 아이디       | String |
+/// | side              | 주문 종류                     | String |
+/// | ord_type          | 주문 방식                     | String |
+/// | price             | 주문 당시 화폐 가격           | NumberString |
+/// | state             | 주문 상태                     | String |
+/// | market            | 마켓의 유일키                 | String |
+/// | created_at        | 주문 생성 시간                | String |
+/// | volume            | 사용자가 입력한 주문 양       | NumberString |
+/// | remaining_volume  | 체결 후 남은 주문 양          | NumberString |
+/// | reserved_fee      | 수수료로 예약된 비용          | NumberString |
+/// | remaining_fee     | 남은 수수료                   | NumberString |
+/// | paid_fee          | 사용된 수수료                | NumberString |
+/// | locked            | 거래에 사용중인 비용          | NumberString |
+/// | executed_volume   | 체결된 양                    | NumberString |
+/// | trades_count      | 해당 주문에 걸린 체결 수      | Integer |
+/// | new_order_uuid    | 신규 주문의 고유 아이디        | String |
+/// | new_order_identifier | 신규 주문의 사용자 지정 아이디 | String |
+pub async fn cancel_and_new_order_by_identifier(
+    prev_order_identifier: &str,
+    new_ord_type: OrderType,
+    new_volume: Option<f64>,
+    new_price: Option<f64>,
+    new_identifier: Option<&str>,
+) -> Result<OrderCancelAndNew, ResponseError> {
+    OrderCancelAndNew::cancel_and_new_order_by_identifier(
+        prev_order_identifier,
+        new_ord_type,
+        new_volume,
+        new_price.map(price_checker),
+        new_identifier,
+    )
+    .await
+}
+
 /// 내가 보유한 자산 리스트를 보여줍니다. (look up your account info)
 ///
 /// # Example
@@ -389,6 +562,84 @@ pub async fn get_account_info() -> Result<Vec<AccountsInfo>, ResponseError> {
     AccountsInfo::get_account_info().await
 }
 
+/// 입출금 현황을 조회한다. (inquiry deposit and withdrawal status of every wallet.)
+///
+/// # Example
+/// ```rust
+/// let wallet_status = api_exchange::get_wallet_status().await;
+/// ```
+/// # Response
+/// ```json
+/// [
+///   {
+///     "currency": "BTC",
+///     "wallet_state": "working",
+///     "block_state": "normal",
+///     "block_height": 849123,
+///     "block_updated_at": "2024-06-25T06:43:48.392+00:00",
+///     "block_elapsed_minutes": 1,
+///     "net_type": "BTC",
+///     "network_name": "Bitcoin"
+///   }
+/// ]
+/// ```
+/// # Response Description
+/// | field                  | description                   | type         |
+/// |:-----------------------|:------------------------------|:-------------|
+/// | currency               | 화폐를 의미하는 영문 대문자 코드 | String       |
+/// | wallet_state           | 입출금 상태<br> - working : 입출금 가능<br> - withdraw_only : 출금만 가능<br> - deposit_only : 입금만 가능<br> - paused : 입출금 중단<br> - unsupported : 입출금 미지원 | String |
+/// | block_state            | 블록 상태<br> - normal : 정상<br> - delayed : 지연<br> - inactive : 비활성 | String |
+/// | block_height           | 블록 높이                      | Integer      |
+/// | block_updated_at       | 블록 갱신 시각                  | DateString   |
+/// | block_elapsed_minutes  | 블록 정보 최종 갱신 후 경과 시간(분) | Integer  |
+/// | net_type               | 입출금 네트워크                 | String       |
+/// | network_name           | 입출금 네트워크 이름             | String       |
+pub async fn get_wallet_status() -> Result<Vec<WalletStatus>, ResponseError> {
+    WalletStatus::get_wallet_status().await
+}
+
+/// API 키 목록과 만료 일자를 조회한다. (inquiry the list of API keys and their expiry.)
+///
+/// # Example
+/// ```rust
+/// let api_keys = api_exchange::get_api_keys().await;
+/// ```
+/// # Response
+/// ```json
+/// [
+///   {
+///     "access_key": "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+///     "expire_at": "2021-03-24T11:26:40+09:00"
+///   }
+/// ]
+/// ```
+/// # Response Description
+/// | field                  | description                   | type         |
+/// |:-----------------------|:------------------------------|:-------------|
+/// | access_key             | access key                    | String       |
+/// | expire_at              | 만료 일시                      | DateString   |
+pub async fn get_api_keys() -> Result<Vec<ApiKey>, ResponseError> {
+    ApiKey::get_api_keys().await
+}
+
+/// 설정된 API 키가 만료되었거나 `days`일 안에 만료되면 반환한다. (get the configured API key if it is expired or expires within `days` days.)
+///
+/// `tracing` 기능을 켜면 경고 이벤트도 남긴다. (a warning event is also emitted with the `tracing` feature.)
+///
+/// 설정된 access key가 목록에 없으면 [crate::response::ResponseErrorState::CustomErrorNoDataPresent] 에러를 반환한다. (fails if the configured access key is not listed.)
+///
+/// # Example
+/// ```rust
+/// if let Some(api_key) = api_exchange::check_api_key_expiry(14).await? {
+///     println!("{} expires at {}", api_key.access_key, api_key.expire_at);
+/// }
+/// ```
+/// - parameters
+/// > `days` number of days before the expiry to warn from <br>
+pub async fn check_api_key_expiry(days: u64) -> Result<Option<ApiKey>, ResponseError> {
+    ApiKey::check_api_key_expiry(days).await
+}
+
 /// 마켓별 주문 가능 정보를 확인한다. (check specific market status.)
 ///
 /// # Example
@@ -976,7 +1227,14 @@ pub async fn get_order_status_list_closed(
 }
 
 pub fn price_checker(price: f64) -> f64 {
-    let truncation = if price >= 2_000_000.0 {
+    let truncation = price_unit(price);
+
+    f64::trunc(price / truncation) * truncation
+}
+
+/// 호가 단위 (Tick size of the given price)
+pub fn price_unit(price: f64) -> f64 {
+    if price >= 2_000_000.0 {
         1000.0
     } else if price >= 1_000_000.0 {
         500.0
@@ -1004,7 +1262,5 @@ pub fn price_checker(price: f64) -> f64 {
         0.0000001
     } else {
         0.00000001
-    };
-
-    f64::trunc(price / truncation) * truncation
+    }
 }
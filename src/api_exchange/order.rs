@@ -1,7 +1,7 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
-use reqwest::{Response, Url};
 
-use crate::request::RequestWithQuery;
+use crate::request::{RequestParams, RequestWithQuery};
+use crate::transport::{HttpRequest, HttpResponse};
 
 use super::{
     super::{
@@ -23,10 +23,7 @@ impl OrderInfo {
         let res =
             Self::request_order_by_price(market_id, side, volume, price, ord_type, identifier)
                 .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -47,10 +44,28 @@ impl OrderInfo {
     ) -> Result<Self, ResponseError> {
         let res = Self::request_ask_at_market_price(market_id, side, volume, ord_type, identifier)
             .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .ok()
+                .unwrap());
+        }
+
+        Self::deserialize_order_response(&res_serialized)
+    }
+
+    pub async fn order_bid_at_market_price(
+        market_id: &str,
+        side: OrderSide,
+        price: f64,
+        ord_type: OrderType,
+        identifier: Option<&str>,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_bid_at_market_price(market_id, side, price, ord_type, identifier)
+            .await?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -69,33 +84,31 @@ impl OrderInfo {
         price: f64,
         ord_type: OrderType,
         identifier: Option<&str>,
-    ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
+    ) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
         let price = format!("{:.8}", price);
         let volume = format!("{:.8}", volume);
 
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            .append_pair("side", &side.to_string())
-            .append_pair("ord_type", &ord_type.to_string())
-            .append_pair("price", &price.to_string())
-            .append_pair("volume", &volume.to_string());
+        params
+            .append("market", market_id)
+            .append("side", side)
+            .append("ord_type", ord_type)
+            .append("price", price)
+            .append("volume", volume);
 
         if let Some(identifier) = identifier {
-            url.query_pairs_mut().append_pair("identifier", identifier);
+            params.append("identifier", identifier);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .post(url.as_str())
+        HttpRequest::post(format!("{URL_SERVER}{URL_ORDER}"))
             .header(ACCEPT, "application/json")
             .header(CONTENT_TYPE, "application/json")
             .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_ask_at_market_price(
@@ -104,65 +117,60 @@ impl OrderInfo {
         volume: f64,
         ord_type: OrderType,
         identifier: Option<&str>,
-    ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
+    ) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
         let volume = format!("{:.8}", volume);
 
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            .append_pair("side", &side.to_string())
-            .append_pair("ord_type", &ord_type.to_string())
-            .append_pair("volume", &volume.to_string());
+        params
+            .append("market", market_id)
+            .append("side", side)
+            .append("ord_type", ord_type)
+            .append("volume", volume);
 
         if let Some(identifier) = identifier {
-            url.query_pairs_mut().append_pair("identifier", identifier);
+            params.append("identifier", identifier);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .post(url.as_str())
+        HttpRequest::post(format!("{URL_SERVER}{URL_ORDER}"))
             .header(ACCEPT, "application/json")
             .header(CONTENT_TYPE, "application/json")
             .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
-    #[allow(dead_code)]
     async fn request_bid_at_market_price(
         market_id: &str,
         side: OrderSide,
         price: f64,
         ord_type: OrderType,
         identifier: Option<&str>,
-    ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
+    ) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
         let price = format!("{:.8}", price);
 
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            .append_pair("side", &side.to_string())
-            .append_pair("ord_type", &ord_type.to_string())
-            .append_pair("price", &price.to_string());
+        params
+            .append("market", market_id)
+            .append("side", side)
+            .append("ord_type", ord_type)
+            .append("price", price);
 
         if let Some(identifier) = identifier {
-            url.query_pairs_mut().append_pair("identifier", identifier);
+            params.append("identifier", identifier);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .post(url.as_str())
+        HttpRequest::post(format!("{URL_SERVER}{URL_ORDER}"))
             .header(ACCEPT, "application/json")
             .header(CONTENT_TYPE, "application/json")
             .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_response(res_serialized: &str) -> Result<Self, ResponseError> {
@@ -202,310 +210,294 @@ mod tests {
 
     #[tokio::test]
     async fn test_order_bid_by_price() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let volume = (5000.0 + 1.) / price_checker(1_435_085.0);
-        let price = price_checker(1_435_085.0);
-
-        let res = OrderInfo::request_order_by_price(
-            "KRW-ETH",
-            OrderSide::Bid,
-            volume,
-            price,
-            OrderType::Limit,
-            None,
-        )
-        .await
-        .unwrap();
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("order_bid_by_price", async {
+            let volume = (5000.0 + 1.) / price_checker(1_435_085.0);
+            let price = price_checker(1_435_085.0);
+
+            let res = OrderInfo::request_order_by_price(
+                "KRW-ETH",
+                OrderSide::Bid,
+                volume,
+                price,
+                OrderType::Limit,
+                None,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            "volume": "",
-            "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            // "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_order_bid_by_price] Missing keys: {:?}", missing_keys);
-            assert!(false);
-        } else {
-            println!("[test_order_bid_by_price] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                "volume": "",
+                "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                // "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_order_bid_by_price] Missing keys: {:?}", missing_keys);
+                assert!(false);
+            } else {
+                println!("[test_order_bid_by_price] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_order_bid_by_price] Extra keys: {:?}", extra_keys);
-            assert!(false);
-        } else {
-            println!("[test_order_bid_by_price] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_order_bid_by_price] Extra keys: {:?}", extra_keys);
+                assert!(false);
+            } else {
+                println!("[test_order_bid_by_price] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     #[tokio::test]
     async fn test_order_ask_by_price() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let volume = (5000.0 + 1.) / price_checker(3_435_085.0);
-        let price = price_checker(3_435_085.0);
-
-        let res = OrderInfo::request_order_by_price(
-            "KRW-ETH",
-            OrderSide::Ask,
-            volume,
-            price,
-            OrderType::Limit,
-            None,
-        )
-        .await
-        .unwrap();
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("order_ask_by_price", async {
+            let volume = (5000.0 + 1.) / price_checker(3_435_085.0);
+            let price = price_checker(3_435_085.0);
+
+            let res = OrderInfo::request_order_by_price(
+                "KRW-ETH",
+                OrderSide::Ask,
+                volume,
+                price,
+                OrderType::Limit,
+                None,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            "volume": "",
-            "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            // "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_order_ask_by_price] Missing keys: {:?}", missing_keys);
-            assert!(false);
-        } else {
-            println!("[test_order_ask_by_price] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                "volume": "",
+                "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                // "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_order_ask_by_price] Missing keys: {:?}", missing_keys);
+                assert!(false);
+            } else {
+                println!("[test_order_ask_by_price] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_order_ask_by_price] Extra keys: {:?}", extra_keys);
-            assert!(false);
-        } else {
-            println!("[test_order_ask_by_price] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_order_ask_by_price] Extra keys: {:?}", extra_keys);
+                assert!(false);
+            } else {
+                println!("[test_order_ask_by_price] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     #[tokio::test]
     async fn test_order_bid_at_market_price() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let price = price_checker(5000.0);
-
-        let res = OrderInfo::request_bid_at_market_price(
-            "KRW-ETH",
-            OrderSide::Bid,
-            price,
-            OrderType::Price,
-            None,
-        )
-        .await
-        .unwrap();
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("order_bid_at_market_price", async {
+            let price = price_checker(5000.0);
+
+            let res = OrderInfo::request_bid_at_market_price(
+                "KRW-ETH",
+                OrderSide::Bid,
+                price,
+                OrderType::Price,
+                None,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            // "volume": "",
-            // "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            // "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!(
-                "[test_order_bid_at_market_price] Missing keys: {:?}",
-                missing_keys
-            );
-            assert!(false);
-        } else {
-            println!("[test_order_bid_at_market_price] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                // "volume": "",
+                // "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                // "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!(
+                    "[test_order_bid_at_market_price] Missing keys: {:?}",
+                    missing_keys
+                );
+                assert!(false);
+            } else {
+                println!("[test_order_bid_at_market_price] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!(
-                "[test_order_bid_at_market_price] Extra keys: {:?}",
-                extra_keys
-            );
-            assert!(false);
-        } else {
-            println!("[test_order_bid_at_market_price] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!(
+                    "[test_order_bid_at_market_price] Extra keys: {:?}",
+                    extra_keys
+                );
+                assert!(false);
+            } else {
+                println!("[test_order_bid_at_market_price] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     #[tokio::test]
     async fn test_order_ask_at_market_price() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let volume = 0.0015;
-
-        let res = OrderInfo::request_ask_at_market_price(
-            "KRW-ETH",
-            OrderSide::Ask,
-            volume,
-            OrderType::Market,
-            None,
-        )
-        .await
-        .unwrap();
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("order_ask_at_market_price", async {
+            let volume = 0.0015;
+
+            let res = OrderInfo::request_ask_at_market_price(
+                "KRW-ETH",
+                OrderSide::Ask,
+                volume,
+                OrderType::Market,
+                None,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            // "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            "volume": "",
-            "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            // "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!(
-                "[test_order_ask_at_market_price] Missing keys: {:?}",
-                missing_keys
-            );
-            assert!(false);
-        } else {
-            println!("[test_order_ask_at_market_price] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                // "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                "volume": "",
+                "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                // "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!(
+                    "[test_order_ask_at_market_price] Missing keys: {:?}",
+                    missing_keys
+                );
+                assert!(false);
+            } else {
+                println!("[test_order_ask_at_market_price] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!(
-                "[test_order_ask_at_market_price] Extra keys: {:?}",
-                extra_keys
-            );
-            assert!(false);
-        } else {
-            println!("[test_order_ask_at_market_price] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!(
+                    "[test_order_ask_at_market_price] Extra keys: {:?}",
+                    extra_keys
+                );
+                assert!(false);
+            } else {
+                println!("[test_order_ask_at_market_price] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     // async fn test order_bid_by_price
@@ -1,7 +1,8 @@
 use crate::request::RequestWithQuery;
+use crate::transport::{HttpRequest, HttpResponse};
 use reqwest::{
     header::{ACCEPT, AUTHORIZATION},
-    Response, Url,
+    Url,
 };
 
 use super::{
@@ -12,10 +13,7 @@ use super::{
 impl OrderInfo {
     pub async fn cancel_order_by_uuid(uuid: &str) -> Result<Self, ResponseError> {
         let res = Self::request_cancel_by_uuid(uuid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -29,10 +27,7 @@ impl OrderInfo {
 
     pub async fn cancel_order_by_identifier(identifier: &str) -> Result<Self, ResponseError> {
         let res = Self::request_cancel_by_identifier(identifier).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -44,36 +39,32 @@ impl OrderInfo {
         Self::deserialize_order_cancel(&res_serialized)
     }
 
-    async fn request_cancel_by_uuid(uuid: &str) -> Result<Response, ResponseError> {
+    async fn request_cancel_by_uuid(uuid: &str) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut().append_pair("uuid", uuid);
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .delete(url.as_str())
+        HttpRequest::delete(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
-    async fn request_cancel_by_identifier(identifier: &str) -> Result<Response, ResponseError> {
+    async fn request_cancel_by_identifier(identifier: &str) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut().append_pair("identifier", identifier);
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .delete(url.as_str())
+        HttpRequest::delete(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_cancel(res_serialized: &str) -> Result<Self, ResponseError> {
@@ -114,73 +105,66 @@ mod tests {
 
     #[tokio::test]
     async fn test_order_cancel_by_uuid() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        crate::fixture::test_scope("order_cancel_by_uuid", async {
+            let uuid = order_to_get_uuid().await;
 
-        let uuid = order_to_get_uuid().await;
-
-        let res = OrderInfo::request_cancel_by_uuid(&uuid).await.unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
+            let res = OrderInfo::request_cancel_by_uuid(&uuid).await.unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            "volume": "",
-            "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            // "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_order_cancel_by_uuid] Missing keys: {missing_keys:?}",);
-            assert!(false);
-        } else {
-            println!("[test_order_cancel_by_uuid] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                "volume": "",
+                "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                // "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_order_cancel_by_uuid] Missing keys: {missing_keys:?}",);
+                assert!(false);
+            } else {
+                println!("[test_order_cancel_by_uuid] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_order_cancel_by_uuid] Extra keys: {extra_keys:?}");
-            assert!(false);
-        } else {
-            println!("[test_order_cancel_by_uuid] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_order_cancel_by_uuid] Extra keys: {extra_keys:?}");
+                assert!(false);
+            } else {
+                println!("[test_order_cancel_by_uuid] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     async fn order_to_get_uuid() -> String {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
         let price = 5000.0;
         let price_desired = 1_435_085.0;
 
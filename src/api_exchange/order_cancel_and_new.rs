@@ -0,0 +1,225 @@
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+
+use crate::request::{RequestParams, RequestWithQuery};
+use crate::transport::{HttpRequest, HttpResponse};
+
+use super::{
+    super::{
+        constant::{URL_ORDER_CANCEL_AND_NEW, URL_SERVER},
+        response::{OrderCancelAndNew, OrderCancelAndNewSource, OrderInfo, ResponseError},
+    },
+    OrderType,
+};
+
+impl OrderCancelAndNew {
+    pub async fn cancel_and_new_order_by_uuid(
+        prev_order_uuid: &str,
+        new_ord_type: OrderType,
+        new_volume: Option<f64>,
+        new_price: Option<f64>,
+        new_identifier: Option<&str>,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_cancel_and_new(
+            ("prev_order_uuid", prev_order_uuid),
+            new_ord_type,
+            new_volume,
+            new_price,
+            new_identifier,
+        )
+        .await?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .unwrap_or_else(crate::response::response_error_from_json));
+        }
+
+        Self::deserialize_cancel_and_new_response(&res_serialized)
+    }
+
+    pub async fn cancel_and_new_order_by_identifier(
+        prev_order_identifier: &str,
+        new_ord_type: OrderType,
+        new_volume: Option<f64>,
+        new_price: Option<f64>,
+        new_identifier: Option<&str>,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_cancel_and_new(
+            ("prev_order_identifier", prev_order_identifier),
+            new_ord_type,
+            new_volume,
+            new_price,
+            new_identifier,
+        )
+        .await?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .unwrap_or_else(crate::response::response_error_from_json));
+        }
+
+        Self::deserialize_cancel_and_new_response(&res_serialized)
+    }
+
+    async fn request_cancel_and_new(
+        prev_order: (&str, &str),
+        new_ord_type: OrderType,
+        new_volume: Option<f64>,
+        new_price: Option<f64>,
+        new_identifier: Option<&str>,
+    ) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
+
+        params
+            .append(prev_order.0, prev_order.1)
+            .append("new_ord_type", new_ord_type);
+
+        if let Some(new_price) = new_price {
+            params.append("new_price", format!("{:.8}", new_price));
+        }
+
+        match new_volume {
+            Some(new_volume) => {
+                params.append("new_volume", format!("{:.8}", new_volume));
+            }
+            None if new_ord_type != OrderType::Price => {
+                params.append("new_volume", "remain_only");
+            }
+            None => {}
+        }
+
+        if let Some(new_identifier) = new_identifier {
+            params.append("new_identifier", new_identifier);
+        }
+
+        let token_string = Self::set_token_with_params(&params)?;
+
+        HttpRequest::post(format!("{URL_SERVER}{URL_ORDER_CANCEL_AND_NEW}"))
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/json")
+            .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
+            .send()
+            .await
+    }
+
+    fn deserialize_cancel_and_new_response(res_serialized: &str) -> Result<Self, ResponseError> {
+        serde_json::from_str(res_serialized)
+            .map(|x: OrderCancelAndNewSource| Self {
+                order_info: OrderInfo {
+                    uuid: x.order_info.uuid(),
+                    side: x.order_info.side(),
+                    ord_type: x.order_info.ord_type(),
+                    price: x.order_info.price(),
+                    state: x.order_info.state(),
+                    market: x.order_info.market(),
+                    created_at: x.order_info.created_at(),
+                    volume: x.order_info.volume(),
+                    remaining_volume: x.order_info.remaining_volume(),
+                    reserved_fee: x.order_info.reserved_fee(),
+                    remaining_fee: x.order_info.remaining_fee(),
+                    paid_fee: x.order_info.paid_fee(),
+                    locked: x.order_info.locked(),
+                    executed_volume: x.order_info.executed_volume(),
+                    executed_funds: x.order_info.executed_funds(),
+                    trades_count: x.order_info.trades_count(),
+                    time_in_force: x.order_info.time_in_force(),
+                },
+                new_order_uuid: x.new_order_uuid,
+                new_order_identifier: x.new_order_identifier,
+            })
+            .map_err(crate::response::response_error_from_json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_exchange::{price_checker, OrderSide};
+    use crate::fixture::{Interaction, ReplayTransport};
+    use crate::response::ResponseErrorState;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cancel_and_new_order_by_uuid() {
+        crate::fixture::test_scope("cancel_and_new_order_by_uuid", async {
+            let uuid = order_to_get_uuid().await;
+
+            let res = OrderCancelAndNew::cancel_and_new_order_by_uuid(
+                &uuid,
+                OrderType::Limit,
+                None,
+                Some(price_checker(1_335_085.0)),
+                None,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(res.order_info.uuid, uuid);
+            assert_eq!(res.new_order_uuid, "a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d");
+            assert_eq!(res.new_order_identifier, None);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_cancel_and_new_order_with_non_api_error_body() {
+        let transport = ReplayTransport::new(vec![Interaction {
+            method: "POST".to_owned(),
+            url: format!("{URL_SERVER}{URL_ORDER_CANCEL_AND_NEW}"),
+            body: Some(serde_json::json!({
+                "prev_order_uuid": "d60dfc8a-db0a-4087-9974-fed6433eb8f1",
+                "new_ord_type": "limit",
+                "new_price": "1335000.00000000",
+                "new_volume": "remain_only",
+            })),
+            status: 502,
+            headers: Default::default(),
+            response: "<html>502 Bad Gateway error</html>".into(),
+        }]);
+
+        let res = crate::transport::scope(
+            transport,
+            crate::credential::scope(
+                crate::credential::Credentials::new("access key", "secret key"),
+                OrderCancelAndNew::cancel_and_new_order_by_uuid(
+                    "d60dfc8a-db0a-4087-9974-fed6433eb8f1",
+                    OrderType::Limit,
+                    None,
+                    Some(1_335_000.0),
+                    None,
+                ),
+            ),
+        )
+        .await;
+
+        assert_eq!(
+            res.unwrap_err().state,
+            ResponseErrorState::InternalJsonParseError
+        );
+    }
+
+    async fn order_to_get_uuid() -> String {
+        let price = 5000.0;
+        let price_desired = 1_435_085.0;
+
+        let res = super::super::order_by_price(
+            "KRW-ETH",
+            OrderSide::Bid,
+            price,
+            price_desired,
+            OrderType::Limit,
+            None,
+        )
+        .await;
+
+        if let Ok(res) = res {
+            res.uuid
+        } else {
+            panic!("Failed to get uuid from order_by_price")
+        }
+    }
+}
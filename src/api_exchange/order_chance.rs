@@ -1,5 +1,5 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
-use reqwest::{Response, Url};
+use reqwest::Url;
 
 use super::{
     super::constant::{URL_ORDER_CHANCE, URL_SERVER},
@@ -8,15 +8,13 @@ use super::{
     },
 };
 use crate::request::RequestWithQuery;
+use crate::transport::{HttpRequest, HttpResponse};
 
 impl RequestWithQuery for OrderChance {}
 impl OrderChance {
     pub async fn get_order_chance(market_id: &str) -> Result<Self, ResponseError> {
         let res = Self::request(market_id).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -84,7 +82,7 @@ impl OrderChance {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(market_id: &str) -> Result<Response, ResponseError> {
+    async fn request(market_id: &str) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_CHANCE}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
@@ -92,13 +90,11 @@ impl OrderChance {
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url)
+        HttpRequest::get(url)
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -112,86 +108,82 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_order_chance() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = OrderChance::request("KRW-ETH").await.unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
+        crate::fixture::test_scope("get_order_chance", async {
+            let res = OrderChance::request("KRW-ETH").await.unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "bid_fee": "",
-            "ask_fee": "",
-            "market": {
-                "id": "",
-                "name": "",
-                "order_sides": [],
-                "order_types": [],
-                "bid": {
-                    "currency": "",
-                    "min_total": ""
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "bid_fee": "",
+                "ask_fee": "",
+                "market": {
+                    "id": "",
+                    "name": "",
+                    "order_sides": [],
+                    "order_types": [],
+                    "bid": {
+                        "currency": "",
+                        "min_total": ""
+                    },
+                    "ask": {
+                        "currency": "",
+                        "min_total": ""
+                    },
+                    "max_total": "",
+                    "state": "",
+                    "ask_types": [],
+                    "bid_types": [],
                 },
-                "ask": {
+                "maker_ask_fee": "",
+                "maker_bid_fee": "",
+                "bid_account": {
                     "currency": "",
-                    "min_total": ""
+                    "balance": "",
+                    "locked": "",
+                    "avg_buy_price": "",
+                    "avg_buy_price_modified": "",
+                    "unit_currency": ""
                 },
-                "max_total": "",
-                "state": "",
-                "ask_types": [],
-                "bid_types": [],
-            },
-            "maker_ask_fee": "",
-            "maker_bid_fee": "",
-            "bid_account": {
-                "currency": "",
-                "balance": "",
-                "locked": "",
-                "avg_buy_price": "",
-                "avg_buy_price_modified": "",
-                "unit_currency": ""
-            },
-            "ask_account": {
-                "currency": "",
-                "balance": "",
-                "locked": "",
-                "avg_buy_price": "",
-                "avg_buy_price_modified": "",
-                "unit_currency": ""
+                "ask_account": {
+                    "currency": "",
+                    "balance": "",
+                    "locked": "",
+                    "avg_buy_price": "",
+                    "avg_buy_price_modified": "",
+                    "unit_currency": ""
+                }
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_get_order_chance] Missing keys: {:?}", missing_keys);
+                assert!(false);
+            } else {
+                println!("[test_get_order_chance] No keys are missing");
             }
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_get_order_chance] Missing keys: {:?}", missing_keys);
-            assert!(false);
-        } else {
-            println!("[test_get_order_chance] No keys are missing");
-        }
 
-        if !extra_keys.is_empty() {
-            println!("[test_get_order_chance] Extra keys: {:?}", extra_keys);
-            assert!(false);
-        } else {
-            println!("[test_get_order_chance] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_get_order_chance] Extra keys: {:?}", extra_keys);
+                assert!(false);
+            } else {
+                println!("[test_get_order_chance] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -1,11 +1,12 @@
 use reqwest::{
     header::{ACCEPT, AUTHORIZATION},
-    Response, Url,
+    Url,
 };
+use crate::transport::{HttpRequest, HttpResponse};
 
 use crate::{constant::OrderBy, request::Request};
 use crate::{
-    request::RequestWithQuery,
+    request::{RequestParams, RequestWithQuery},
     response::{ResponseErrorBody, ResponseErrorState},
 };
 
@@ -28,10 +29,7 @@ impl OrderInfo {
         order_by: OrderBy,
     ) -> Result<Vec<Self>, ResponseError> {
         let res = Self::request_get_order_list_by_uuids(market_id, uuids, order_by).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -49,10 +47,7 @@ impl OrderInfo {
         order_by: OrderBy,
     ) -> Result<Vec<Self>, ResponseError> {
         let res = Self::request_get_orders_by_identifiers(market_id, identifiers, order_by).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -101,10 +96,7 @@ impl OrderInfo {
         }
 
         let res = Self::request_get_order_list_opened(market_id, states, page, limit, order_by).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -128,10 +120,7 @@ impl OrderInfo {
             market_id, states, start_time, end_time, limit, order_by,
         )
         .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -147,10 +136,7 @@ impl OrderInfo {
     pub async fn get_order_state_list() -> Result<Vec<Self>, ResponseError> {
         #[allow(deprecated)]
         let res = Self::request(&format!("{URL_SERVER}{URL_ORDER_STATUS_LIST}")).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -163,73 +149,65 @@ impl OrderInfo {
     }
 
     #[deprecated(since = "1.6.0")]
-    async fn request(url: &str) -> Result<Response, ResponseError> {
+    async fn request(url: &str) -> Result<HttpResponse, ResponseError> {
         let url = Url::parse(url).unwrap();
         let token_string = Self::set_token()?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_get_order_list_by_uuids(
         market_id: &str,
         uuids: &[&str],
         order_by: OrderBy,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS_BY_UUID}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            .append_pair("order_by", &order_by.to_string());
+        let mut params = RequestParams::new();
 
-        for uuid in uuids {
-            url.query_pairs_mut().append_pair("uuids", uuid);
-        }
+        params
+            .append("market", market_id)
+            .append("order_by", order_by)
+            .append_array("uuids", uuids);
 
-        let url = url.as_str().replace("&uuids", "&uuids[]");
-        let token_string = Self::set_token_with_query(&url)?;
+        params.append_to_url(&mut url);
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_get_orders_by_identifiers(
         market_id: &str,
         identifiers: &[&str],
         order_by: OrderBy,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS_BY_UUID}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            .append_pair("order_by", &order_by.to_string());
+        let mut params = RequestParams::new();
 
-        for identifier in identifiers {
-            url.query_pairs_mut().append_pair("identifiers", identifier);
-        }
+        params
+            .append("market", market_id)
+            .append("order_by", order_by)
+            .append_array("identifiers", identifiers);
 
-        let url = url.as_str().replace("identifiers", "identifiers[]");
-        let token_string = Self::set_token_with_query(&url)?;
+        params.append_to_url(&mut url);
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_get_order_list_opened(
@@ -238,32 +216,27 @@ impl OrderInfo {
         page: u8,
         limit: u8,
         order_by: OrderBy,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS_OPEN}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            // .append_pair("state", &state.to_string())
-            .append_pair("page", &page.to_string())
-            .append_pair("limit", &limit.to_string())
-            .append_pair("order_by", &order_by.to_string());
+        let mut params = RequestParams::new();
 
-        for state in states {
-            url.query_pairs_mut()
-                .append_pair("states", &state.to_string());
-        }
+        params
+            .append("market", market_id)
+            .append("page", page)
+            .append("limit", limit)
+            .append("order_by", order_by)
+            .append_array("states", states);
 
-        let url = url.as_str().replace("states", "states[]");
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        params.append_to_url(&mut url);
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_get_orders_closed(
@@ -273,40 +246,34 @@ impl OrderInfo {
         end_time: Option<&str>,
         limit: u16,
         order_by: OrderBy,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS_CLOSED}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            // .append_pair("state", &state.to_string())
-            .append_pair("limit", &limit.to_string())
-            .append_pair("order_by", &order_by.to_string());
+        let mut params = RequestParams::new();
+
+        params
+            .append("market", market_id)
+            .append("limit", limit)
+            .append("order_by", order_by);
 
         if let Some(start_time) = start_time {
-            url.query_pairs_mut().append_pair("start_time", start_time);
+            params.append("start_time", start_time);
         }
 
         if let Some(end_time) = end_time {
-            url.query_pairs_mut().append_pair("end_time", end_time);
+            params.append("end_time", end_time);
         }
 
-        for state in states {
-            url.query_pairs_mut()
-                .append_pair("states", &state.to_string());
-        }
-
-        let url = url.as_str().replace("states", "states[]");
+        params.append_array("states", states);
+        params.append_to_url(&mut url);
+        let token_string = Self::set_token_with_params(&params)?;
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
-
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_status_response(res_serialized: &str) -> Result<Vec<Self>, ResponseError> {
@@ -351,370 +318,354 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_order_state_list() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        #[allow(deprecated)]
-        let res = OrderInfo::request(&format!("{URL_SERVER}{URL_ORDER_STATUS_LIST}"))
-            .await
-            .unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
+        crate::fixture::test_scope("get_order_state_list", async {
+            #[allow(deprecated)]
+            let res = OrderInfo::request(&format!("{URL_SERVER}{URL_ORDER_STATUS_LIST}"))
+                .await
+                .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            "volume": "",
-            "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            // "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
-
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_get_order_state_list] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_order_state_list] No keys are missing in item[{}]",
-                        index
-                    );
-                }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                "volume": "",
+                "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                // "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_order_state_list] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_order_state_list] No keys are missing in item[{}]",
+                            index
+                        );
+                    }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_order_state_list] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_order_state_list] No extra keys found in item[{}]",
-                        index
-                    );
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_order_state_list] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_order_state_list] No extra keys found in item[{}]",
+                            index
+                        );
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     #[tokio::test]
     async fn test_get_orders_by_uuids() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let uuid = order_to_get_uuid().await;
-
-        let res =
-            OrderInfo::request_get_order_list_by_uuids("KRW-ETH", &[uuid.as_str()], OrderBy::Desc)
-                .await
-                .unwrap();
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("get_order_status_by_uuids", async {
+            let uuid = order_to_get_uuid().await;
+
+            let res = OrderInfo::request_get_order_list_by_uuids(
+                "KRW-ETH",
+                &[uuid.as_str()],
+                OrderBy::Desc,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            "volume": "",
-            "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
-
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_get_orders_by_uuids] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_orders_by_uuids] No keys are missing in item[{}]",
-                        index
-                    );
-                }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                "volume": "",
+                "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_orders_by_uuids] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_orders_by_uuids] No keys are missing in item[{}]",
+                            index
+                        );
+                    }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_orders_by_uuids] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_orders_by_uuids] No extra keys found in item[{}]",
-                        index
-                    );
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_orders_by_uuids] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_orders_by_uuids] No extra keys found in item[{}]",
+                            index
+                        );
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     #[tokio::test]
     async fn test_get_order_status_opened() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = OrderInfo::request_get_order_list_opened(
-            "KRW-ETH",
-            &[OrderState::Wait],
-            1,
-            10,
-            OrderBy::Desc,
-        )
-        .await
-        .unwrap();
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("get_order_status_opened", async {
+            let res = OrderInfo::request_get_order_list_opened(
+                "KRW-ETH",
+                &[OrderState::Wait],
+                1,
+                10,
+                OrderBy::Desc,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            "volume": "",
-            "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
-
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_get_order_status_opened] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_order_status_opened] No keys are missing in item[{}]",
-                        index
-                    );
-                }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                "volume": "",
+                "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_order_status_opened] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_order_status_opened] No keys are missing in item[{}]",
+                            index
+                        );
+                    }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_order_status_opened] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_order_status_opened] No extra keys found in item[{}]",
-                        index
-                    );
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_order_status_opened] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_order_status_opened] No extra keys found in item[{}]",
+                            index
+                        );
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     #[tokio::test]
     async fn test_get_order_status_closed() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = OrderInfo::request_get_orders_closed(
-            "KRW-ETH",
-            &[OrderState::Done],
-            None,
-            None,
-            10,
-            OrderBy::Desc,
-        )
-        .await
-        .unwrap();
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("get_order_status_closed", async {
+            let res = OrderInfo::request_get_orders_closed(
+                "KRW-ETH",
+                &[OrderState::Done],
+                None,
+                None,
+                10,
+                OrderBy::Desc,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "uuid": "",
-            "side": "",
-            "ord_type": "",
-            "price": "",
-            "state": "",
-            "market": "",
-            "created_at": "",
-            "volume": "",
-            "remaining_volume": "",
-            "reserved_fee": "",
-            "remaining_fee": "",
-            "paid_fee": "",
-            "locked": "",
-            "executed_volume": "",
-            "executed_funds": "",
-            "trades_count": "",
-            // "time_in_force": "",
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}]", index));
-
-                let ord_type = item.get("ord_type").and_then(|v| v.as_str()).unwrap();
-
-                if !missing_keys.is_empty() {
-                    match ord_type {
-                        "limit" => {
-                            let missing_keys = missing_keys
-                                .iter()
-                                .filter(|x| x.contains("price"))
-                                .map(|x| x.to_string())
-                                .collect::<Vec<String>>();
-
-                            if missing_keys.is_empty() {
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "uuid": "",
+                "side": "",
+                "ord_type": "",
+                "price": "",
+                "state": "",
+                "market": "",
+                "created_at": "",
+                "volume": "",
+                "remaining_volume": "",
+                "reserved_fee": "",
+                "remaining_fee": "",
+                "paid_fee": "",
+                "locked": "",
+                "executed_volume": "",
+                "executed_funds": "",
+                "trades_count": "",
+                // "time_in_force": "",
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}]", index));
+
+                    let ord_type = item.get("ord_type").and_then(|v| v.as_str()).unwrap();
+
+                    if !missing_keys.is_empty() {
+                        match ord_type {
+                            "limit" => {
+                                let missing_keys = missing_keys
+                                    .iter()
+                                    .filter(|x| x.contains("price"))
+                                    .map(|x| x.to_string())
+                                    .collect::<Vec<String>>();
+
+                                if missing_keys.is_empty() {
+                                    continue;
+                                }
+                            }
+                            "market" => {
                                 continue;
                             }
+                            _ => {}
                         }
-                        "market" => {
-                            continue;
-                        }
-                        _ => {}
-                    }
 
-                    // Check the presence of the price field based on the ord_type field
-                    println!(
-                        "[test_get_order_status_closed] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false, "Missing keys found");
-                }
+                        // Check the presence of the price field based on the ord_type field
+                        println!(
+                            "[test_get_order_status_closed] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false, "Missing keys found");
+                    }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_order_status_closed] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false, "Extra keys found");
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_order_status_closed] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false, "Extra keys found");
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
+        })
+        .await;
     }
 
     async fn order_to_get_uuid() -> String {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
         let price = 5000.0;
         let price_desired = 1_435_085.0;
 
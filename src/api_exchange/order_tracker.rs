@@ -0,0 +1,484 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use crate::constant::OrderBy;
+
+use super::{
+    super::response::{
+        ObjectTrades, OrderInfo, OrderStatus, ResponseError, ResponseErrorBody, ResponseErrorState,
+    },
+    OrderSide, OrderState,
+};
+
+/// Maximum number of uuids accepted by a single order list request
+const UUIDS_PER_REQUEST: usize = 100;
+
+/// Lifecycle event of an order registered to [OrderTracker]
+#[derive(Debug, Clone)]
+pub enum OrderEvent {
+    /// The order is seen on the exchange for the first time
+    Accepted { uuid: String, market: String },
+    /// New trades have been made on the order
+    PartiallyFilled {
+        uuid: String,
+        trades: Vec<ObjectTrades>,
+    },
+    /// The order is fully filled
+    Filled(TrackedOrder),
+    /// The order is canceled
+    Cancelled(TrackedOrder),
+    /// The IOC/FOK order is closed without being fully filled
+    Expired(TrackedOrder),
+}
+
+/// Snapshot of an order registered to [OrderTracker]
+#[derive(Debug, Clone)]
+pub struct TrackedOrder {
+    pub uuid: String,
+    pub market: String,
+    pub side: Option<OrderSide>,
+    pub state: Option<OrderState>,
+    pub executed_volume: f64,
+    pub paid_fee: f64,
+    pub trades_count: i64,
+    pub trades: Vec<ObjectTrades>,
+    accepted: bool,
+    expirable: bool,
+}
+
+impl TrackedOrder {
+    fn new(uuid: &str, market: &str) -> Self {
+        Self {
+            uuid: uuid.to_owned(),
+            market: market.to_owned(),
+            side: None,
+            state: None,
+            executed_volume: 0.0,
+            paid_fee: 0.0,
+            trades_count: 0,
+            trades: Vec::new(),
+            accepted: false,
+            expirable: false,
+        }
+    }
+
+    /// Volume weighted average price of the trades made so far
+    pub fn avg_fill_price(&self) -> Option<f64> {
+        let volume: f64 = self.trades.iter().map(|trade| trade.volume).sum();
+
+        if volume > 0.0 {
+            Some(self.trades.iter().map(|trade| trade.funds).sum::<f64>() / volume)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the order reached `done` or `cancel`
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.state,
+            Some(OrderState::Done) | Some(OrderState::Cancel)
+        )
+    }
+}
+
+/// Tracks placed orders and reports their lifecycle as [OrderEvent]s
+///
+/// Orders are polled in batches per market with [OrderInfo::get_order_status_list_by_uuids].
+/// Trades are fetched with [OrderStatus::get_order_status_by_uuid] only for the orders whose `trades_count` changed.
+pub struct OrderTracker {
+    orders: HashMap<String, TrackedOrder>,
+    poll_interval: Duration,
+}
+
+impl OrderTracker {
+    pub fn new(poll_interval: Duration) -> Self {
+        Self {
+            orders: HashMap::new(),
+            poll_interval,
+        }
+    }
+
+    /// Register an order returned by the order APIs
+    pub fn register(&mut self, order_info: &OrderInfo) {
+        self.register_uuid(&order_info.market, &order_info.uuid);
+    }
+
+    /// Register an order by its market and uuid
+    pub fn register_uuid(&mut self, market_id: &str, uuid: &str) {
+        self.orders
+            .entry(uuid.to_owned())
+            .or_insert_with(|| TrackedOrder::new(uuid, market_id));
+    }
+
+    /// Stop tracking an order
+    pub fn unregister(&mut self, uuid: &str) -> Option<TrackedOrder> {
+        self.orders.remove(uuid)
+    }
+
+    pub fn get(&self, uuid: &str) -> Option<&TrackedOrder> {
+        self.orders.get(uuid)
+    }
+
+    /// Orders which have not reached a terminal state yet
+    pub fn pending(&self) -> impl Iterator<Item = &TrackedOrder> {
+        self.orders.values().filter(|order| !order.is_terminal())
+    }
+
+    /// Poll every pending order once and return the events observed
+    ///
+    /// Every order is fetched before any of them is applied, so a failed request leaves the tracked orders
+    /// as they were and the next poll reports their events.
+    pub async fn poll(&mut self) -> Result<Vec<OrderEvent>, ResponseError> {
+        let mut markets: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+        for order in self.pending() {
+            markets
+                .entry(order.market.clone())
+                .or_default()
+                .push(order.uuid.clone());
+        }
+
+        let mut fetched = Vec::new();
+
+        for (market_id, uuids) in markets {
+            for chunk in uuids.chunks(UUIDS_PER_REQUEST) {
+                let uuids = chunk.iter().map(String::as_str).collect::<Vec<&str>>();
+                let order_infos =
+                    OrderInfo::get_order_status_list_by_uuids(&market_id, &uuids, OrderBy::Asc)
+                        .await?;
+
+                for order_info in order_infos {
+                    let trades = match self.orders.get(&order_info.uuid) {
+                        Some(order) if order.trades_count != order_info.trades_count => {
+                            OrderStatus::get_order_status_by_uuid(&order_info.uuid)
+                                .await?
+                                .trades
+                        }
+                        _ => Vec::new(),
+                    };
+
+                    fetched.push((order_info, trades));
+                }
+            }
+        }
+
+        Ok(fetched
+            .into_iter()
+            .flat_map(|(order_info, trades)| self.apply(&order_info, trades))
+            .collect())
+    }
+
+    /// Poll until the order reaches a terminal state, returning every event observed meanwhile
+    ///
+    /// Fails with `order_not_found` for an order not registered, and with `custom_error_timeout`
+    /// if the order is still pending after `timeout`. The events observed until then are kept in the tracked orders.
+    pub async fn wait_until_terminal(
+        &mut self,
+        uuid: &str,
+        timeout: Duration,
+    ) -> Result<Vec<OrderEvent>, ResponseError> {
+        if !self.orders.contains_key(uuid) {
+            return Err(ResponseError {
+                state: ResponseErrorState::OrderNotFound,
+                error: ResponseErrorBody {
+                    name: "order_not_found".to_owned(),
+                    message: format!("order {uuid} is not registered to the tracker"),
+                },
+            });
+        }
+
+        let started_at = Instant::now();
+        let mut events = Vec::new();
+
+        loop {
+            events.append(&mut self.poll().await?);
+
+            match self.orders.get(uuid) {
+                Some(order) if !order.is_terminal() => {}
+                _ => return Ok(events),
+            }
+
+            let Some(remaining) = timeout.checked_sub(started_at.elapsed()) else {
+                return Err(crate::response::response_error_custom_timeout(format!(
+                    "order {uuid} is still pending after {timeout:?}"
+                )));
+            };
+
+            tokio::time::sleep(self.poll_interval.min(remaining)).await;
+        }
+    }
+
+    fn apply(&mut self, order_info: &OrderInfo, trades: Vec<ObjectTrades>) -> Vec<OrderEvent> {
+        let mut events = Vec::new();
+
+        let Some(order) = self.orders.get_mut(&order_info.uuid) else {
+            return events;
+        };
+
+        if !order.accepted {
+            order.accepted = true;
+            events.push(OrderEvent::Accepted {
+                uuid: order.uuid.clone(),
+                market: order.market.clone(),
+            });
+        }
+
+        let known = order
+            .trades
+            .iter()
+            .map(|trade| trade.uuid.clone())
+            .collect::<HashSet<String>>();
+        let new_trades = trades
+            .into_iter()
+            .filter(|trade| !known.contains(&trade.uuid))
+            .collect::<Vec<ObjectTrades>>();
+
+        order.side = Some(order_info.side);
+        order.state = Some(order_info.state);
+        order.executed_volume = order_info.executed_volume;
+        order.paid_fee = order_info.paid_fee;
+        order.trades_count = order_info.trades_count;
+        order.expirable = order_info.time_in_force.is_some();
+        order.trades.extend(new_trades.iter().cloned());
+
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_fills(&new_trades);
+
+        if !new_trades.is_empty() && order_info.state != OrderState::Done {
+            events.push(OrderEvent::PartiallyFilled {
+                uuid: order.uuid.clone(),
+                trades: new_trades,
+            });
+        }
+
+        match order_info.state {
+            OrderState::Done => events.push(OrderEvent::Filled(order.clone())),
+            OrderState::Cancel if order.expirable => {
+                events.push(OrderEvent::Expired(order.clone()))
+            }
+            OrderState::Cancel => events.push(OrderEvent::Cancelled(order.clone())),
+            OrderState::Wait | OrderState::Watch => {}
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::credential::{self, Credentials};
+    use crate::transport::{HttpRequest, HttpResponse, HttpTransport, TransportFuture};
+
+    use super::*;
+
+    /// Transport answering every request with the same order list
+    struct FixedTransport(String);
+
+    impl HttpTransport for FixedTransport {
+        fn send(&self, _request: HttpRequest) -> TransportFuture<'_> {
+            Box::pin(async { Ok(HttpResponse::new(reqwest::StatusCode::OK, self.0.clone())) })
+        }
+    }
+
+    /// Transport answering the requests for `failing_market` with a server error, and the others with an order list
+    struct FailingMarketTransport {
+        failing_market: &'static str,
+        order_list: String,
+    }
+
+    impl HttpTransport for FailingMarketTransport {
+        fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+            Box::pin(async move {
+                if request.url.contains(self.failing_market) {
+                    return Ok(HttpResponse::new(
+                        reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                        r#"{"error":{"name":"server_error","message":"Internal Server Error"}}"#,
+                    ));
+                }
+
+                Ok(HttpResponse::new(
+                    reqwest::StatusCode::OK,
+                    self.order_list.clone(),
+                ))
+            })
+        }
+    }
+
+    fn order_list() -> String {
+        serde_json::to_string(&[serde_json::json!({
+            "uuid": "9ca023a5-851b-4fec-9f0a-48cd83c2eaae",
+            "side": "bid",
+            "ord_type": "limit",
+            "price": "4280000.0",
+            "state": "wait",
+            "market": "KRW-BTC",
+            "created_at": "2019-01-04T13:48:09+09:00",
+            "volume": "1.0",
+            "remaining_volume": "1.0",
+            "reserved_fee": "2140.0",
+            "remaining_fee": "2140.0",
+            "paid_fee": "0.0",
+            "locked": "4282140.0",
+            "executed_volume": "0.0",
+            "trades_count": 0,
+        })])
+        .unwrap()
+    }
+
+    fn order_info(state: &str, executed_volume: f64, trades_count: i64) -> OrderInfo {
+        serde_json::from_value(serde_json::json!({
+            "uuid": "9ca023a5-851b-4fec-9f0a-48cd83c2eaae",
+            "side": "Bid",
+            "ord_type": "Limit",
+            "price": 4280000.0,
+            "state": state,
+            "market": "KRW-BTC",
+            "created_at": "2019-01-04T13:48:09",
+            "volume": 1.0,
+            "remaining_volume": 1.0 - executed_volume,
+            "reserved_fee": 0.0,
+            "remaining_fee": 0.0,
+            "paid_fee": executed_volume * 2140.0,
+            "locked": 0.0,
+            "executed_volume": executed_volume,
+            "executed_funds": null,
+            "trades_count": trades_count,
+            "time_in_force": null,
+        }))
+        .unwrap()
+    }
+
+    fn trade(uuid: &str, price: f64, volume: f64) -> ObjectTrades {
+        serde_json::from_value(serde_json::json!({
+            "market": "KRW-BTC",
+            "uuid": uuid,
+            "price": price,
+            "volume": volume,
+            "funds": price * volume,
+            "side": "Bid",
+            "created_at": "2019-01-04T13:48:09",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_order_lifecycle() {
+        let mut tracker = OrderTracker::new(Duration::from_secs(1));
+        tracker.register_uuid("KRW-BTC", "9ca023a5-851b-4fec-9f0a-48cd83c2eaae");
+
+        let events = tracker.apply(&order_info("Wait", 0.0, 0), Vec::new());
+        assert!(matches!(events.as_slice(), [OrderEvent::Accepted { .. }]));
+
+        let events = tracker.apply(
+            &order_info("Wait", 0.4, 1),
+            vec![trade("t1", 4_000_000.0, 0.4)],
+        );
+        assert!(
+            matches!(events.as_slice(), [OrderEvent::PartiallyFilled { trades, .. }] if trades.len() == 1)
+        );
+
+        let events = tracker.apply(
+            &order_info("Done", 1.0, 2),
+            vec![trade("t1", 4_000_000.0, 0.4), trade("t2", 5_000_000.0, 0.6)],
+        );
+        let [OrderEvent::Filled(order)] = events.as_slice() else {
+            panic!("unexpected events: {events:?}");
+        };
+
+        assert_eq!(order.trades.len(), 2);
+        assert_eq!(order.avg_fill_price(), Some(4_600_000.0));
+        assert_eq!(order.paid_fee, 2140.0);
+        assert_eq!(tracker.pending().count(), 0);
+    }
+
+    #[test]
+    fn test_apply_order_cancelled() {
+        let mut tracker = OrderTracker::new(Duration::from_secs(1));
+        tracker.register_uuid("KRW-BTC", "9ca023a5-851b-4fec-9f0a-48cd83c2eaae");
+
+        let events = tracker.apply(&order_info("Cancel", 0.0, 0), Vec::new());
+
+        assert!(matches!(
+            events.as_slice(),
+            [OrderEvent::Accepted { .. }, OrderEvent::Cancelled(_)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_terminal_unknown_order() {
+        let mut tracker = OrderTracker::new(Duration::from_secs(1));
+
+        let result = tracker
+            .wait_until_terminal(
+                "9ca023a5-851b-4fec-9f0a-48cd83c2eaae",
+                Duration::from_secs(1),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().state, ResponseErrorState::OrderNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_terminal_timeout() {
+        let mut tracker = OrderTracker::new(Duration::from_millis(10));
+        tracker.register_uuid("KRW-BTC", "9ca023a5-851b-4fec-9f0a-48cd83c2eaae");
+
+        let result = crate::transport::scope(
+            FixedTransport(order_list()),
+            credential::scope(
+                Credentials::new("access key", "secret key"),
+                tracker.wait_until_terminal(
+                    "9ca023a5-851b-4fec-9f0a-48cd83c2eaae",
+                    Duration::from_millis(30),
+                ),
+            ),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err().state,
+            ResponseErrorState::CustomErrorTimeout
+        );
+        assert!(tracker
+            .get("9ca023a5-851b-4fec-9f0a-48cd83c2eaae")
+            .is_some_and(|order| order.state == Some(OrderState::Wait)));
+    }
+
+    #[tokio::test]
+    async fn test_poll_failure_keeps_events() {
+        let mut tracker = OrderTracker::new(Duration::from_secs(1));
+        tracker.register_uuid("KRW-BTC", "9ca023a5-851b-4fec-9f0a-48cd83c2eaae");
+        tracker.register_uuid("KRW-ETH", "d60dfc8a-db0a-4087-9974-fed6433eb8f1");
+
+        // KRW-BTC is fetched before the request for KRW-ETH fails
+        let result = crate::transport::scope(
+            FailingMarketTransport {
+                failing_market: "KRW-ETH",
+                order_list: order_list(),
+            },
+            credential::scope(Credentials::new("access key", "secret key"), tracker.poll()),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(tracker
+            .get("9ca023a5-851b-4fec-9f0a-48cd83c2eaae")
+            .is_some_and(|order| order.state.is_none()));
+
+        let events = crate::transport::scope(
+            FailingMarketTransport {
+                failing_market: "KRW-XRP",
+                order_list: order_list(),
+            },
+            credential::scope(Credentials::new("access key", "secret key"), tracker.poll()),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            events.as_slice(),
+            [OrderEvent::Accepted { uuid, .. }] if uuid == "9ca023a5-851b-4fec-9f0a-48cd83c2eaae"
+        ));
+    }
+}
@@ -0,0 +1,565 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::constant::{AskType, BidType};
+
+use super::{
+    super::response::{OrderChance, ResponseError, ResponseErrorBody, ResponseErrorState},
+    price_unit, OrderCondition, OrderSide, OrderType,
+};
+
+/// Order to be checked by [OrderValidator]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderProposal {
+    pub market: String,
+    pub side: OrderSide,
+    pub ord_type: OrderType,
+    /// Required for `limit` and `market` orders
+    pub volume: Option<f64>,
+    /// Required for `limit` and `price` orders, and for `best` bid orders
+    pub price: Option<f64>,
+    pub time_in_force: Option<OrderCondition>,
+}
+
+/// Reason why an order would be rejected by the exchange
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderRejection {
+    /// The proposal is checked against the order chance of another market
+    MarketMismatch { expected: String },
+    /// The market is not active
+    MarketNotActive { state: String },
+    /// The market does not accept orders of this side
+    SideNotSupported(OrderSide),
+    /// The market does not accept orders of this type. ex) `not_supported_ord_type`
+    OrdTypeNotSupported {
+        ord_type: OrderType,
+        time_in_force: Option<OrderCondition>,
+    },
+    /// Volume is missing or not positive
+    InvalidVolume,
+    /// Price is missing or not positive
+    InvalidPrice,
+    /// Price does not fit the tick size. ex) `invalid_price_bid`
+    InvalidPriceUnit { price: f64, price_unit: f64 },
+    /// Order total is below the minimum. ex) `under_min_total_bid`, `under_min_total_ask`
+    UnderMinTotal { total: f64, min_total: f64 },
+    /// Order total is above the maximum
+    OverMaxTotal { total: f64, max_total: f64 },
+    /// Not enough balance including fees. ex) `insufficient_funds_bid`, `insufficient_funds_ask`
+    InsufficientFunds { required: f64, available: f64 },
+}
+
+/// Result of [OrderValidator::check]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderValidation {
+    pub rejections: Vec<OrderRejection>,
+    /// Corrected order with the price rounded to the tick size and the volume capped,
+    /// if the rejections can be fixed that way
+    pub suggestion: Option<OrderProposal>,
+}
+
+impl OrderValidation {
+    pub fn is_valid(&self) -> bool {
+        self.rejections.is_empty()
+    }
+}
+
+/// Checks orders locally against cached [OrderChance]s before they are sent
+pub struct OrderValidator {
+    chances: HashMap<String, (Instant, OrderChance)>,
+    ttl: Duration,
+}
+
+impl OrderValidator {
+    /// `ttl` is how long an [OrderChance] is reused before being fetched again
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            chances: HashMap::new(),
+            ttl,
+        }
+    }
+
+    /// Get the cached order chance of the market, fetching it if missing or stale
+    ///
+    /// Fails with `notfoundmarket` if the exchange answers with the order chance of a market id other than `market_id`,
+    /// ex) `KRW-ETH` for `krw-eth`.
+    pub async fn order_chance(&mut self, market_id: &str) -> Result<&OrderChance, ResponseError> {
+        let is_fresh = self
+            .chances
+            .get(market_id)
+            .is_some_and(|(fetched_at, _)| fetched_at.elapsed() < self.ttl);
+
+        if !is_fresh {
+            let order_chance = OrderChance::get_order_chance(market_id).await?;
+            self.insert(order_chance);
+        }
+
+        self.chances
+            .get(market_id)
+            .map(|(_, order_chance)| order_chance)
+            .ok_or_else(|| ResponseError {
+                state: ResponseErrorState::NotFoundMarket,
+                error: ResponseErrorBody {
+                    name: "notfoundmarket".to_owned(),
+                    message: format!(
+                        "order chance of {market_id} is returned for another market id"
+                    ),
+                },
+            })
+    }
+
+    /// Cache an order chance fetched elsewhere
+    pub fn insert(&mut self, order_chance: OrderChance) {
+        self.chances.insert(
+            order_chance.market.id.clone(),
+            (Instant::now(), order_chance),
+        );
+    }
+
+    /// Drop the cached order chance, ex) after an order has been filled and balances changed
+    pub fn invalidate(&mut self, market_id: &str) {
+        self.chances.remove(market_id);
+    }
+
+    /// Check the proposal against the cached order chance of its market
+    pub async fn validate(
+        &mut self,
+        proposal: &OrderProposal,
+    ) -> Result<OrderValidation, ResponseError> {
+        let order_chance = self.order_chance(&proposal.market).await?;
+
+        Ok(Self::check(order_chance, proposal))
+    }
+
+    /// Check the proposal against the given order chance
+    pub fn check(order_chance: &OrderChance, proposal: &OrderProposal) -> OrderValidation {
+        let mut rejections = Vec::new();
+        let mut fatal = false;
+        let market = &order_chance.market;
+
+        if market.id != proposal.market {
+            fatal = true;
+            rejections.push(OrderRejection::MarketMismatch {
+                expected: market.id.clone(),
+            });
+        }
+
+        if market.state != "active" {
+            fatal = true;
+            rejections.push(OrderRejection::MarketNotActive {
+                state: market.state.clone(),
+            });
+        }
+
+        if !market.order_sides.contains(&proposal.side) {
+            fatal = true;
+            rejections.push(OrderRejection::SideNotSupported(proposal.side));
+        }
+
+        if !is_ord_type_supported(order_chance, proposal) {
+            fatal = true;
+            rejections.push(OrderRejection::OrdTypeNotSupported {
+                ord_type: proposal.ord_type,
+                time_in_force: proposal.time_in_force,
+            });
+        }
+
+        let needs_volume = matches!(proposal.ord_type, OrderType::Limit | OrderType::Market)
+            || (proposal.ord_type == OrderType::Best && proposal.side == OrderSide::Ask);
+        let needs_price = matches!(proposal.ord_type, OrderType::Limit | OrderType::Price)
+            || (proposal.ord_type == OrderType::Best && proposal.side == OrderSide::Bid);
+
+        if needs_volume && !proposal.volume.is_some_and(|volume| volume > 0.0) {
+            fatal = true;
+            rejections.push(OrderRejection::InvalidVolume);
+        }
+
+        if needs_price && !proposal.price.is_some_and(|price| price > 0.0) {
+            fatal = true;
+            rejections.push(OrderRejection::InvalidPrice);
+        }
+
+        if fatal {
+            return OrderValidation {
+                rejections,
+                suggestion: None,
+            };
+        }
+
+        let (fee, min_total, available) = match proposal.side {
+            OrderSide::Bid => (
+                fee_of(order_chance.bid_fee),
+                market.bid.min_total as f64,
+                order_chance.bid_account.balance,
+            ),
+            OrderSide::Ask => (
+                fee_of(order_chance.ask_fee),
+                market.ask.min_total as f64,
+                order_chance.ask_account.balance,
+            ),
+        };
+        let max_total = market.max_total as f64;
+
+        // the limit price has to fit the tick size, while `price`/`best` bids carry the total amount instead
+        let mut price = proposal.price;
+
+        if let (OrderType::Limit, Some(limit_price)) = (proposal.ord_type, proposal.price) {
+            if let Some(unit) = price_unit_of(order_chance, proposal.side, limit_price) {
+                if !fits_price_unit(limit_price, unit) {
+                    rejections.push(OrderRejection::InvalidPriceUnit {
+                        price: limit_price,
+                        price_unit: unit,
+                    });
+                    price = Some(f64::trunc(limit_price / unit) * unit);
+                }
+            }
+        }
+
+        let total = total_of(proposal.ord_type, proposal.volume, proposal.price);
+
+        if let Some(total) = total {
+            if total < min_total {
+                rejections.push(OrderRejection::UnderMinTotal { total, min_total });
+            }
+
+            if total > max_total {
+                rejections.push(OrderRejection::OverMaxTotal { total, max_total });
+            }
+        }
+
+        let required = match proposal.side {
+            OrderSide::Bid => total.map(|total| total * (1.0 + fee)),
+            OrderSide::Ask => proposal.volume,
+        };
+
+        if let Some(required) = required {
+            if required > available {
+                rejections.push(OrderRejection::InsufficientFunds {
+                    required,
+                    available,
+                });
+            }
+        }
+
+        if rejections.is_empty() {
+            return OrderValidation {
+                rejections,
+                suggestion: None,
+            };
+        }
+
+        // cap the order by the balance and the maximum total, then make sure it still reaches the minimum
+        let mut volume = proposal.volume;
+
+        match (proposal.side, proposal.ord_type) {
+            (OrderSide::Bid, OrderType::Limit) => {
+                let limit_price = price.unwrap_or_default();
+                let budget = f64::min(available / (1.0 + fee), max_total);
+                volume =
+                    volume.map(|volume| truncate_volume(f64::min(volume, budget / limit_price)));
+            }
+            (OrderSide::Bid, _) => {
+                let budget = f64::min(available / (1.0 + fee), max_total);
+                price = price.map(|amount| f64::trunc(f64::min(amount, budget)));
+            }
+            (OrderSide::Ask, ord_type) => {
+                let mut cap = available;
+
+                if let (OrderType::Limit, Some(limit_price)) = (ord_type, price) {
+                    cap = f64::min(cap, max_total / limit_price);
+                }
+
+                volume = volume.map(|volume| truncate_volume(f64::min(volume, cap)));
+            }
+        }
+
+        let suggestion = OrderProposal {
+            volume,
+            price,
+            ..proposal.clone()
+        };
+
+        let is_fixable = total_of(suggestion.ord_type, suggestion.volume, suggestion.price)
+            .is_none_or(|total| total >= min_total)
+            && suggestion.volume.is_none_or(|volume| volume > 0.0);
+
+        OrderValidation {
+            rejections,
+            suggestion: is_fixable.then_some(suggestion),
+        }
+    }
+}
+
+fn is_ord_type_supported(order_chance: &OrderChance, proposal: &OrderProposal) -> bool {
+    let market = &order_chance.market;
+
+    match proposal.side {
+        OrderSide::Bid => {
+            let bid_type = match (proposal.ord_type, proposal.time_in_force) {
+                (OrderType::Limit, None) => BidType::Limit,
+                (OrderType::Limit, Some(OrderCondition::IOK)) => BidType::LimitIOC,
+                (OrderType::Limit, Some(OrderCondition::FOK)) => BidType::LimitFOK,
+                (OrderType::Price, None) => BidType::Price,
+                (OrderType::Best, Some(OrderCondition::IOK)) => BidType::BestIOC,
+                (OrderType::Best, Some(OrderCondition::FOK)) => BidType::BestFOK,
+                _ => return false,
+            };
+
+            market
+                .bid_types
+                .as_ref()
+                .is_none_or(|bid_types| bid_types.contains(&bid_type))
+        }
+        OrderSide::Ask => {
+            let ask_type = match (proposal.ord_type, proposal.time_in_force) {
+                (OrderType::Limit, None) => AskType::Limit,
+                (OrderType::Limit, Some(OrderCondition::IOK)) => AskType::LimitIOC,
+                (OrderType::Limit, Some(OrderCondition::FOK)) => AskType::LimitFOK,
+                (OrderType::Market, None) => AskType::Market,
+                (OrderType::Best, Some(OrderCondition::IOK)) => AskType::BestIOC,
+                (OrderType::Best, Some(OrderCondition::FOK)) => AskType::BestFOK,
+                _ => return false,
+            };
+
+            market
+                .ask_types
+                .as_ref()
+                .is_none_or(|ask_types| ask_types.contains(&ask_type))
+        }
+    }
+}
+
+/// Total amount of the order in the quote currency, if it is known before execution
+fn total_of(ord_type: OrderType, volume: Option<f64>, price: Option<f64>) -> Option<f64> {
+    match ord_type {
+        OrderType::Limit => Some(volume? * price?),
+        OrderType::Price | OrderType::Best => price,
+        OrderType::Market => None,
+    }
+}
+
+/// Widen the fee rate without picking up the binary error of [f32], ex) `0.0005`
+fn fee_of(fee: f32) -> f64 {
+    fee.to_string().parse().unwrap_or(fee as f64)
+}
+
+/// Tick size of `price`, given by the order chance or by [price_unit] on KRW markets
+///
+/// `None` for the other markets whose order chance has no `price_unit`, as their tick sizes are not known.
+fn price_unit_of(order_chance: &OrderChance, side: OrderSide, price: f64) -> Option<f64> {
+    let market = &order_chance.market;
+    let ask_bid = match side {
+        OrderSide::Bid => &market.bid,
+        OrderSide::Ask => &market.ask,
+    };
+
+    match ask_bid
+        .price_unit
+        .as_deref()
+        .and_then(|unit| unit.parse::<f64>().ok())
+        .filter(|unit| *unit > 0.0)
+    {
+        Some(unit) => Some(unit),
+        None if market.id.starts_with("KRW-") => Some(price_unit(price)),
+        None => None,
+    }
+}
+
+fn fits_price_unit(price: f64, unit: f64) -> bool {
+    let ticks = price / unit;
+
+    (ticks - ticks.round()).abs() < 1e-6
+}
+
+fn truncate_volume(volume: f64) -> f64 {
+    f64::trunc(volume * 100_000_000.0) / 100_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order_chance(krw_balance: f64, eth_balance: f64) -> OrderChance {
+        serde_json::from_value(serde_json::json!({
+            "bid_fee": 0.0005,
+            "ask_fee": 0.0005,
+            "maker_bid_fee": 0.0005,
+            "maker_ask_fee": 0.0005,
+            "market": {
+                "id": "KRW-ETH",
+                "name": "ETH/KRW",
+                "order_sides": ["Ask", "Bid"],
+                "bid_types": ["BestFOK", "BestIOC", "Limit", "LimitFOK", "LimitIOC", "Price"],
+                "ask_types": ["BestFOK", "BestIOC", "Limit", "LimitFOK", "LimitIOC", "Market"],
+                "bid": { "currency": "KRW", "price_unit": null, "min_total": 5000 },
+                "ask": { "currency": "ETH", "price_unit": null, "min_total": 5000 },
+                "max_total": 1_000_000_000u64,
+                "state": "active"
+            },
+            "bid_account": {
+                "currency": "KRW",
+                "balance": krw_balance,
+                "locked": 0.0,
+                "avg_buy_price": 0.0,
+                "avg_buy_price_modified": true,
+                "unit_currency": "KRW"
+            },
+            "ask_account": {
+                "currency": "ETH",
+                "balance": eth_balance,
+                "locked": 0.0,
+                "avg_buy_price": 3_000_000.0,
+                "avg_buy_price_modified": false,
+                "unit_currency": "KRW"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn limit_bid(volume: f64, price: f64) -> OrderProposal {
+        OrderProposal {
+            market: "KRW-ETH".to_owned(),
+            side: OrderSide::Bid,
+            ord_type: OrderType::Limit,
+            volume: Some(volume),
+            price: Some(price),
+            time_in_force: None,
+        }
+    }
+
+    #[test]
+    fn test_check_valid_order() {
+        let validation =
+            OrderValidator::check(&order_chance(100_000.0, 0.0), &limit_bid(0.01, 3_000_000.0));
+
+        assert!(validation.is_valid());
+        assert_eq!(validation.suggestion, None);
+    }
+
+    #[test]
+    fn test_check_rounds_price_and_caps_volume() {
+        let validation =
+            OrderValidator::check(&order_chance(20_000.0, 0.0), &limit_bid(0.01, 3_000_300.0));
+
+        assert_eq!(
+            validation.rejections,
+            vec![
+                OrderRejection::InvalidPriceUnit {
+                    price: 3_000_300.0,
+                    price_unit: 1000.0
+                },
+                OrderRejection::InsufficientFunds {
+                    required: 30_003.0 * 1.0005,
+                    available: 20_000.0
+                },
+            ]
+        );
+
+        let suggestion = validation.suggestion.unwrap();
+        assert_eq!(suggestion.price, Some(3_000_000.0));
+        assert!(suggestion.volume.unwrap() * 3_000_000.0 * (1.0 + 0.0005) <= 20_000.0);
+    }
+
+    #[test]
+    fn test_check_price_unit_of_non_krw_market() {
+        let mut order_chance = order_chance(0.0, 0.0);
+        order_chance.market.id = "BTC-ETH".to_owned();
+        order_chance.market.bid.currency = "BTC".to_owned();
+        order_chance.market.bid.min_total = 0;
+        order_chance.bid_account.balance = 1.0;
+        let proposal = OrderProposal {
+            market: "BTC-ETH".to_owned(),
+            ..limit_bid(0.1, 0.03512345)
+        };
+
+        // no tick size is known without the price unit of the order chance
+        assert!(OrderValidator::check(&order_chance, &proposal).is_valid());
+
+        order_chance.market.bid.price_unit = Some("0.0001".to_owned());
+        let validation = OrderValidator::check(&order_chance, &proposal);
+
+        assert_eq!(
+            validation.rejections,
+            vec![OrderRejection::InvalidPriceUnit {
+                price: 0.03512345,
+                price_unit: 0.0001
+            }]
+        );
+        assert!((validation.suggestion.unwrap().price.unwrap() - 0.0351).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_check_under_min_total() {
+        let validation = OrderValidator::check(
+            &order_chance(100_000.0, 0.0),
+            &limit_bid(0.001, 3_000_000.0),
+        );
+
+        assert_eq!(
+            validation.rejections,
+            vec![OrderRejection::UnderMinTotal {
+                total: 3000.0,
+                min_total: 5000.0
+            }]
+        );
+        assert_eq!(validation.suggestion, None);
+    }
+
+    #[test]
+    fn test_check_not_supported_ord_type() {
+        let proposal = OrderProposal {
+            ord_type: OrderType::Market,
+            ..limit_bid(0.01, 3_000_000.0)
+        };
+        let validation = OrderValidator::check(&order_chance(100_000.0, 0.0), &proposal);
+
+        assert_eq!(
+            validation.rejections,
+            vec![OrderRejection::OrdTypeNotSupported {
+                ord_type: OrderType::Market,
+                time_in_force: None
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_insufficient_funds_ask() {
+        let proposal = OrderProposal {
+            side: OrderSide::Ask,
+            ..limit_bid(0.5, 3_000_000.0)
+        };
+        let validation = OrderValidator::check(&order_chance(0.0, 0.1), &proposal);
+
+        assert_eq!(
+            validation.rejections,
+            vec![OrderRejection::InsufficientFunds {
+                required: 0.5,
+                available: 0.1
+            }]
+        );
+        assert_eq!(validation.suggestion.unwrap().volume, Some(0.1));
+    }
+
+    #[tokio::test]
+    async fn test_order_chance_of_another_market_id() {
+        let mut interactions: Vec<crate::fixture::Interaction> =
+            serde_json::from_str(include_str!("../../tests/fixtures/get_order_chance.json"))
+                .unwrap();
+        interactions[0].url = interactions[0].url.replace("KRW-ETH", "krw-eth");
+
+        let mut validator = OrderValidator::new(Duration::from_secs(60));
+        let result = crate::transport::scope(
+            crate::fixture::ReplayTransport::new(interactions),
+            crate::credential::scope(
+                crate::credential::Credentials::new("access key", "secret key"),
+                validator.order_chance("krw-eth"),
+            ),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err().state,
+            ResponseErrorState::NotFoundMarket
+        );
+    }
+}
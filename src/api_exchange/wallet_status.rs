@@ -0,0 +1,163 @@
+use reqwest::header::{ACCEPT, AUTHORIZATION};
+
+use crate::request::Request;
+use crate::transport::{HttpRequest, HttpResponse};
+
+use super::{
+    super::constant::{URL_SERVER, URL_STATUS_WALLET},
+    super::response::ResponseError,
+    super::response::{WalletStatus, WalletStatusSource},
+};
+
+impl WalletStatus {
+    pub async fn get_wallet_status() -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request().await?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .ok()
+                .unwrap());
+        }
+
+        Self::deserialize_wallet_status_response(&res_serialized)
+    }
+
+    async fn request() -> Result<HttpResponse, ResponseError> {
+        let token_string = Self::set_token()?;
+
+        HttpRequest::get(format!("{URL_SERVER}{URL_STATUS_WALLET}"))
+            .header(ACCEPT, "application/json")
+            .header(AUTHORIZATION, &token_string)
+            .send()
+            .await
+    }
+
+    fn deserialize_wallet_status_response(
+        res_serialized: &str,
+    ) -> Result<Vec<Self>, ResponseError> {
+        serde_json::from_str(res_serialized)
+            .map(|i: Vec<WalletStatusSource>| {
+                i.into_iter()
+                    .map(|x| Self {
+                        currency: x.currency(),
+                        wallet_state: x.wallet_state(),
+                        block_state: x.block_state(),
+                        block_height: x.block_height(),
+                        block_updated_at: x.block_updated_at(),
+                        block_elapsed_minutes: x.block_elapsed_minutes(),
+                        net_type: x.net_type(),
+                        network_name: x.network_name(),
+                    })
+                    .collect::<Vec<Self>>()
+            })
+            .map_err(crate::response::response_error_from_json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use serde_json::Value;
+
+    use crate::response::{BlockState, WalletState};
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_wallet_status() {
+        let res = WalletStatus::deserialize_wallet_status_response(
+            r#"[
+                {
+                    "currency": "BTC",
+                    "wallet_state": "working",
+                    "block_state": "normal",
+                    "block_height": 849123,
+                    "block_updated_at": "2024-06-25T06:43:48.392+00:00",
+                    "block_elapsed_minutes": 1,
+                    "net_type": "BTC",
+                    "network_name": "Bitcoin"
+                },
+                {
+                    "currency": "XRP",
+                    "wallet_state": "withdraw_only",
+                    "block_state": null,
+                    "block_height": null,
+                    "block_updated_at": null,
+                    "block_elapsed_minutes": null,
+                    "net_type": "XRP",
+                    "network_name": "Ripple"
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(res[0].wallet_state, WalletState::Working);
+        assert_eq!(res[0].block_state, Some(BlockState::Normal));
+        assert!(res[0].block_updated_at.is_some());
+        assert!(!res[1].wallet_state.can_deposit());
+        assert!(res[1].wallet_state.can_withdraw());
+        assert_eq!(res[1].block_height, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_wallet_status() {
+        crate::fixture::test_scope("get_wallet_status", async {
+            let res = WalletStatus::request().await.unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
+
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_keys = [
+                "currency",
+                "wallet_state",
+                "block_state",
+                "block_height",
+                "block_updated_at",
+                "block_elapsed_minutes",
+                "net_type",
+                "network_name",
+            ]
+            .iter()
+            .cloned()
+            .collect::<HashSet<&str>>();
+
+            if let Value::Array(json) = json {
+                if json.is_empty() {
+                    println!("[get_wallet_status] The JSON is empty.");
+                }
+
+                if let Value::Object(map) = &json[0] {
+                    let json_keys = map.keys().map(|k| k.as_str()).collect::<HashSet<&str>>();
+                    let unexpected_keys =
+                        json_keys.difference(&expected_keys).collect::<HashSet<_>>();
+                    let missing_keys = expected_keys.difference(&json_keys).collect::<HashSet<_>>();
+
+                    if !unexpected_keys.is_empty() {
+                        println!("[get_wallet_status] Unexpected keys found: {unexpected_keys:?}");
+                        assert!(false);
+                    } else {
+                        println!("[get_wallet_status] No unexpected keys found.");
+                    }
+
+                    if !missing_keys.is_empty() {
+                        println!("[get_wallet_status] Missing keys: {missing_keys:?}");
+                        assert!(false);
+                    } else {
+                        println!("[get_wallet_status] No keys are missing.");
+                    }
+                } else {
+                    println!("[get_wallet_status] The JSON is not an object.");
+                }
+            }
+
+            assert!(true);
+        })
+        .await;
+    }
+}
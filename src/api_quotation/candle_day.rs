@@ -4,7 +4,8 @@ use super::super::constant::URL_SERVER;
 use super::UrlAssociates;
 
 use reqwest::header::ACCEPT;
-use reqwest::{Response, Url};
+use reqwest::Url;
+use crate::transport::{HttpRequest, HttpResponse};
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -41,10 +42,7 @@ impl CandleChartDay {
         price_unit: Option<&str>,
     ) -> Result<Vec<Self>, ResponseError> {
         let res = Self::request(market_id, count, last_candle_time, price_unit).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -82,7 +80,7 @@ impl CandleChartDay {
         count: u8,
         last_candle_time: Option<&str>,
         price_unit: Option<&str>,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let url_candle = UrlAssociates::UrlCandleDay.to_string();
         let mut url = Url::parse(&format!("{URL_SERVER}{url_candle}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
@@ -101,12 +99,10 @@ impl CandleChartDay {
                 .append_pair("convertingPriceUnit", price_unit);
         }
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -120,85 +116,81 @@ mod tests {
 
     #[tokio::test]
     async fn test_request_candle_day() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = CandleChartDay::request("KRW-ETH", 1, None, None)
-            .await
-            .unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
-
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
-
-        let json = serde_json::from_str::<Value>(&res_serialized)
-            .map_err(crate::response::response_error_from_json)
-            .unwrap();
-        let expected_structure = serde_json::json!([{
-            "market": "",
-            "candle_date_time_utc": "",
-            "candle_date_time_kst": "",
-            "opening_price": "",
-            "high_price": "",
-            "low_price": "",
-            "trade_price": "",
-            "timestamp": "",
-            "candle_acc_trade_price": "",
-            "candle_acc_trade_volume": "",
-            "prev_closing_price": "",
-            "change_price": "",
-            "change_rate": "",
-            // "converted_trade_price": ""
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
-
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_request_candle_day] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_request_candle_day] No keys are missing in item[{}]",
-                        index
-                    );
-                }
+        crate::fixture::test_scope("get_candle_of_day", async {
+            let res = CandleChartDay::request("KRW-ETH", 1, None, None)
+                .await
+                .unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_request_candle_day] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_request_candle_day] No extra keys found in item[{}]",
-                        index
-                    );
+            let json = serde_json::from_str::<Value>(&res_serialized)
+                .map_err(crate::response::response_error_from_json)
+                .unwrap();
+            let expected_structure = serde_json::json!([{
+                "market": "",
+                "candle_date_time_utc": "",
+                "candle_date_time_kst": "",
+                "opening_price": "",
+                "high_price": "",
+                "low_price": "",
+                "trade_price": "",
+                "timestamp": "",
+                "candle_acc_trade_price": "",
+                "candle_acc_trade_volume": "",
+                "prev_closing_price": "",
+                "change_price": "",
+                "change_rate": "",
+                // "converted_trade_price": ""
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_request_candle_day] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_request_candle_day] No keys are missing in item[{}]",
+                            index
+                        );
+                    }
+
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_request_candle_day] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_request_candle_day] No extra keys found in item[{}]",
+                            index
+                        );
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -1,5 +1,6 @@
 use reqwest::header::ACCEPT;
-use reqwest::{Response, Url};
+use reqwest::Url;
+use crate::transport::{HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 
 use super::super::constant::{URL_MARKET_STATE, URL_SERVER};
@@ -44,10 +45,7 @@ pub struct MarketStateSource {
 impl MarketState {
     pub async fn get_market_state_list(is_detailed: bool) -> Result<Vec<Self>, ResponseError> {
         let res = Self::request(is_detailed).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -71,18 +69,16 @@ impl MarketState {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(is_detailed: bool) -> Result<Response, ResponseError> {
+    async fn request(is_detailed: bool) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_MARKET_STATE}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut()
             .append_pair("isDetails", is_detailed.to_string().as_str());
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -96,71 +92,67 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_market_state_list() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = MarketState::request(true).await.unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
-
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+        crate::fixture::test_scope("get_market_state", async {
+            let res = MarketState::request(true).await.unwrap();
+            let res_serialized = res.text();
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "market": "",
-            "korean_name": "",
-            "english_name": "",
-            "market_warning": "",
-            "market_event": {
-                "warning": "",
-                "caution": {
-                    "CONCENTRATION_OF_SMALL_ACCOUNTS": "",
-                    "DEPOSIT_AMOUNT_SOARING": "",
-                    "GLOBAL_PRICE_DIFFERENCES": "",
-                    "PRICE_FLUCTUATIONS": "",
-                    "TRADING_VOLUME_SOARING": "",
-                }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
             }
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
-
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_get_market_state] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false, "Missing keys found");
+
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "market": "",
+                "korean_name": "",
+                "english_name": "",
+                "market_warning": "",
+                "market_event": {
+                    "warning": "",
+                    "caution": {
+                        "CONCENTRATION_OF_SMALL_ACCOUNTS": "",
+                        "DEPOSIT_AMOUNT_SOARING": "",
+                        "GLOBAL_PRICE_DIFFERENCES": "",
+                        "PRICE_FLUCTUATIONS": "",
+                        "TRADING_VOLUME_SOARING": "",
+                    }
                 }
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_market_state] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false, "Missing keys found");
+                    }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_market_state] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false, "Extra keys found");
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_market_state] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false, "Extra keys found");
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -3,7 +3,8 @@ use crate::response::ResponseError;
 use super::super::constant::{URL_ORDERBOOK, URL_SERVER};
 
 use reqwest::header::ACCEPT;
-use reqwest::{Response, Url};
+use reqwest::Url;
+use crate::transport::{HttpRequest, HttpResponse};
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -26,10 +27,7 @@ pub struct OrderBookUnit {
 impl OrderBookInfo {
     pub async fn get_orderbook_info_list(markets_id: &[&str]) -> Result<Vec<Self>, ResponseError> {
         let res = Self::request(markets_id).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -62,18 +60,16 @@ impl OrderBookInfo {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(markets_id: &[&str]) -> Result<Response, ResponseError> {
+    async fn request(markets_id: &[&str]) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDERBOOK}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut()
             .append_pair("markets", &markets_id.join(","));
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -87,80 +83,76 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_order_book() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = OrderBookInfo::request(&["KRW-ETH"]).await.unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
+        crate::fixture::test_scope("get_order_book", async {
+            let res = OrderBookInfo::request(&["KRW-ETH"]).await.unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "market": "",
-            "timestamp": "",
-            "total_ask_size": "",
-            "total_bid_size": "",
-            "level": "",
-            "orderbook_units": [
-                {
-                    "ask_price": "",
-                    "bid_price": "",
-                    "ask_size": "",
-                    "bid_size": ""
-                }
-            ]
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
-
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_get_order_status_closed] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_order_status_closed] No keys are missing in item[{}]",
-                        index
-                    );
-                }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "market": "",
+                "timestamp": "",
+                "total_ask_size": "",
+                "total_bid_size": "",
+                "level": "",
+                "orderbook_units": [
+                    {
+                        "ask_price": "",
+                        "bid_price": "",
+                        "ask_size": "",
+                        "bid_size": ""
+                    }
+                ]
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_order_status_closed] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_order_status_closed] No keys are missing in item[{}]",
+                            index
+                        );
+                    }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_order_status_closed] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_order_status_closed] No extra keys found in item[{}]",
-                        index
-                    );
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_order_status_closed] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_order_status_closed] No extra keys found in item[{}]",
+                            index
+                        );
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -5,6 +5,7 @@ use super::SnapshotChangeType;
 
 use reqwest::header::ACCEPT;
 use reqwest::Url;
+use crate::transport::{HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -70,10 +71,7 @@ pub struct TickerSnapshotSource {
 impl TickerSnapshot {
     pub async fn get_ticker_snapshot_list(markets_id: &[&str]) -> Result<Vec<Self>, ResponseError> {
         let res = Self::request(markets_id).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -118,18 +116,16 @@ impl TickerSnapshot {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(markets_id: &[&str]) -> Result<reqwest::Response, ResponseError> {
+    async fn request(markets_id: &[&str]) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_TICKER}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut()
             .append_pair("markets", &markets_id.join(","));
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -143,93 +139,89 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_ticker_snapshot() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = TickerSnapshot::request(&["KRW-ETH"]).await.unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
-
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
-
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "market": "",
-            "trade_date": "",
-            "trade_time": "",
-            "trade_date_kst": "",
-            "trade_time_kst": "",
-            "trade_timestamp": "",
-            "opening_price": "",
-            "high_price": "",
-            "low_price": "",
-            "trade_price": "",
-            "prev_closing_price": "",
-            "change": "",
-            "change_price": "",
-            "change_rate": "",
-            "signed_change_price": "",
-            "signed_change_rate": "",
-            "trade_volume": "",
-            "acc_trade_price": "",
-            "acc_trade_price_24h": "",
-            "acc_trade_volume": "",
-            "acc_trade_volume_24h": "",
-            "highest_52_week_price": "",
-            "highest_52_week_date": "",
-            "lowest_52_week_price": "",
-            "lowest_52_week_date": "",
-            "timestamp": ""
-        }]);
+        crate::fixture::test_scope("ticker_snapshot", async {
+            let res = TickerSnapshot::request(&["KRW-ETH"]).await.unwrap();
+            let res_serialized = res.text();
 
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_get_ticker_snapshot] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_ticker_snapshot] No keys are missing in item[{}]",
-                        index
-                    );
-                }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "market": "",
+                "trade_date": "",
+                "trade_time": "",
+                "trade_date_kst": "",
+                "trade_time_kst": "",
+                "trade_timestamp": "",
+                "opening_price": "",
+                "high_price": "",
+                "low_price": "",
+                "trade_price": "",
+                "prev_closing_price": "",
+                "change": "",
+                "change_price": "",
+                "change_rate": "",
+                "signed_change_price": "",
+                "signed_change_rate": "",
+                "trade_volume": "",
+                "acc_trade_price": "",
+                "acc_trade_price_24h": "",
+                "acc_trade_volume": "",
+                "acc_trade_volume_24h": "",
+                "highest_52_week_price": "",
+                "highest_52_week_date": "",
+                "lowest_52_week_price": "",
+                "lowest_52_week_date": "",
+                "timestamp": ""
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_ticker_snapshot] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_ticker_snapshot] No keys are missing in item[{}]",
+                            index
+                        );
+                    }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_ticker_snapshot] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_ticker_snapshot] No extra keys found in item[{}]",
-                        index
-                    );
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_ticker_snapshot] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_ticker_snapshot] No extra keys found in item[{}]",
+                            index
+                        );
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -3,7 +3,8 @@ use crate::response::ResponseError;
 use super::super::constant::{URL_SERVER, URL_TRADES_TICKS};
 
 use reqwest::header::ACCEPT;
-use reqwest::{Response, Url};
+use reqwest::Url;
+use crate::transport::{HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -29,10 +30,7 @@ impl TradeRecent {
         days_ago: Option<u8>,
     ) -> Result<Vec<Self>, ResponseError> {
         let res = Self::request(market_id, hhmmss, count, cursor, days_ago).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -69,7 +67,7 @@ impl TradeRecent {
         count: u32,
         cursor: &str,
         days_ago: Option<u8>,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_TRADES_TICKS}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut()
@@ -86,12 +84,10 @@ impl TradeRecent {
                 .append_pair("daysAgo", days_ago.to_string().as_str());
         }
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -105,79 +101,75 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_trade_recent_list() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = TradeRecent::request("KRW-ETH", Some("120101"), 1, "0", None)
-            .await
-            .unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
-
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
-
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "market": "",
-            "trade_date_utc": "",
-            "trade_time_utc": "",
-            "timestamp": "",
-            "trade_price": "",
-            "trade_volume": "",
-            "prev_closing_price": "",
-            "change_price": "",
-            "ask_bid": "",
-            "sequential_id": ""
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
-
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_get_trade_recent_list] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_trade_recent_list] No keys are missing in item[{}]",
-                        index
-                    );
-                }
+        crate::fixture::test_scope("get_trade_recent_list", async {
+            let res = TradeRecent::request("KRW-ETH", Some("120101"), 1, "0", None)
+                .await
+                .unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_trade_recent_list] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_trade_recent_list] No extra keys found in item[{}]",
-                        index
-                    );
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "market": "",
+                "trade_date_utc": "",
+                "trade_time_utc": "",
+                "timestamp": "",
+                "trade_price": "",
+                "trade_volume": "",
+                "prev_closing_price": "",
+                "change_price": "",
+                "ask_bid": "",
+                "sequential_id": ""
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_trade_recent_list] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_trade_recent_list] No keys are missing in item[{}]",
+                            index
+                        );
+                    }
+
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_trade_recent_list] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_trade_recent_list] No extra keys found in item[{}]",
+                            index
+                        );
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -1,13 +1,21 @@
 mod withdraw_address;
+mod withdraw_cancel;
 mod withdraw_chance;
 mod withdraw_coin;
+mod withdraw_guard;
 mod withdraw_info;
 mod withdraw_info_list;
 mod withdraw_krw;
+mod withdraw_plan;
 
 use core::fmt::Display;
+use std::time::Duration;
 
-use super::response::{ResponseError, TransactionInfo, TransactionInfoDerived};
+use serde::{Deserialize, Serialize};
+
+use super::response::{
+    ResponseError, TransferCheckpoint, TransferHistory, TwoFactorTracker, WithdrawalRecord,
+};
 use crate::{
     constant::{OrderBy, TransactionType, TwoFactorType},
     response::{WithdrawChance, WithdrawCoinAddress},
@@ -16,9 +24,14 @@ use crate::{
 #[cfg(feature = "sqlx-type")]
 use sqlx::Type;
 
-/// List of withdraw state
-#[derive(Debug, Copy, Clone)]
+pub use withdraw_guard::{
+    AllowedAddress, WithdrawalApprover, WithdrawalError, WithdrawalGuard, WithdrawalRejection,
+    WithdrawalRequest,
+};
+pub use withdraw_plan::{WithdrawalPlan, WithdrawalTarget};
 
+/// List of withdraw state
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(
     feature = "sqlx-type",
     derive(sqlx::Type),
@@ -53,20 +66,26 @@ impl Display for WithdrawState {
     }
 }
 
+/// Accepts the uppercase values of the response as well as their lowercase form
 impl From<&str> for WithdrawState {
     fn from(value: &str) -> Self {
-        match value {
-            "waiting" => WithdrawState::Waiting,
-            "processing" => WithdrawState::Processing,
-            "done" => WithdrawState::Done,
-            "failed" => WithdrawState::Failed,
-            "canceled" => WithdrawState::Canceled,
-            "rejected" => WithdrawState::Rejected,
-            a => panic!("Unexpected value: {}", a),
+        match value.to_ascii_uppercase().as_str() {
+            "WAITING" => WithdrawState::Waiting,
+            "PROCESSING" => WithdrawState::Processing,
+            "DONE" => WithdrawState::Done,
+            "FAILED" => WithdrawState::Failed,
+            "CANCELED" | "CANCELLED" => WithdrawState::Canceled,
+            "REJECTED" => WithdrawState::Rejected,
+            _ => panic!("Unexpected value: {}", value),
         }
     }
 }
 
+/// Whether coins can be withdrawn from a wallet in this `wallet_state`
+fn withdrawable_wallet_state(wallet_state: &str) -> bool {
+    matches!(wallet_state, "working" | "withdraw_only")
+}
+
 /// 출금 기록을 조회한다. (inquiry the records of withdraws.)
 ///
 /// # Example
@@ -135,7 +154,7 @@ impl From<&str> for WithdrawState {
 /// | currency | 화폐를 의미하는 영문 대문자 코드 | String
 /// | net_type | 출금 네트워크 | String
 /// | txid | 출금의 트랜잭션 아이디 | String
-/// | state | 출금 상태<br> - WAITING : 대기중<br> - PROCESSING : 진행중<br> - DONE : 완료<br> - FAILED : 실패<br> - CANCELLED : 취소됨<br> - REJECTED : 거절됨 | String
+/// | state | 출금 상태<br> - WAITING : 대기중<br> - PROCESSING : 진행중<br> - DONE : 완료<br> - FAILED : 실패<br> - CANCELED : 취소됨<br> - REJECTED : 거절됨 | String
 /// | created_at | 출금 생성 시간 | DateString
 /// | done_at | 출금 완료 시간 | DateString
 /// | amount | 출금 금액/수량 | NumberString
@@ -149,11 +168,36 @@ pub async fn get_withdraw_info_list(
     limit: u32,
     page: u32,
     order_by: OrderBy,
-) -> Result<Vec<TransactionInfo>, ResponseError> {
-    TransactionInfo::get_withdraw_info_list(currency, state, uuids, txids, limit, page, order_by)
+) -> Result<Vec<WithdrawalRecord>, ResponseError> {
+    WithdrawalRecord::get_withdraw_info_list(currency, state, uuids, txids, limit, page, order_by)
         .await
 }
 
+/// 출금 기록 전체를 모든 상태에 걸쳐 `from` 커서로 순회한다. (iterate over every withdrawal record across every state with the `from` cursor.)
+///
+/// # Example
+/// ```rust
+/// use response::TransferCheckpoint;
+///
+/// let mut withdraw_history = api_withdraw::get_withdraw_history(Some("ETH"), TransferCheckpoint::default());
+///
+/// while let Some(withdrawal) = withdraw_history.next().await? {
+///     // ...
+/// }
+///
+/// // store it to resume the sync later
+/// let checkpoint = withdraw_history.checkpoint().clone();
+/// ```
+/// - parameters
+/// > `currency` ex) KRW, BTC, ETH etc. every currency of the accounts if `None` <br>
+/// > `checkpoint` checkpoint of the previous sync, `TransferCheckpoint::default()` to sync from the beginning <br>
+pub fn get_withdraw_history(
+    currency: Option<&str>,
+    checkpoint: TransferCheckpoint,
+) -> TransferHistory<WithdrawalRecord> {
+    TransferHistory::new(currency, checkpoint)
+}
+
 /// 개별 출금 조회.
 ///
 /// # Example
@@ -190,7 +234,7 @@ pub async fn get_withdraw_info_list(
 /// | currency | 화폐를 의미하는 영문 대문자 코드 | String
 /// | net_type | 출금 네트워크 | String
 /// | txid | 출금의 트랜잭션 아이디 | String
-/// | state | 출금 상태<br> - WAITING : 대기중<br> - PROCESSING : 진행중<br> - DONE : 완료<br> - FAILED : 실패<br> - CANCELLED : 취소됨<br> - REJECTED : 거절됨 | String
+/// | state | 출금 상태<br> - WAITING : 대기중<br> - PROCESSING : 진행중<br> - DONE : 완료<br> - FAILED : 실패<br> - CANCELED : 취소됨<br> - REJECTED : 거절됨 | String
 /// | created_at | 출금 생성 시간 | DateString
 /// | done_at | 출금 완료 시간 | DateString
 /// | amount | 출금 금액/수량 | NumberString
@@ -200,8 +244,8 @@ pub async fn get_withdraw_info(
     currency: Option<&str>,
     uuid: Option<&str>,
     txid: Option<&str>,
-) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::get_withdraw_info(currency, uuid, txid).await
+) -> Result<WithdrawalRecord, ResponseError> {
+    WithdrawalRecord::get_withdraw_info(currency, uuid, txid).await
 }
 
 /// 출금 가능 정보를 조회한다.
@@ -346,8 +390,8 @@ pub async fn withdraw_coin(
     address: &str,
     secondary_address: Option<&str>,
     transaction_type: TransactionType,
-) -> Result<TransactionInfoDerived, ResponseError> {
-    TransactionInfoDerived::withdraw_coin(
+) -> Result<WithdrawalRecord, ResponseError> {
+    WithdrawalRecord::withdraw_coin(
         currency,
         net_type,
         amount,
@@ -368,7 +412,7 @@ pub async fn withdraw_coin(
 /// - parameters
 /// > `amount` amount of withdraw <br>
 /// > `two_factor_type`
-/// >> * `TwoFactorType::KakaoPay` Two factor identification via kakao <br>
+/// >> * `TwoFactorType::Kakao` Two factor identification via kakao <br>
 /// >> * `TwoFactorType::Naver` Two factor identification via naver <br>
 /// # Response
 /// ```json
@@ -402,8 +446,93 @@ pub async fn withdraw_coin(
 pub async fn withdraw_krw(
     amount: f64,
     two_factor_type: TwoFactorType,
-) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::withdraw_krw(amount, two_factor_type).await
+) -> Result<WithdrawalRecord, ResponseError> {
+    WithdrawalRecord::withdraw_krw(amount, two_factor_type).await
+}
+
+/// 원화를 출금하고, 카카오/네이버 인증 후 출금이 완료되거나 거절될 때까지 추적한다. (withdraw KRW and track it until it is approved, rejected or timed out.)
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use response::TwoFactorEvent;
+///
+/// let mut withdraw_tracker = api_withdraw::withdraw_krw_tracked(
+///     10000.0,
+///     api_withdraw::TwoFactorType::Naver,
+///     Duration::from_secs(3),
+///     Duration::from_secs(300),
+/// ).await?;
+///
+/// for event in withdraw_tracker.wait().await? {
+///     // notify the event
+/// }
+/// ```
+/// - parameters
+/// > `amount` amount of withdraw <br>
+/// > `two_factor_type`
+/// >> * `TwoFactorType::Kakao` Two factor identification via kakao <br>
+/// >> * `TwoFactorType::Naver` Two factor identification via naver <br>
+///
+/// > `poll_interval` interval of checking the withdrawal with `get_withdraw_info` <br>
+/// > `timeout` how long to wait for the approval before `TwoFactorEvent::TimedOut` <br>
+pub async fn withdraw_krw_tracked(
+    amount: f64,
+    two_factor_type: TwoFactorType,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> Result<TwoFactorTracker<WithdrawalRecord>, ResponseError> {
+    let withdrawal = WithdrawalRecord::withdraw_krw(amount, two_factor_type).await?;
+
+    Ok(TwoFactorTracker::new(withdrawal, poll_interval, timeout))
+}
+
+/// 출금을 취소한다. (cancel a withdraw which is not processed yet.)
+///
+/// # Example
+/// ```rust
+/// let withdraw_info = api_withdraw::get_withdraw_info(None, Some("9f432943-54e0-40b7-825f-b6fec8b42b79"), None).await.unwrap();
+///
+/// if withdraw_info.cancelable {
+///     let canceled_withdraw = api_withdraw::cancel_withdraw(&withdraw_info.uuid).await;
+/// }
+/// ```
+/// - parameters
+/// > `uuid` uuid of withdraw to cancel<br>
+/// # Response
+/// ```json
+/// {
+///     "type": "withdraw",
+///     "uuid": "9f432943-54e0-40b7-825f-b6fec8b42b79",
+///     "currency": "BTC",
+///     "net_type": "BTC",
+///     "txid": "ebe6937b-130e-4066-8ac6-4b0e67f28adc",
+///     "state": "CANCELED",
+///     "created_at": "2018-04-13T11:24:01+09:00",
+///     "done_at": null,
+///     "amount": "0.01",
+///     "fee": "0.0",
+///     "krw_amount": "80420.0",
+///     "transaction_type": "default"
+/// }
+/// ```
+/// # Response Description
+/// | field                  | description                   | type         |
+/// |:-----------------------|:------------------------------|:-------------|
+/// | type| 입출금 종류 | String |
+/// | uuid| 출금의 고유 아이디 | String |
+/// | currency| 화폐를 의미하는 영문 대문자 코드 | String |
+/// | net_type| 출금 네트워크 | String |
+/// | txid| 출금의 트랜잭션 아이디 | String |
+/// | state| 출금 상태 | String |
+/// | created_at| 출금 생성 시간 | DateString |
+/// | done_at| 출금 완료 시간 | DateString |
+/// | amount| 출금 금액/수량 | NumberString |
+/// | fee| 출금 수수료 | NumberString |
+/// | krw_amount| 원화 환산 가격 | NumberString |
+/// | transaction_type| 출금 유형 | String |
+pub async fn cancel_withdraw(uuid: &str) -> Result<WithdrawalRecord, ResponseError> {
+    WithdrawalRecord::cancel_withdraw(uuid).await
 }
 
 /// 출금 허용 주소 리스트 조회
@@ -435,3 +564,57 @@ pub async fn withdraw_krw(
 pub async fn get_withdraw_address_list() -> Result<Vec<WithdrawCoinAddress>, ResponseError> {
     WithdrawCoinAddress::get_withdraw_address_list().await
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::response::{AccountsInfo, MemberLevel, WithdrawCurrency, WithdrawLimit};
+
+    use super::*;
+
+    /// Chance to withdraw ETH, with a withdraw fee of 0.01 and a minimum of 0.02
+    #[allow(deprecated)]
+    pub(super) fn withdraw_chance(
+        balance: f64,
+        onetime: Option<f64>,
+        remaining_daily: f64,
+    ) -> WithdrawChance {
+        WithdrawChance {
+            member_level: MemberLevel {
+                security_level: 3,
+                fee_level: 0,
+                email_verified: true,
+                identity_auth_verified: true,
+                bank_account_verified: true,
+                two_factor_auth_verified: true,
+                locked: false,
+                wallet_locked: false,
+            },
+            currency: WithdrawCurrency {
+                code: "ETH".to_owned(),
+                withdraw_fee: 0.01,
+                is_coin: true,
+                wallet_state: "working".to_owned(),
+                wallet_support: vec!["deposit".to_owned(), "withdraw".to_owned()],
+            },
+            account: AccountsInfo {
+                currency: "ETH".to_owned(),
+                balance,
+                locked: 0.0,
+                avg_buy_price: 0.0,
+                avg_buy_price_modified: false,
+                unit_currency: "KRW".to_owned(),
+            },
+            withdraw_limit: WithdrawLimit {
+                currency: "ETH".to_owned(),
+                minimum: Some(0.02),
+                onetime,
+                daily: None,
+                remaining_daily,
+                remaining_daily_krw: 0.0,
+                remaining_daily_fiat: 0.0,
+                fixed: Some(4),
+                can_withdraw: true,
+            },
+        }
+    }
+}
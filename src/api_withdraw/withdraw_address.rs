@@ -1,9 +1,10 @@
 use reqwest::{
     header::{ACCEPT, AUTHORIZATION},
-    Response, Url,
+    Url,
 };
 
 use crate::request::Request;
+use crate::transport::{HttpRequest, HttpResponse};
 
 use super::{
     super::constant::{URL_SERVER, URL_WITHDRAWS_COIN_ADDRESS},
@@ -13,10 +14,7 @@ use super::{
 impl WithdrawCoinAddress {
     pub async fn get_withdraw_address_list() -> Result<Vec<Self>, ResponseError> {
         let res = Self::request().await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -40,18 +38,16 @@ impl WithdrawCoinAddress {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request() -> Result<Response, ResponseError> {
+    async fn request() -> Result<HttpResponse, ResponseError> {
         let url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAWS_COIN_ADDRESS}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         let token_string = Self::set_token()?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -65,56 +61,52 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_withdraw_address() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = WithdrawCoinAddress::request().await.unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
+        crate::fixture::test_scope("get_withdraw_address", async {
+            let res = WithdrawCoinAddress::request().await.unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!({
-            "currency": "",
-            "net_type": "",
-            "network_name": "",
-            "withdraw_address": "",
-            "secondary_address": ""
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!(
-                "[test_get_withdraw_address] Missing keys: {:?}",
-                missing_keys
-            );
-            assert!(false);
-        } else {
-            println!("[test_get_withdraw_address] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!({
+                "currency": "",
+                "net_type": "",
+                "network_name": "",
+                "withdraw_address": "",
+                "secondary_address": ""
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!(
+                    "[test_get_withdraw_address] Missing keys: {:?}",
+                    missing_keys
+                );
+                assert!(false);
+            } else {
+                println!("[test_get_withdraw_address] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_get_withdraw_address] Extra keys: {:?}", extra_keys);
-            assert!(false);
-        } else {
-            println!("[test_get_withdraw_address] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_get_withdraw_address] Extra keys: {:?}", extra_keys);
+                assert!(false);
+            } else {
+                println!("[test_get_withdraw_address] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -0,0 +1,155 @@
+use reqwest::header::{ACCEPT, AUTHORIZATION};
+use reqwest::Url;
+
+use crate::request::RequestWithQuery;
+use crate::response::{TransactionInfoSource, WithdrawalRecord};
+use crate::transport::{HttpRequest, HttpResponse};
+
+use super::{
+    super::constant::{URL_SERVER, URL_WITHDRAW},
+    super::response::ResponseError,
+};
+
+impl WithdrawalRecord {
+    pub async fn cancel_withdraw(uuid: &str) -> Result<Self, ResponseError> {
+        let res = Self::request_cancel_withdraw(uuid).await?;
+        let res_serialized = res.text();
+
+        if res_serialized.contains("error") {
+            return Err(serde_json::from_str(&res_serialized)
+                .map(crate::response::response_error)
+                .ok()
+                .unwrap());
+        }
+
+        serde_json::from_str::<TransactionInfoSource>(&res_serialized)
+            .map(Self::from)
+            .map_err(crate::response::response_error_from_json)
+    }
+
+    async fn request_cancel_withdraw(uuid: &str) -> Result<HttpResponse, ResponseError> {
+        let mut url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAW}"))
+            .map_err(crate::response::response_error_internal_url_parse_error)?;
+
+        url.query_pairs_mut().append_pair("uuid", uuid);
+
+        let token_string = Self::set_token_with_query(url.as_str())?;
+
+        HttpRequest::delete(url.as_str())
+            .header(ACCEPT, "application/json")
+            .header(AUTHORIZATION, &token_string)
+            .send()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use serde_json::Value;
+
+    use crate::response::WithdrawalRecord;
+
+    #[tokio::test]
+    async fn test_cancel_withdraw() {
+        crate::fixture::test_scope("cancel_withdraw", async {
+            // withdrawal of the fixture, unless another one is given to record
+            let uuid = std::env::var("TEST_WITHDRAW_UUID")
+                .unwrap_or_else(|_| "35a4f1dc-1db5-4d6b-89b5-7ec137875956".to_owned());
+
+            let res = WithdrawalRecord::request_cancel_withdraw(&uuid)
+                .await
+                .unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
+
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "type": "",
+                "uuid": "",
+                "currency": "",
+                "net_type": "",
+                "txid": "",
+                "state": "",
+                "created_at": "",
+                "done_at": "",
+                "amount": "",
+                "fee": "",
+                "krw_amount": "",
+                "transaction_type": ""
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_cancel_withdraw] Missing keys: {:?}", missing_keys);
+                assert!(false);
+            } else {
+                println!("[test_cancel_withdraw] No keys are missing");
+            }
+
+            if !extra_keys.is_empty() {
+                println!("[test_cancel_withdraw] Extra keys: {:?}", extra_keys);
+                assert!(false);
+            } else {
+                println!("[test_cancel_withdraw] No extra keys found.");
+            }
+
+            assert!(true);
+        })
+        .await;
+    }
+
+    fn compare_keys(
+        json: &Value,
+        expected: &HashMap<&str, Value>,
+        path: &str,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut missing_keys = Vec::new();
+        let mut extra_keys = Vec::new();
+
+        if let Value::Object(map) = json {
+            let json_keys: HashSet<&str> = map.keys().map(|k| k.as_str()).collect();
+            let expected_keys: HashSet<&str> = expected.keys().cloned().collect();
+
+            for key in expected_keys.difference(&json_keys) {
+                missing_keys.push(format!("{}{}", path, key));
+            }
+
+            for key in json_keys.difference(&expected_keys) {
+                extra_keys.push(format!("{}{}", path, key));
+            }
+
+            for key in expected_keys.intersection(&json_keys) {
+                if let Some(expected_value) = expected.get(*key) {
+                    let new_path = format!("{}{}.", path, key);
+                    if let Value::Object(_) = expected_value {
+                        let expected_map = expected_value
+                            .as_object()
+                            .unwrap()
+                            .iter()
+                            .map(|(k, v)| (k.as_str(), v.clone()))
+                            .collect::<HashMap<&str, Value>>();
+                        let (mut missing, mut extra) =
+                            compare_keys(&map[*key], &expected_map, &new_path);
+                        missing_keys.append(&mut missing);
+                        extra_keys.append(&mut extra);
+                    }
+                }
+            }
+        }
+
+        (missing_keys, extra_keys)
+    }
+}
@@ -1,7 +1,8 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
-use reqwest::{Response, Url};
+use reqwest::Url;
 
 use crate::request::RequestWithQuery;
+use crate::transport::{HttpRequest, HttpResponse};
 
 use super::{
     super::constant::{URL_SERVER, URL_WITHDRAWS_CHANCE},
@@ -19,10 +20,7 @@ impl WithdrawChance {
         net_type: &str,
     ) -> Result<Self, ResponseError> {
         let res = Self::request(currency, net_type).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -76,7 +74,7 @@ impl WithdrawChance {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(currency: &str, net_type: &str) -> Result<Response, ResponseError> {
+    async fn request(currency: &str, net_type: &str) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAWS_CHANCE}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut().append_pair("currency", currency);
@@ -84,13 +82,11 @@ impl WithdrawChance {
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -104,90 +100,86 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_withdraw_chance() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = WithdrawChance::request("ETH", "ETH").await.unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
+        crate::fixture::test_scope("get_withdraw_chance", async {
+            let res = WithdrawChance::request("ETH", "ETH").await.unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "member_level": {
-                "security_level": "",
-                "fee_level": "",
-                "email_verified": "",
-                "identity_auth_verified": "",
-                "bank_account_verified": "",
-                "two_factor_auth_verified": "",
-                // "kakao_pay_auth_verified": "",
-                "locked": "",
-                "wallet_locked": ""
-            },
-            "currency": {
-                "code": "",
-                "withdraw_fee": "",
-                "is_coin": "",
-                "wallet_state": "",
-                "wallet_support": ""
-            },
-            "account": {
-                "currency": "",
-                "balance": "",
-                "locked": "",
-                "avg_buy_price": "",
-                "avg_buy_price_modified": "",
-                "unit_currency": ""
-            },
-            "withdraw_limit": {
-                "currency": "",
-                "minimum": "",
-                "onetime": "",
-                "daily": "",
-                "remaining_daily": "",
-                "remaining_daily_krw": "",
-                "fixed": "",
-                "can_withdraw": "",
-                "remaining_daily_fiat": "",
-                "fiat_currency": "",
-                "withdraw_delayed_fiat": ""
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "member_level": {
+                    "security_level": "",
+                    "fee_level": "",
+                    "email_verified": "",
+                    "identity_auth_verified": "",
+                    "bank_account_verified": "",
+                    "two_factor_auth_verified": "",
+                    // "kakao_pay_auth_verified": "",
+                    "locked": "",
+                    "wallet_locked": ""
+                },
+                "currency": {
+                    "code": "",
+                    "withdraw_fee": "",
+                    "is_coin": "",
+                    "wallet_state": "",
+                    "wallet_support": ""
+                },
+                "account": {
+                    "currency": "",
+                    "balance": "",
+                    "locked": "",
+                    "avg_buy_price": "",
+                    "avg_buy_price_modified": "",
+                    "unit_currency": ""
+                },
+                "withdraw_limit": {
+                    "currency": "",
+                    "minimum": "",
+                    "onetime": "",
+                    "daily": "",
+                    "remaining_daily": "",
+                    "remaining_daily_krw": "",
+                    "fixed": "",
+                    "can_withdraw": "",
+                    "remaining_daily_fiat": "",
+                    "fiat_currency": "",
+                    "withdraw_delayed_fiat": ""
+                }
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!(
+                    "[test_get_withdraw_chance] Missing keys: {:?}",
+                    missing_keys
+                );
+                assert!(false);
+            } else {
+                println!("[test_get_withdraw_chance] No keys are missing");
             }
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!(
-                "[test_get_withdraw_chance] Missing keys: {:?}",
-                missing_keys
-            );
-            assert!(false);
-        } else {
-            println!("[test_get_withdraw_chance] No keys are missing");
-        }
 
-        if !extra_keys.is_empty() {
-            println!("[test_get_withdraw_chance] Extra keys: {:?}", extra_keys);
-            assert!(false);
-        } else {
-            println!("[test_get_withdraw_chance] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_get_withdraw_chance] Extra keys: {:?}", extra_keys);
+                assert!(false);
+            } else {
+                println!("[test_get_withdraw_chance] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
@@ -1,17 +1,16 @@
+use crate::transport::{HttpRequest, HttpResponse};
 use reqwest::header::{ACCEPT, AUTHORIZATION};
-use reqwest::{Response, Url};
 
 use super::{
     super::{
         constant::{URL_SERVER, URL_WITHDRAWS_COIN},
-        request::RequestWithQuery,
-        response::{ResponseError, TransactionInfoDerived, TransactionInfoDerivedSource},
+        request::{RequestParams, RequestWithQuery},
+        response::{ResponseError, ResponseErrorState, TransactionInfoSource, WithdrawalRecord},
     },
     TransactionType,
 };
 
-impl RequestWithQuery for TransactionInfoDerived {}
-impl TransactionInfoDerived {
+impl WithdrawalRecord {
     pub async fn withdraw_coin(
         currency: &str,
         net_type: &str,
@@ -20,6 +19,30 @@ impl TransactionInfoDerived {
         secondary_address: Option<&str>,
         transaction_type: TransactionType,
     ) -> Result<Self, ResponseError> {
+        Self::withdraw_coin_unless_rejected(
+            currency,
+            net_type,
+            amount,
+            address,
+            secondary_address,
+            transaction_type,
+        )
+        .await
+        .map_err(|(error, _)| error)
+    }
+
+    /// Withdraw like [WithdrawalRecord::withdraw_coin], also telling whether a failure is a rejection by the API
+    ///
+    /// Only a 4xx response with an error body means that the withdrawal was not made.
+    /// It may still have been made on any other failure, such as a dropped connection or a 5xx response.
+    pub(crate) async fn withdraw_coin_unless_rejected(
+        currency: &str,
+        net_type: &str,
+        amount: f64,
+        address: &str,
+        secondary_address: Option<&str>,
+        transaction_type: TransactionType,
+    ) -> Result<Self, (ResponseError, bool)> {
         let res = Self::request_withdraw_coin(
             currency,
             net_type,
@@ -28,37 +51,29 @@ impl TransactionInfoDerived {
             secondary_address,
             transaction_type,
         )
-        .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        .await
+        .map_err(|error| {
+            let maybe_sent = matches!(
+                error.state,
+                ResponseErrorState::InternalReqwestError
+                    | ResponseErrorState::InternalTransportError
+            );
+            (error, !maybe_sent)
+        })?;
+        let rejected = res.status().is_client_error();
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
+            if let Ok(error) =
+                serde_json::from_str(&res_serialized).map(crate::response::response_error)
+            {
+                return Err((error, rejected));
+            }
         }
 
-        serde_json::from_str(&res_serialized)
-            .map(|x: TransactionInfoDerivedSource| {
-                Self {
-                    r#type: x.r#type(),
-                    uuid: x.uuid(),
-                    currency: x.currency(),
-                    net_type: x.net_type(),
-                    txid: x.txid(),
-                    state: x.state(),
-                    created_at: x.created_at(),
-                    done_at: x.done_at(),
-                    amount: x.amount(),
-                    fee: x.fee(),
-                    // krw_amount: x.krw_amount(),
-                    transaction_type: x.transaction_type(),
-                }
-            })
-            .map_err(crate::response::response_error_from_json)
+        serde_json::from_str::<TransactionInfoSource>(&res_serialized)
+            .map(Self::from)
+            .map_err(|e| (crate::response::response_error_from_json(e), false))
     }
 
     async fn request_withdraw_coin(
@@ -68,33 +83,28 @@ impl TransactionInfoDerived {
         address: &str,
         secondary_address: Option<&str>,
         transaction_type: TransactionType,
-    ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAWS_COIN}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
+    ) -> Result<HttpResponse, ResponseError> {
+        let mut params = RequestParams::new();
 
-        url.query_pairs_mut()
-            .append_pair("net_type", net_type)
-            .append_pair("currency", currency)
-            .append_pair("amount", &format!("{amount}"))
-            .append_pair("address", address)
-            .append_pair("transaction_type", &transaction_type.to_string());
+        params
+            .append("net_type", net_type)
+            .append("currency", currency)
+            .append("amount", format!("{amount}"))
+            .append("address", address)
+            .append("transaction_type", transaction_type);
 
         if let Some(secondary_address) = secondary_address {
-            url.query_pairs_mut()
-                .append_pair("secondary_address", secondary_address);
+            params.append("secondary_address", secondary_address);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .post(url.as_str())
-            // .json(&asdf)
+        HttpRequest::post(format!("{URL_SERVER}{URL_WITHDRAWS_COIN}"))
             .header(ACCEPT, "application/json")
-            // .header(CONTENT_TYPE, "application/json")
             .header(AUTHORIZATION, &token_string)
+            .json(&params.json_body())
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -104,74 +114,70 @@ mod tests {
 
     use serde_json::Value;
 
-    use crate::{constant::TransactionType, response::TransactionInfoDerived};
+    use crate::{constant::TransactionType, response::WithdrawalRecord};
 
     #[tokio::test]
     async fn test_get_withdraw_coin() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = TransactionInfoDerived::request_withdraw_coin(
-            "ETH",
-            "ETH",
-            0.02,
-            "0x40268F1e99F76b658c6D52d89166EE289EfC225d",
-            None,
-            TransactionType::Default,
-        )
-        .await
-        .unwrap();
-
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("withdraw_coin", async {
+            let res = WithdrawalRecord::request_withdraw_coin(
+                "ETH",
+                "ETH",
+                0.02,
+                "0x40268F1e99F76b658c6D52d89166EE289EfC225d",
+                None,
+                TransactionType::Default,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            let res_serialized = res.text();
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "type": "",
-            "uuid": "",
-            "currency": "",
-            "net_type": "",
-            "txid": "",
-            "state": "",
-            "created_at": "",
-            "done_at": "",
-            "amount": "",
-            "fee": "",
-            // "krw_amount": "",
-            "transaction_type": ""
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_get_withdraw_coin] Missing keys: {:?}", missing_keys);
-            assert!(false);
-        } else {
-            println!("[test_get_withdraw_coin] No keys are missing");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_get_withdraw_coin] Extra keys: {:?}", extra_keys);
-            assert!(false);
-        } else {
-            println!("[test_get_withdraw_coin] No extra keys found.");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "type": "",
+                "uuid": "",
+                "currency": "",
+                "net_type": "",
+                "txid": "",
+                "state": "",
+                "created_at": "",
+                "done_at": "",
+                "amount": "",
+                "fee": "",
+                // "krw_amount": "",
+                "transaction_type": ""
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_get_withdraw_coin] Missing keys: {:?}", missing_keys);
+                assert!(false);
+            } else {
+                println!("[test_get_withdraw_coin] No keys are missing");
+            }
+
+            if !extra_keys.is_empty() {
+                println!("[test_get_withdraw_coin] Extra keys: {:?}", extra_keys);
+                assert!(false);
+            } else {
+                println!("[test_get_withdraw_coin] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
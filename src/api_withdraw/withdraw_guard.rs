@@ -0,0 +1,525 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::Path;
+
+use crate::journal::Journal;
+
+use super::{
+    super::{
+        constant::TransactionType,
+        response::{ResponseError, WithdrawChance, WithdrawCoinAddress, WithdrawalRecord},
+    },
+    withdrawable_wallet_state,
+};
+
+/// Withdrawal to be checked by [WithdrawalGuard]
+#[derive(Debug, Clone)]
+pub struct WithdrawalRequest {
+    pub currency: String,
+    pub net_type: String,
+    pub amount: f64,
+    pub address: String,
+    pub secondary_address: Option<String>,
+    pub transaction_type: TransactionType,
+}
+
+/// Address withdrawals are allowed to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowedAddress {
+    pub currency: String,
+    pub net_type: String,
+    pub address: String,
+    pub secondary_address: Option<String>,
+}
+
+impl From<&WithdrawCoinAddress> for AllowedAddress {
+    fn from(value: &WithdrawCoinAddress) -> Self {
+        Self {
+            currency: value.currency.clone(),
+            net_type: value.net_type.clone(),
+            address: value.withdraw_address.clone(),
+            secondary_address: value.secondary_address.clone(),
+        }
+    }
+}
+
+/// Reason why [WithdrawalGuard] refuses a withdrawal
+#[derive(Debug, Clone, PartialEq)]
+pub enum WithdrawalRejection {
+    /// Amount is not positive
+    InvalidAmount,
+    /// The request is checked against the withdraw chance of another currency
+    CurrencyMismatch { expected: String },
+    /// The currency, network, address and secondary address are not in the allowlist
+    AddressNotAllowed { net_type: String, address: String },
+    /// `wallet_support` does not contain `withdraw`, the wallet is not in a withdrawable state
+    /// or the exchange does not allow the withdrawal
+    WithdrawNotSupported {
+        wallet_state: String,
+        wallet_support: Vec<String>,
+    },
+    /// Amount is below `withdraw_limit.minimum`
+    UnderMinimum { amount: f64, minimum: f64 },
+    /// Amount is above `withdraw_limit.onetime`
+    OverOnetime { amount: f64, onetime: f64 },
+    /// Amount is above `withdraw_limit.remaining_daily`
+    OverRemainingDaily { amount: f64, remaining_daily: f64 },
+    /// Amount added to today's withdrawals is above the local daily cap
+    OverDailyCap {
+        amount: f64,
+        withdrawn: f64,
+        daily_cap: f64,
+    },
+    /// Balance cannot cover the amount and the withdraw fee
+    InsufficientBalance { required: f64, available: f64 },
+    /// The approver declined the withdrawal
+    NotApproved,
+}
+
+/// Error of [WithdrawalGuard::withdraw_coin] and [super::WithdrawalPlan::plan]
+#[derive(Debug)]
+pub enum WithdrawalError {
+    /// The withdrawal is refused before being sent, for every reason listed
+    Rejected(Vec<WithdrawalRejection>),
+    /// A request to the exchange failed
+    Response(ResponseError),
+}
+
+impl From<ResponseError> for WithdrawalError {
+    fn from(value: ResponseError) -> Self {
+        Self::Response(value)
+    }
+}
+
+/// [WithdrawalError::Rejected] becomes [crate::response::ResponseErrorState::CustomErrorWithdrawalRejected]
+impl From<WithdrawalError> for ResponseError {
+    fn from(value: WithdrawalError) -> Self {
+        match value {
+            WithdrawalError::Rejected(rejections) => {
+                crate::response::response_error_custom_withdrawal_rejected(format!(
+                    "{rejections:?}"
+                ))
+            }
+            WithdrawalError::Response(e) => e,
+        }
+    }
+}
+
+/// Approves withdrawals which passed every check of [WithdrawalGuard]
+///
+/// Implemented for `FnMut(&WithdrawalRequest) -> bool` closures.
+pub trait WithdrawalApprover {
+    fn approve(&mut self, request: &WithdrawalRequest) -> impl Future<Output = bool>;
+}
+
+impl<F> WithdrawalApprover for F
+where
+    F: FnMut(&WithdrawalRequest) -> bool,
+{
+    fn approve(&mut self, request: &WithdrawalRequest) -> impl Future<Output = bool> {
+        std::future::ready(self(request))
+    }
+}
+
+/// Withdraws coins only to allowed addresses, within daily caps and after approval
+///
+/// Addresses are checked against [super::get_withdraw_address_list] unless a local allowlist is set.
+/// Daily caps only count the withdrawals made through the guard, and reset at 00:00 KST.
+/// The amounts withdrawn are kept in memory, so a restart resets them unless [WithdrawalGuard::with_journal] is set.
+pub struct WithdrawalGuard<A> {
+    allowlist: Option<Vec<AllowedAddress>>,
+    daily_caps: HashMap<String, f64>,
+    /// Day in KST and amount withdrawn on that day, by currency
+    withdrawn: Journal<(i64, f64)>,
+    approver: A,
+}
+
+impl<A: WithdrawalApprover> WithdrawalGuard<A> {
+    pub fn new(approver: A) -> Self {
+        Self {
+            allowlist: None,
+            daily_caps: HashMap::new(),
+            withdrawn: Journal::in_memory(),
+            approver,
+        }
+    }
+
+    /// Allow only the given addresses instead of every registered withdraw address
+    pub fn with_allowlist(mut self, allowlist: Vec<AllowedAddress>) -> Self {
+        self.allowlist = Some(allowlist);
+        self
+    }
+
+    /// Cap the amount of `currency` withdrawn per day
+    pub fn with_daily_cap(mut self, currency: &str, daily_cap: f64) -> Self {
+        self.daily_caps.insert(currency.to_owned(), daily_cap);
+        self
+    }
+
+    /// Persist the amounts withdrawn today to the json file at `path`, so the daily caps survive restarts
+    pub fn with_journal(mut self, path: impl AsRef<Path>) -> Result<Self, ResponseError> {
+        self.withdrawn = Journal::open(path)?;
+        Ok(self)
+    }
+
+    /// Replace the allowlist with the withdraw addresses registered on the exchange
+    pub async fn refresh_allowlist(&mut self) -> Result<(), ResponseError> {
+        let addresses = WithdrawCoinAddress::get_withdraw_address_list().await?;
+
+        self.allowlist = Some(addresses.iter().map(AllowedAddress::from).collect());
+        Ok(())
+    }
+
+    /// Amount of `currency` withdrawn through the guard today
+    pub fn withdrawn_today(&self, currency: &str) -> f64 {
+        match self.withdrawn.records.get(currency) {
+            Some((day, withdrawn)) if *day == today() => *withdrawn,
+            _ => 0.0,
+        }
+    }
+
+    /// Check the request against the allowlist, the daily caps and the given withdraw chance
+    #[allow(deprecated)]
+    pub fn check(
+        &self,
+        request: &WithdrawalRequest,
+        withdraw_chance: &WithdrawChance,
+    ) -> Vec<WithdrawalRejection> {
+        let mut rejections = Vec::new();
+        let currency = &withdraw_chance.currency;
+        let withdraw_limit = &withdraw_chance.withdraw_limit;
+
+        if request.amount.is_nan() || request.amount <= 0.0 {
+            rejections.push(WithdrawalRejection::InvalidAmount);
+        }
+
+        if currency.code != request.currency {
+            rejections.push(WithdrawalRejection::CurrencyMismatch {
+                expected: currency.code.clone(),
+            });
+        }
+
+        let allowed = self.allowlist.iter().flatten().any(|allowed| {
+            allowed.currency == request.currency
+                && allowed.net_type == request.net_type
+                && allowed.address == request.address
+                && allowed.secondary_address == request.secondary_address
+        });
+
+        if !allowed {
+            rejections.push(WithdrawalRejection::AddressNotAllowed {
+                net_type: request.net_type.clone(),
+                address: request.address.clone(),
+            });
+        }
+
+        if !currency.wallet_support.iter().any(|x| x == "withdraw")
+            || !withdrawable_wallet_state(&currency.wallet_state)
+            || !withdraw_limit.can_withdraw
+            || withdraw_chance.member_level.wallet_locked
+        {
+            rejections.push(WithdrawalRejection::WithdrawNotSupported {
+                wallet_state: currency.wallet_state.clone(),
+                wallet_support: currency.wallet_support.clone(),
+            });
+        }
+
+        if let Some(minimum) = withdraw_limit.minimum {
+            if request.amount < minimum {
+                rejections.push(WithdrawalRejection::UnderMinimum {
+                    amount: request.amount,
+                    minimum,
+                });
+            }
+        }
+
+        if let Some(onetime) = withdraw_limit.onetime {
+            if request.amount > onetime {
+                rejections.push(WithdrawalRejection::OverOnetime {
+                    amount: request.amount,
+                    onetime,
+                });
+            }
+        }
+
+        if request.amount > withdraw_limit.remaining_daily {
+            rejections.push(WithdrawalRejection::OverRemainingDaily {
+                amount: request.amount,
+                remaining_daily: withdraw_limit.remaining_daily,
+            });
+        }
+
+        if let Some(daily_cap) = self.daily_caps.get(&request.currency) {
+            let withdrawn = self.withdrawn_today(&request.currency);
+
+            if withdrawn + request.amount > *daily_cap {
+                rejections.push(WithdrawalRejection::OverDailyCap {
+                    amount: request.amount,
+                    withdrawn,
+                    daily_cap: *daily_cap,
+                });
+            }
+        }
+
+        let required = request.amount + currency.withdraw_fee;
+
+        if required > withdraw_chance.account.balance {
+            rejections.push(WithdrawalRejection::InsufficientBalance {
+                required,
+                available: withdraw_chance.account.balance,
+            });
+        }
+
+        rejections
+    }
+
+    /// Same as [super::withdraw_coin], submitted only if every check passes and the approver agrees
+    ///
+    /// Fails with [WithdrawalError::Rejected] otherwise. The amount counts toward the daily cap
+    /// from before the request is sent, and is only given back if the API rejects the request with a 4xx error.
+    /// It stays counted when the outcome is unknown, such as on a dropped connection or a 5xx response.
+    pub async fn withdraw_coin(
+        &mut self,
+        request: &WithdrawalRequest,
+    ) -> Result<WithdrawalRecord, WithdrawalError> {
+        if self.allowlist.is_none() {
+            self.refresh_allowlist().await?;
+        }
+
+        let withdraw_chance =
+            WithdrawChance::get_withdraw_chance(&request.currency, &request.net_type).await?;
+        let rejections = self.check(request, &withdraw_chance);
+
+        if !rejections.is_empty() {
+            return Err(WithdrawalError::Rejected(rejections));
+        }
+
+        if !self.approver.approve(request).await {
+            return Err(WithdrawalError::Rejected(vec![
+                WithdrawalRejection::NotApproved,
+            ]));
+        }
+
+        self.record_withdrawn(&request.currency, request.amount)?;
+
+        let withdrawal_record = WithdrawalRecord::withdraw_coin_unless_rejected(
+            &request.currency,
+            &request.net_type,
+            request.amount,
+            &request.address,
+            request.secondary_address.as_deref(),
+            request.transaction_type,
+        )
+        .await;
+
+        match withdrawal_record {
+            Ok(withdrawal_record) => Ok(withdrawal_record),
+            Err((error, rejected)) => {
+                if rejected {
+                    // keeping the amount counted is the safe side if the journal cannot be written
+                    let _ = self.record_withdrawn(&request.currency, -request.amount);
+                }
+
+                Err(error.into())
+            }
+        }
+    }
+
+    fn record_withdrawn(&mut self, currency: &str, amount: f64) -> Result<(), ResponseError> {
+        let withdrawn = self.withdrawn_today(currency) + amount;
+
+        self.withdrawn
+            .records
+            .insert(currency.to_owned(), (today(), withdrawn));
+        self.withdrawn.flush()
+    }
+}
+
+/// Days since the unix epoch in KST
+fn today() -> i64 {
+    (crate::date::now_secs() + crate::date::KST_OFFSET_SECS).div_euclid(crate::date::DAY_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_withdraw::tests::withdraw_chance;
+
+    fn request(address: &str, amount: f64) -> WithdrawalRequest {
+        WithdrawalRequest {
+            currency: "ETH".to_owned(),
+            net_type: "ETH".to_owned(),
+            amount,
+            address: address.to_owned(),
+            secondary_address: None,
+            transaction_type: TransactionType::Default,
+        }
+    }
+
+    fn guard() -> WithdrawalGuard<impl WithdrawalApprover> {
+        WithdrawalGuard::new(|_: &WithdrawalRequest| true)
+            .with_allowlist(vec![AllowedAddress {
+                currency: "ETH".to_owned(),
+                net_type: "ETH".to_owned(),
+                address: "0x40268F1e99F76b658c6D52d89166EE289EfC225d".to_owned(),
+                secondary_address: None,
+            }])
+            .with_daily_cap("ETH", 1.0)
+    }
+
+    #[test]
+    fn test_check_allowed_withdrawal() {
+        let guard = guard();
+        let rejections = guard.check(
+            &request("0x40268F1e99F76b658c6D52d89166EE289EfC225d", 0.5),
+            &withdraw_chance(10.0, None, 10.0),
+        );
+
+        assert!(rejections.is_empty(), "{rejections:?}");
+    }
+
+    #[test]
+    fn test_check_rejections() {
+        let mut guard = guard();
+        guard.record_withdrawn("ETH", 0.8).unwrap();
+
+        let rejections = guard.check(
+            &request("0xdeadbeef", 0.5),
+            &withdraw_chance(0.3, None, 0.4),
+        );
+
+        assert_eq!(
+            rejections,
+            vec![
+                WithdrawalRejection::AddressNotAllowed {
+                    net_type: "ETH".to_owned(),
+                    address: "0xdeadbeef".to_owned(),
+                },
+                WithdrawalRejection::OverRemainingDaily {
+                    amount: 0.5,
+                    remaining_daily: 0.4,
+                },
+                WithdrawalRejection::OverDailyCap {
+                    amount: 0.5,
+                    withdrawn: 0.8,
+                    daily_cap: 1.0,
+                },
+                WithdrawalRejection::InsufficientBalance {
+                    required: 0.51,
+                    available: 0.3,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_coin_rejected() {
+        let mut guard = guard();
+
+        let result = crate::fixture::test_scope(
+            "get_withdraw_chance",
+            guard.withdraw_coin(&request("0xdeadbeef", 0.5)),
+        )
+        .await;
+
+        let Err(WithdrawalError::Rejected(rejections)) = result else {
+            panic!("unexpected result: {result:?}");
+        };
+        assert_eq!(
+            rejections[0],
+            WithdrawalRejection::AddressNotAllowed {
+                net_type: "ETH".to_owned(),
+                address: "0xdeadbeef".to_owned(),
+            }
+        );
+        assert_eq!(guard.withdrawn_today("ETH"), 0.0);
+    }
+
+    async fn withdraw_coin_answered(
+        status: u16,
+        response: serde_json::Value,
+    ) -> WithdrawalGuard<impl WithdrawalApprover> {
+        let mut interactions: Vec<crate::fixture::Interaction> = serde_json::from_str(
+            include_str!("../../tests/fixtures/get_withdraw_chance.json"),
+        )
+        .unwrap();
+        interactions[0].response["account"]["balance"] = "10.0".into();
+        interactions[0].response["withdraw_limit"]["remaining_daily"] = "10.0".into();
+        interactions.push(crate::fixture::Interaction {
+            method: "POST".to_owned(),
+            url: "https://api.upbit.com/v1/withdraws/coin".to_owned(),
+            body: Some(serde_json::json!({
+                "net_type": "ETH",
+                "currency": "ETH",
+                "amount": "0.5",
+                "address": "0x40268F1e99F76b658c6D52d89166EE289EfC225d",
+                "transaction_type": "default",
+            })),
+            status,
+            headers: Default::default(),
+            response,
+        });
+
+        let mut guard = guard();
+        let result = crate::transport::scope(
+            crate::fixture::ReplayTransport::new(interactions),
+            crate::credential::scope(
+                crate::credential::Credentials::new("access key", "secret key"),
+                guard.withdraw_coin(&request("0x40268F1e99F76b658c6D52d89166EE289EfC225d", 0.5)),
+            ),
+        )
+        .await;
+
+        assert!(
+            matches!(result, Err(WithdrawalError::Response(_))),
+            "unexpected result: {result:?}"
+        );
+        guard
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_coin_refused_by_api() {
+        let guard = withdraw_coin_answered(
+            400,
+            serde_json::json!({
+                "error": {
+                    "name": "withdraw_insufficient_balance",
+                    "message": "잔고가 부족합니다."
+                }
+            }),
+        )
+        .await;
+
+        assert_eq!(guard.withdrawn_today("ETH"), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_coin_unknown_outcome() {
+        let guard = withdraw_coin_answered(
+            500,
+            serde_json::json!({
+                "error": {
+                    "name": "server_error",
+                    "message": "서버 에러가 발생했습니다."
+                }
+            }),
+        )
+        .await;
+
+        assert_eq!(guard.withdrawn_today("ETH"), 0.5);
+    }
+
+    #[test]
+    fn test_withdrawn_journal() {
+        let path =
+            std::env::temp_dir().join(format!("upbit-withdrawn-{}.json", uuid::Uuid::new_v4()));
+
+        let mut guard = guard().with_journal(&path).unwrap();
+        guard.record_withdrawn("ETH", 0.8).unwrap();
+
+        let guard = self::guard().with_journal(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(guard.withdrawn_today("ETH"), 0.8);
+    }
+}
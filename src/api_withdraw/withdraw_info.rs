@@ -1,25 +1,48 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
-use reqwest::{Response, Url};
+use reqwest::Url;
 
 use crate::request::RequestWithQuery;
-use crate::response::{TransactionInfo, TransactionInfoSource};
+use crate::transport::{HttpRequest, HttpResponse};
+use crate::response::{
+    TransactionInfoSource, TwoFactorStatus, TwoFactorTransfer, WithdrawalRecord,
+};
 
 use super::{
     super::constant::{URL_SERVER, URL_WITHDRAW},
     super::response::ResponseError,
+    WithdrawState,
 };
 
-impl TransactionInfo {
+impl TwoFactorTransfer for WithdrawalRecord {
+    type State = WithdrawState;
+
+    fn state(&self) -> Self::State {
+        self.state
+    }
+
+    fn two_factor_status(&self) -> TwoFactorStatus {
+        match self.state {
+            WithdrawState::Done => TwoFactorStatus::Approved,
+            WithdrawState::Failed | WithdrawState::Canceled | WithdrawState::Rejected => {
+                TwoFactorStatus::Rejected
+            }
+            WithdrawState::Waiting | WithdrawState::Processing => TwoFactorStatus::Pending,
+        }
+    }
+
+    async fn fetch(uuid: &str) -> Result<Self, ResponseError> {
+        Self::get_withdraw_info(None, Some(uuid), None).await
+    }
+}
+
+impl WithdrawalRecord {
     pub async fn get_withdraw_info(
         currency: Option<&str>,
         uuid: Option<&str>,
         txid: Option<&str>,
     ) -> Result<Self, ResponseError> {
         let res = Self::request_get_withdraw_info(currency, uuid, txid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -28,33 +51,8 @@ impl TransactionInfo {
                 .unwrap());
         }
 
-        serde_json::from_str(&res_serialized)
-            .map(|x: TransactionInfoSource| Self {
-                r#type: x.r#type(),
-                uuid: x.uuid(),
-                currency: x.currency(),
-                net_type: x.net_type(),
-                txid: x.txid(),
-                state: x.state(),
-                created_at: x.created_at(),
-                done_at: x.done_at(),
-                amount: x.amount(),
-                fee: x.fee(),
-                transaction_type: x.transaction_type(),
-
-                holder: None,
-                bank: None,
-                fiat_amount: None,
-                memo: None,
-                fiat_currency: None,
-                confirmations: None,
-                krw_amount: None,
-                network_name: None,
-                cancelable: None,
-                blockchain_url: None,
-                state_i18n: None,
-                address: None,
-            })
+        serde_json::from_str::<TransactionInfoSource>(&res_serialized)
+            .map(Self::from)
             .map_err(crate::response::response_error_from_json)
     }
 
@@ -62,7 +60,7 @@ impl TransactionInfo {
         currency: Option<&str>,
         uuid: Option<&str>,
         txid: Option<&str>,
-    ) -> Result<Response, ResponseError> {
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAW}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
@@ -80,13 +78,11 @@ impl TransactionInfo {
 
         let token_string = Self::set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -96,65 +92,61 @@ mod tests {
 
     use serde_json::Value;
 
-    use crate::response::TransactionInfo;
+    use crate::response::WithdrawalRecord;
 
     #[tokio::test]
     async fn test_get_withdraw_info() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = TransactionInfo::request_get_withdraw_info(Some("ETH"), None, None)
-            .await
-            .unwrap();
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
-            .unwrap();
-
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+        crate::fixture::test_scope("get_withdraw_info", async {
+            let res = WithdrawalRecord::request_get_withdraw_info(Some("ETH"), None, None)
+                .await
+                .unwrap();
+            let res_serialized = res.text();
+
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = serde_json::json!({
-            "type": "",
-            "uuid": "",
-            "currency": "",
-            "net_type": "",
-            "txid": "",
-            "state": "",
-            "created_at": "",
-            "done_at": "",
-            "amount": "",
-            "fee": "",
-            "transaction_type": ""
-        });
-
-        let expected_structure = expected_structure
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
-
-        if !missing_keys.is_empty() {
-            println!("[test_get_withdraw_info] Missing keys: {:?}", missing_keys);
-            assert!(false);
-        } else {
-            println!("[test_get_withdraw_info] No keys are missing");
-        }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = serde_json::json!({
+                "type": "",
+                "uuid": "",
+                "currency": "",
+                "net_type": "",
+                "txid": "",
+                "state": "",
+                "created_at": "",
+                "done_at": "",
+                "amount": "",
+                "fee": "",
+                "transaction_type": ""
+            });
+
+            let expected_structure = expected_structure
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
+
+            let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
+
+            if !missing_keys.is_empty() {
+                println!("[test_get_withdraw_info] Missing keys: {:?}", missing_keys);
+                assert!(false);
+            } else {
+                println!("[test_get_withdraw_info] No keys are missing");
+            }
 
-        if !extra_keys.is_empty() {
-            println!("[test_get_withdraw_info] Extra keys: {:?}", extra_keys);
-            assert!(false);
-        } else {
-            println!("[test_get_withdraw_info] No extra keys found.");
-        }
+            if !extra_keys.is_empty() {
+                println!("[test_get_withdraw_info] Extra keys: {:?}", extra_keys);
+                assert!(false);
+            } else {
+                println!("[test_get_withdraw_info] No extra keys found.");
+            }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
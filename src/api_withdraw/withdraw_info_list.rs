@@ -1,18 +1,50 @@
-use std::str::FromStr;
-
-use crate::request::RequestWithQuery;
+use crate::request::{RequestParams, RequestWithQuery};
+use crate::transport::{HttpRequest, HttpResponse};
 use reqwest::{
     header::{ACCEPT, AUTHORIZATION},
-    Response, Url,
+    Url,
 };
 
 use super::{
     super::constant::{OrderBy, URL_SERVER, URL_WITHDRAWS},
-    super::response::{ResponseError, TransactionInfo, TransactionInfoSource},
+    super::response::{ResponseError, TransactionInfoSource, TransferListing, WithdrawalRecord},
     WithdrawState,
 };
 
-impl TransactionInfo {
+impl TransferListing for WithdrawalRecord {
+    type State = WithdrawState;
+
+    const STATES: &'static [Self::State] = &[
+        WithdrawState::Waiting,
+        WithdrawState::Processing,
+        WithdrawState::Done,
+        WithdrawState::Failed,
+        WithdrawState::Canceled,
+        WithdrawState::Rejected,
+    ];
+
+    async fn list_from(
+        currency: &str,
+        state: Self::State,
+        from: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<Self>, ResponseError> {
+        Self::list(
+            currency,
+            state,
+            None,
+            None,
+            limit,
+            1,
+            OrderBy::Asc,
+            from,
+            None,
+        )
+        .await
+    }
+}
+
+impl WithdrawalRecord {
     pub async fn get_withdraw_info_list(
         currency: &str,
         state: WithdrawState,
@@ -22,11 +54,29 @@ impl TransactionInfo {
         page: u32,
         order_by: OrderBy,
     ) -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request(currency, state, uuids, txids, limit, page, order_by).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        Self::list(
+            currency, state, uuids, txids, limit, page, order_by, None, None,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list(
+        currency: &str,
+        state: WithdrawState,
+        uuids: Option<&[&str]>,
+        txids: Option<&[&str]>,
+        limit: u32,
+        page: u32,
+        order_by: OrderBy,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request(
+            currency, state, uuids, txids, limit, page, order_by, from, to,
+        )
+        .await?;
+        let res_serialized = res.text();
 
         if res_serialized.contains("error") {
             return Err(serde_json::from_str(&res_serialized)
@@ -35,39 +85,12 @@ impl TransactionInfo {
                 .unwrap());
         }
 
-        serde_json::from_str(&res_serialized)
-            .map(|x: Vec<TransactionInfoSource>| {
-                x.into_iter()
-                    .map(|x| Self {
-                        r#type: x.r#type(),
-                        uuid: x.uuid(),
-                        currency: x.currency(),
-                        net_type: x.net_type(),
-                        txid: x.txid(),
-                        state: x.state(),
-                        created_at: x.created_at(),
-                        done_at: x.done_at(),
-                        amount: x.amount(),
-                        fee: x.fee(),
-                        transaction_type: x.transaction_type(),
-                        holder: None,
-                        bank: None,
-                        fiat_amount: None,
-                        memo: None,
-                        fiat_currency: None,
-                        confirmations: None,
-                        krw_amount: None,
-                        network_name: None,
-                        cancelable: None,
-                        blockchain_url: None,
-                        state_i18n: None,
-                        address: None,
-                    })
-                    .collect::<Vec<Self>>()
-            })
+        serde_json::from_str::<Vec<TransactionInfoSource>>(&res_serialized)
+            .map(|x| x.into_iter().map(Self::from).collect())
             .map_err(crate::response::response_error_from_json)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn request(
         currency: &str,
         state: WithdrawState,
@@ -76,47 +99,44 @@ impl TransactionInfo {
         limit: u32,
         page: u32,
         order_by: OrderBy,
-    ) -> Result<Response, ResponseError> {
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<HttpResponse, ResponseError> {
         let mut url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAWS}"))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
+        let mut params = RequestParams::new();
 
-        url.query_pairs_mut()
-            .append_pair("currency", currency)
-            .append_pair("state", &state.to_string())
-            .append_pair("limit", &format!("{limit}"))
-            .append_pair("page", &format!("{page}"))
-            .append_pair("order_by", &order_by.to_string());
+        params
+            .append("currency", currency)
+            .append("state", state)
+            .append("limit", limit)
+            .append("page", page)
+            .append("order_by", order_by);
 
-        let mut url_modified = if let Some(uuids) = uuids {
-            for uuid in uuids {
-                url.query_pairs_mut().append_pair("uuids", uuid);
-            }
+        if let Some(from) = from {
+            params.append("from", from);
+        }
 
-            Url::from_str(url.as_str().replace("uuids", "uuids[]").as_str())
-                .map_err(crate::response::response_error_internal_url_parse_error)?
-        } else {
-            url
-        };
+        if let Some(to) = to {
+            params.append("to", to);
+        }
 
-        let url_modified = if let Some(txids) = txids {
-            for txid in txids {
-                url_modified.query_pairs_mut().append_pair("txids", txid);
-            }
+        if let Some(uuids) = uuids {
+            params.append_array("uuids", uuids);
+        }
 
-            url_modified.as_str().replace("txids", "txids[]")
-        } else {
-            url_modified.as_str().to_string()
-        };
+        if let Some(txids) = txids {
+            params.append_array("txids", txids);
+        }
 
-        let token_string = Self::set_token_with_query(&url_modified)?;
+        params.append_to_url(&mut url);
+        let token_string = Self::set_token_with_params(&params)?;
 
-        reqwest::Client::new()
-            .get(url_modified)
+        HttpRequest::get(url.as_str())
             .header(ACCEPT, "application/json")
             .header(AUTHORIZATION, &token_string)
             .send()
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -126,92 +146,90 @@ mod tests {
 
     use serde_json::{json, Value};
 
-    use crate::{api_withdraw::WithdrawState, constant::OrderBy, response::TransactionInfo};
+    use crate::{api_withdraw::WithdrawState, constant::OrderBy, response::WithdrawalRecord};
 
     #[tokio::test]
     async fn test_get_withdraw_list() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = TransactionInfo::request(
-            "ETH",
-            WithdrawState::Waiting,
-            None,
-            None,
-            1,
-            1,
-            OrderBy::Desc,
-        )
-        .await
-        .unwrap();
-        let res_serialized = res
-            .text()
+        crate::fixture::test_scope("get_withdraw_list", async {
+            let res = WithdrawalRecord::request(
+                "ETH",
+                WithdrawState::Waiting,
+                None,
+                None,
+                1,
+                1,
+                OrderBy::Desc,
+                None,
+                None,
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
             .unwrap();
+            let res_serialized = res.text();
 
-        if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
-        }
+            if res_serialized.contains("error") {
+                assert!(false, "Error response: {res_serialized}");
+            }
 
-        let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
-        let expected_structure = json!([{
-            "type": "",
-            "uuid": "",
-            "currency": "",
-            "net_type": "",
-            "txid": "",
-            "state": "",
-            "created_at": "",
-            "done_at": "",
-            "amount": "",
-            "fee": "",
-            "transaction_type": ""
-        }]);
-
-        let expected_structure = expected_structure[0]
-            .as_object()
-            .unwrap()
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(json_array) = json.as_array() {
-            for (index, item) in json_array.iter().enumerate() {
-                let (missing_keys, extra_keys) =
-                    compare_keys(item, &expected_structure, &format!("item[{}].", index));
-
-                if !missing_keys.is_empty() {
-                    println!(
-                        "[test_get_withdraw_list] Missing keys in item[{}]: {:?}",
-                        index, missing_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_withdraw_list] No keys are missing in item[{}]",
-                        index
-                    );
-                }
+            let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
+            let expected_structure = json!([{
+                "type": "",
+                "uuid": "",
+                "currency": "",
+                "net_type": "",
+                "txid": "",
+                "state": "",
+                "created_at": "",
+                "done_at": "",
+                "amount": "",
+                "fee": "",
+                "transaction_type": ""
+            }]);
+
+            let expected_structure = expected_structure[0]
+                .as_object()
+                .unwrap()
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.clone()))
+                .collect::<HashMap<&str, Value>>();
 
-                if !extra_keys.is_empty() {
-                    println!(
-                        "[test_get_withdraw_list] Extra keys in item[{}]: {:?}",
-                        index, extra_keys
-                    );
-                    assert!(false);
-                } else {
-                    println!(
-                        "[test_get_withdraw_list] No extra keys found in item[{}]",
-                        index
-                    );
+            if let Some(json_array) = json.as_array() {
+                for (index, item) in json_array.iter().enumerate() {
+                    let (missing_keys, extra_keys) =
+                        compare_keys(item, &expected_structure, &format!("item[{}].", index));
+
+                    if !missing_keys.is_empty() {
+                        println!(
+                            "[test_get_withdraw_list] Missing keys in item[{}]: {:?}",
+                            index, missing_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_withdraw_list] No keys are missing in item[{}]",
+                            index
+                        );
+                    }
+
+                    if !extra_keys.is_empty() {
+                        println!(
+                            "[test_get_withdraw_list] Extra keys in item[{}]: {:?}",
+                            index, extra_keys
+                        );
+                        assert!(false);
+                    } else {
+                        println!(
+                            "[test_get_withdraw_list] No extra keys found in item[{}]",
+                            index
+                        );
+                    }
                 }
+            } else {
+                assert!(false, "Expected an array of objects in the response");
             }
-        } else {
-            assert!(false, "Expected an array of objects in the response");
-        }
 
-        assert!(true);
+            assert!(true);
+        })
+        .await;
     }
 
     fn compare_keys(
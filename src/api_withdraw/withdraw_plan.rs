@@ -0,0 +1,270 @@
+use super::{
+    super::{constant::TransactionType, response::WithdrawChance},
+    withdrawable_wallet_state, WithdrawalError, WithdrawalRejection, WithdrawalRequest,
+};
+
+/// Amount a withdrawal should deliver
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WithdrawalTarget {
+    /// The destination receives at least this amount, rounded up to `withdraw_limit.fixed` decimals
+    Receive(f64),
+    /// As much as the balance, `onetime` and `remaining_daily` allow,
+    /// rounded down to `withdraw_limit.fixed` decimals
+    AllAvailable,
+}
+
+/// Amount to submit for a withdrawal, computed from [WithdrawChance]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithdrawalPlan {
+    pub currency: String,
+    pub net_type: String,
+    /// `amount` to submit, which is also the amount received
+    pub amount: f64,
+    /// `withdraw_fee`, charged on top of `amount`
+    pub fee: f64,
+    /// Balance debited by the withdrawal, `amount` + `fee`
+    pub total: f64,
+}
+
+impl WithdrawalPlan {
+    /// Fetch the withdraw chance and plan the withdrawal
+    ///
+    /// Fails with [WithdrawalError::Rejected] listing every [WithdrawalRejection] if the target cannot be met.
+    pub async fn plan(
+        currency: &str,
+        net_type: &str,
+        target: WithdrawalTarget,
+    ) -> Result<Self, WithdrawalError> {
+        let withdraw_chance = WithdrawChance::get_withdraw_chance(currency, net_type).await?;
+
+        Self::from_withdraw_chance(&withdraw_chance, net_type, target)
+            .map_err(WithdrawalError::Rejected)
+    }
+
+    /// Plan the withdrawal with the given withdraw chance
+    #[allow(deprecated)]
+    pub fn from_withdraw_chance(
+        withdraw_chance: &WithdrawChance,
+        net_type: &str,
+        target: WithdrawalTarget,
+    ) -> Result<Self, Vec<WithdrawalRejection>> {
+        let mut rejections = Vec::new();
+        let currency = &withdraw_chance.currency;
+        let withdraw_limit = &withdraw_chance.withdraw_limit;
+        let balance = withdraw_chance.account.balance;
+        let fee = currency.withdraw_fee;
+
+        if !currency.wallet_support.iter().any(|x| x == "withdraw")
+            || !withdrawable_wallet_state(&currency.wallet_state)
+            || !withdraw_limit.can_withdraw
+        {
+            rejections.push(WithdrawalRejection::WithdrawNotSupported {
+                wallet_state: currency.wallet_state.clone(),
+                wallet_support: currency.wallet_support.clone(),
+            });
+        }
+
+        let amount = match target {
+            WithdrawalTarget::Receive(amount) => {
+                if amount.is_nan() || amount <= 0.0 {
+                    rejections.push(WithdrawalRejection::InvalidAmount);
+                }
+
+                round_up(amount, withdraw_limit.fixed)
+            }
+            WithdrawalTarget::AllAvailable => {
+                let available = balance - fee;
+                let amount = withdraw_limit
+                    .onetime
+                    .map_or(available, |onetime| available.min(onetime))
+                    .min(withdraw_limit.remaining_daily);
+
+                if available <= 0.0 {
+                    rejections.push(WithdrawalRejection::InsufficientBalance {
+                        required: fee + withdraw_limit.minimum.unwrap_or_default(),
+                        available: balance,
+                    });
+                } else if withdraw_limit.remaining_daily <= 0.0 {
+                    rejections.push(WithdrawalRejection::OverRemainingDaily {
+                        amount: available,
+                        remaining_daily: withdraw_limit.remaining_daily,
+                    });
+                }
+
+                round_down(amount.max(0.0), withdraw_limit.fixed)
+            }
+        };
+
+        if let Some(minimum) = withdraw_limit.minimum {
+            if amount < minimum {
+                rejections.push(WithdrawalRejection::UnderMinimum { amount, minimum });
+            }
+        }
+
+        if let Some(onetime) = withdraw_limit.onetime {
+            if amount > onetime {
+                rejections.push(WithdrawalRejection::OverOnetime { amount, onetime });
+            }
+        }
+
+        if amount > withdraw_limit.remaining_daily {
+            rejections.push(WithdrawalRejection::OverRemainingDaily {
+                amount,
+                remaining_daily: withdraw_limit.remaining_daily,
+            });
+        }
+
+        if amount + fee > balance && target != WithdrawalTarget::AllAvailable {
+            rejections.push(WithdrawalRejection::InsufficientBalance {
+                required: amount + fee,
+                available: balance,
+            });
+        }
+
+        if !rejections.is_empty() {
+            return Err(rejections);
+        }
+
+        Ok(Self {
+            currency: currency.code.clone(),
+            net_type: net_type.to_owned(),
+            amount,
+            fee,
+            total: amount + fee,
+        })
+    }
+
+    /// Request to submit the plan through [super::WithdrawalGuard]
+    pub fn into_request(
+        self,
+        address: &str,
+        secondary_address: Option<&str>,
+        transaction_type: TransactionType,
+    ) -> WithdrawalRequest {
+        WithdrawalRequest {
+            currency: self.currency,
+            net_type: self.net_type,
+            amount: self.amount,
+            address: address.to_owned(),
+            secondary_address: secondary_address.map(str::to_owned),
+            transaction_type,
+        }
+    }
+}
+
+/// Scale of `fixed` decimals, tolerating the error of the float multiplication
+fn scaled(amount: f64, fixed: i32) -> (f64, f64) {
+    let scale = 10f64.powi(fixed);
+    let scaled = amount * scale;
+    let nearest = scaled.round();
+
+    if (scaled - nearest).abs() < 1e-6 {
+        (nearest, scale)
+    } else {
+        (scaled, scale)
+    }
+}
+
+fn round_up(amount: f64, fixed: Option<i32>) -> f64 {
+    match fixed {
+        Some(fixed) => {
+            let (scaled, scale) = scaled(amount, fixed);
+            scaled.ceil() / scale
+        }
+        None => amount,
+    }
+}
+
+fn round_down(amount: f64, fixed: Option<i32>) -> f64 {
+    match fixed {
+        Some(fixed) => {
+            let (scaled, scale) = scaled(amount, fixed);
+            scaled.floor() / scale
+        }
+        None => amount,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_withdraw::tests::withdraw_chance;
+
+    #[test]
+    fn test_plan_receive() {
+        let plan = WithdrawalPlan::from_withdraw_chance(
+            &withdraw_chance(1.0, None, 10.0),
+            "ETH",
+            WithdrawalTarget::Receive(0.123456789),
+        )
+        .unwrap();
+
+        assert_eq!(plan.amount, 0.1235);
+        assert_eq!(plan.fee, 0.01);
+        assert_eq!(plan.total, 0.1235 + 0.01);
+    }
+
+    #[test]
+    fn test_plan_all_available() {
+        let plan = WithdrawalPlan::from_withdraw_chance(
+            &withdraw_chance(0.3, None, 10.0),
+            "ETH",
+            WithdrawalTarget::AllAvailable,
+        )
+        .unwrap();
+        assert_eq!(plan.amount, 0.29);
+
+        let plan = WithdrawalPlan::from_withdraw_chance(
+            &withdraw_chance(0.3, Some(0.1), 10.0),
+            "ETH",
+            WithdrawalTarget::AllAvailable,
+        )
+        .unwrap();
+        assert_eq!(plan.amount, 0.1);
+    }
+
+    #[test]
+    fn test_plan_impossible() {
+        let rejections = WithdrawalPlan::from_withdraw_chance(
+            &withdraw_chance(0.02, None, 10.0),
+            "ETH",
+            WithdrawalTarget::Receive(0.015),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            rejections,
+            vec![
+                WithdrawalRejection::UnderMinimum {
+                    amount: 0.015,
+                    minimum: 0.02,
+                },
+                WithdrawalRejection::InsufficientBalance {
+                    required: 0.015 + 0.01,
+                    available: 0.02,
+                },
+            ]
+        );
+
+        let rejections = WithdrawalPlan::from_withdraw_chance(
+            &withdraw_chance(0.005, None, 10.0),
+            "ETH",
+            WithdrawalTarget::AllAvailable,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            rejections,
+            vec![
+                WithdrawalRejection::InsufficientBalance {
+                    required: 0.01 + 0.02,
+                    available: 0.005,
+                },
+                WithdrawalRejection::UnderMinimum {
+                    amount: 0.0,
+                    minimum: 0.02,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::api_exchange::{self, OrderSide, OrderState, OrderType};
+use crate::constant::OrderBy;
+use crate::response::{
+    AccountsInfo, ApiKey, OrderCancelAndNew, OrderChance, OrderInfo, OrderStatus, ResponseError,
+    WalletStatus,
+};
+
+use super::block_on;
+
+/// Blocking version of [api_exchange::order_by_price]
+pub fn order_by_price(
+    market_id: &str,
+    side: OrderSide,
+    price: f64,
+    price_desired: f64,
+    ord_type: OrderType,
+    identifier: Option<&str>,
+) -> Result<OrderInfo, ResponseError> {
+    block_on(api_exchange::order_by_price(
+        market_id,
+        side,
+        price,
+        price_desired,
+        ord_type,
+        identifier,
+    ))
+}
+
+/// Blocking version of [api_exchange::cancel_order_by_uuid]
+pub fn cancel_order_by_uuid(uuid: &str) -> Result<OrderInfo, ResponseError> {
+    block_on(api_exchange::cancel_order_by_uuid(uuid))
+}
+
+/// Blocking version of [api_exchange::cancel_order_by_identifier]
+pub fn cancel_order_by_identifier(identifier: &str) -> Result<OrderInfo, ResponseError> {
+    block_on(api_exchange::cancel_order_by_identifier(identifier))
+}
+
+/// Blocking version of [api_exchange::cancel_and_new_order_by_uuid]
+pub fn cancel_and_new_order_by_uuid(
+    prev_order_uuid: &str,
+    new_ord_type: OrderType,
+    new_volume: Option<f64>,
+    new_price: Option<f64>,
+    new_identifier: Option<&str>,
+) -> Result<OrderCancelAndNew, ResponseError> {
+    block_on(api_exchange::cancel_and_new_order_by_uuid(
+        prev_order_uuid,
+        new_ord_type,
+        new_volume,
+        new_price,
+        new_identifier,
+    ))
+}
+
+/// Blocking version of [api_exchange::cancel_and_new_order_by_identifier]
+pub fn cancel_and_new_order_by_identifier(
+    prev_order_identifier: &str,
+    new_ord_type: OrderType,
+    new_volume: Option<f64>,
+    new_price: Option<f64>,
+    new_identifier: Option<&str>,
+) -> Result<OrderCancelAndNew, ResponseError> {
+    block_on(api_exchange::cancel_and_new_order_by_identifier(
+        prev_order_identifier,
+        new_ord_type,
+        new_volume,
+        new_price,
+        new_identifier,
+    ))
+}
+
+/// Blocking version of [api_exchange::get_account_info]
+pub fn get_account_info() -> Result<Vec<AccountsInfo>, ResponseError> {
+    block_on(api_exchange::get_account_info())
+}
+
+/// Blocking version of [api_exchange::get_wallet_status]
+pub fn get_wallet_status() -> Result<Vec<WalletStatus>, ResponseError> {
+    block_on(api_exchange::get_wallet_status())
+}
+
+/// Blocking version of [api_exchange::get_api_keys]
+pub fn get_api_keys() -> Result<Vec<ApiKey>, ResponseError> {
+    block_on(api_exchange::get_api_keys())
+}
+
+/// Blocking version of [api_exchange::check_api_key_expiry]
+pub fn check_api_key_expiry(days: u64) -> Result<Option<ApiKey>, ResponseError> {
+    block_on(api_exchange::check_api_key_expiry(days))
+}
+
+/// Blocking version of [api_exchange::get_order_chance]
+pub fn get_order_chance(market_id: &str) -> Result<OrderChance, ResponseError> {
+    block_on(api_exchange::get_order_chance(market_id))
+}
+
+/// Blocking version of [api_exchange::get_order_status_by_uuid]
+pub fn get_order_status_by_uuid(uuid: &str) -> Result<OrderStatus, ResponseError> {
+    block_on(api_exchange::get_order_status_by_uuid(uuid))
+}
+
+/// Blocking version of [api_exchange::get_order_status_by_identifier]
+pub fn get_order_status_by_identifier(identifier: &str) -> Result<OrderStatus, ResponseError> {
+    block_on(api_exchange::get_order_status_by_identifier(identifier))
+}
+
+/// Blocking version of [api_exchange::list_order_status]
+#[deprecated(since = "1.6.0", note = "use get_order_status_*() instead")]
+#[allow(deprecated)]
+pub fn list_order_status() -> Result<Vec<OrderInfo>, ResponseError> {
+    block_on(api_exchange::list_order_status())
+}
+
+/// Blocking version of [api_exchange::get_order_status_list_by_uuids]
+pub fn get_order_status_list_by_uuids(
+    market_id: &str,
+    uuids: &[&str],
+    order_by: OrderBy,
+) -> Result<Vec<OrderInfo>, ResponseError> {
+    block_on(api_exchange::get_order_status_list_by_uuids(
+        market_id, uuids, order_by,
+    ))
+}
+
+/// Blocking version of [api_exchange::get_order_status_by_identifiers]
+pub fn get_order_status_by_identifiers(
+    market_id: &str,
+    identifiers: &[&str],
+    order_by: OrderBy,
+) -> Result<Vec<OrderInfo>, ResponseError> {
+    block_on(api_exchange::get_order_status_by_identifiers(
+        market_id,
+        identifiers,
+        order_by,
+    ))
+}
+
+/// Blocking version of [api_exchange::get_order_status_list_opened]
+pub fn get_order_status_list_opened(
+    market_id: &str,
+    states: &[OrderState],
+    page: u8,
+    limit: u8,
+    order_by: OrderBy,
+) -> Result<Vec<OrderInfo>, ResponseError> {
+    block_on(api_exchange::get_order_status_list_opened(
+        market_id, states, page, limit, order_by,
+    ))
+}
+
+/// Blocking version of [api_exchange::get_order_status_list_closed]
+pub fn get_order_status_list_closed(
+    market_id: &str,
+    states: &[OrderState],
+    start_time: Option<&str>,
+    end_time: Option<&str>,
+    limit: u16,
+    order_by: OrderBy,
+) -> Result<Vec<OrderInfo>, ResponseError> {
+    block_on(api_exchange::get_order_status_list_closed(
+        market_id, states, start_time, end_time, limit, order_by,
+    ))
+}
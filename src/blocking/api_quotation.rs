@@ -0,0 +1,115 @@
+use crate::api_quotation::{
+    self, CandleChartDay, CandleChartMinute, CandleChartMonth, CandleChartWeek, CandleMinute,
+    MarketState, OrderBookInfo, TickerSnapshot, TradeRecent,
+};
+use crate::response::ResponseError;
+
+use super::block_on;
+
+/// Blocking version of [api_quotation::get_order_book_info_list]
+pub fn get_order_book_info_list(markets_id: &[&str]) -> Result<Vec<OrderBookInfo>, ResponseError> {
+    block_on(api_quotation::get_order_book_info_list(markets_id))
+}
+
+/// Blocking version of [api_quotation::get_ticker_snapshot_list]
+pub fn get_ticker_snapshot_list(markets_id: &[&str]) -> Result<Vec<TickerSnapshot>, ResponseError> {
+    block_on(api_quotation::get_ticker_snapshot_list(markets_id))
+}
+
+/// Blocking version of [api_quotation::get_trade_recent_list]
+pub fn get_trade_recent_list(
+    market_id: &str,
+    hhmmss: Option<&str>,
+    count: u32,
+    cursor: &str,
+    days_ago: Option<u8>,
+) -> Result<Vec<TradeRecent>, ResponseError> {
+    block_on(api_quotation::get_trade_recent_list(
+        market_id, hhmmss, count, cursor, days_ago,
+    ))
+}
+
+/// Blocking version of [api_quotation::get_market_state_list]
+pub fn get_market_state_list(is_detailed: bool) -> Result<Vec<MarketState>, ResponseError> {
+    block_on(api_quotation::get_market_state_list(is_detailed))
+}
+
+/// Blocking version of [api_quotation::get_candle_minute_list]
+pub fn get_candle_minute_list(
+    market_id: &str,
+    to: Option<&str>,
+    count: u8,
+    candle_minute: CandleMinute,
+) -> Result<Vec<CandleChartMinute>, ResponseError> {
+    block_on(api_quotation::get_candle_minute_list(
+        market_id,
+        to,
+        count,
+        candle_minute,
+    ))
+}
+
+/// Blocking version of [api_quotation::get_candle_day_list]
+pub fn get_candle_day_list(
+    market_id: &str,
+    count: u8,
+    last_candle_time: Option<&str>,
+    price_unit: Option<&str>,
+) -> Result<Vec<CandleChartDay>, ResponseError> {
+    block_on(api_quotation::get_candle_day_list(
+        market_id,
+        count,
+        last_candle_time,
+        price_unit,
+    ))
+}
+
+/// Blocking version of [api_quotation::get_candle_week_list]
+pub fn get_candle_week_list(
+    market_id: &str,
+    count: u8,
+    last_candle_time: Option<&str>,
+) -> Result<Vec<CandleChartWeek>, ResponseError> {
+    block_on(api_quotation::get_candle_week_list(
+        market_id,
+        count,
+        last_candle_time,
+    ))
+}
+
+/// Blocking version of [api_quotation::get_candle_month_list]
+pub fn get_candle_month_list(
+    market_id: &str,
+    count: u8,
+    last_candle_time: Option<&str>,
+) -> Result<Vec<CandleChartMonth>, ResponseError> {
+    block_on(api_quotation::get_candle_month_list(
+        market_id,
+        count,
+        last_candle_time,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixture::ReplayTransport;
+
+    #[test]
+    fn test_get_ticker_snapshot_list() {
+        // the scope of a transport does not cross into the runtime of the blocking APIs
+        crate::set_transport(
+            ReplayTransport::open(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/blocking_get_ticker_snapshot_list.json"
+            ))
+            .unwrap(),
+        );
+        let res = super::get_ticker_snapshot_list(&["KRW-ETH", "KRW-BTC"]);
+        crate::clear_transport();
+
+        let res = res.unwrap();
+
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].market, "KRW-ETH");
+    }
+}
@@ -0,0 +1,74 @@
+/// Blocking version of [crate::api_exchange]
+pub mod api_exchange;
+/// Blocking version of [crate::api_quotation]
+pub mod api_quotation;
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::{Builder, Runtime};
+
+/// Runtime driving the async APIs called by the blocking ones
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+/// Client of the blocking APIs, whose pooled connections belong to [RUNTIME]
+static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Run `future` to completion on the runtime of this module
+///
+/// The future is polled on the calling thread while a worker of the multi-thread runtime drives the IO and timers,
+/// so blocking APIs called from several threads run concurrently.
+///
+/// # Panics
+///
+/// Panics if called within an async runtime, like `reqwest::blocking`.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    RUNTIME
+        .get_or_init(|| {
+            Builder::new_multi_thread()
+                .worker_threads(1)
+                .enable_all()
+                .build()
+                .expect("failed to build the runtime of the blocking APIs")
+        })
+        .block_on(crate::transport::scope_default_client(
+            CLIENT.get_or_init(reqwest::Client::new).clone(),
+            future,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_block_on() {
+        let value = block_on(async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            1
+        });
+        assert_eq!(value, 1);
+
+        let started_at = std::time::Instant::now();
+        let values = std::thread::scope(|s| {
+            let handles = (0..4)
+                .map(|x| {
+                    s.spawn(move || {
+                        block_on(async move {
+                            tokio::time::sleep(Duration::from_millis(100)).await;
+                            x * 2
+                        })
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(values, vec![0, 2, 4, 6]);
+        assert!(started_at.elapsed() < Duration::from_millis(400));
+    }
+}
@@ -26,6 +26,8 @@ pub const URL_ORDER_STATUS_BY_UUID: &str = "/v1/orders/uuids";
 pub const URL_ORDER_STATUS_OPEN: &str = "/v1/orders/open";
 /// URL of API getting order status closed
 pub const URL_ORDER_STATUS_CLOSED: &str = "/v1/orders/closed";
+/// URL of API canceling an order and placing a new one
+pub const URL_ORDER_CANCEL_AND_NEW: &str = "/v1/orders/cancel_and_new";
 
 /// URL of API getting order book
 pub const URL_ORDERBOOK: &str = "/v1/orderbook";
@@ -62,6 +64,18 @@ pub const URL_DEPOSITS_COIN_ADDRESSES: &str = "/v1/deposits/coin_addresses";
 /// URL of API requesting to deposit KRW
 pub const URL_DEPOSITS_KRW: &str = "/v1/deposits/krw";
 
+/// URL of API listing VASPs available for travel rule verification
+pub const URL_TRAVEL_RULE_VASPS: &str = "/v1/travel_rule/vasps";
+/// URL of API verifying travel rule of a deposit by its uuid
+pub const URL_TRAVEL_RULE_DEPOSIT_UUID: &str = "/v1/travel_rule/deposit/uuid";
+/// URL of API verifying travel rule of a deposit by its txid
+pub const URL_TRAVEL_RULE_DEPOSIT_TXID: &str = "/v1/travel_rule/deposit/txid";
+
+/// URL of API getting wallet status of every currency
+pub const URL_STATUS_WALLET: &str = "/v1/status/wallet";
+/// URL of API listing API keys and their expiry
+pub const URL_API_KEYS: &str = "/v1/api_keys";
+
 /// URL of API listing candle data of minute unit
 pub const URL_CANDLE_MINUTE: &str = "/v1/candles/minutes/";
 /// URL of API listing candle data of day unit
@@ -134,12 +148,16 @@ impl From<&str> for TransactionType {
 
 /// Kind of tow factor type
 ///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TwoFactorType {
     /// 카카오페이 인증
     #[deprecated(since = "1.7.3", note = "Use Kakao instead")]
     KakaoPay,
     /// 네이버 인증
     Naver,
+    /// 카카오 인증
+    Kakao,
+    #[deprecated(since = "1.13.3", note = "Use Kakao instead")]
     KaKao,
 }
 
@@ -149,6 +167,8 @@ impl Display for TwoFactorType {
             #[allow(deprecated)]
             TwoFactorType::KakaoPay => write!(f, "kakao_pay"),
             TwoFactorType::Naver => write!(f, "naver"),
+            TwoFactorType::Kakao => write!(f, "kakao"),
+            #[allow(deprecated)]
             TwoFactorType::KaKao => write!(f, "kakao"),
         }
     }
@@ -188,7 +208,7 @@ impl From<&str> for TransferType {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "sqlx-type", derive(sqlx::Type))]
 #[cfg_attr(
     feature = "sqlx-type",
@@ -217,7 +237,7 @@ impl Display for AskType {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "sqlx-type", derive(sqlx::Type))]
 #[cfg_attr(
     feature = "sqlx-type",
@@ -0,0 +1,287 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use zeroize::Zeroizing;
+
+use crate::response::{response_error_internal_credential_error, ResponseError};
+
+/// Environment variable of the access key read by [EnvCredentials::default]
+const ACCESS_KEY_VAR: &str = "ACCESS_KEY";
+/// Environment variable of the secret key read by [EnvCredentials::default]
+const SECRET_KEY_VAR: &str = "SECRET_KEY";
+
+/// Provider installed by [set_credential_provider]
+static CREDENTIAL_PROVIDER: RwLock<Option<Arc<dyn CredentialProvider>>> = RwLock::new(None);
+/// Keys set by [crate::set_access_key] and [crate::set_secret_key]
+static ACCESS_KEY: RwLock<Option<String>> = RwLock::new(None);
+static SECRET_KEY: RwLock<Option<Secret>> = RwLock::new(None);
+
+tokio::task_local! {
+    /// Credentials of the future run by [scope]
+    static SCOPED_CREDENTIALS: Credentials;
+}
+
+/// Secret string which is zeroized when dropped and redacted from [Debug]
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(Zeroizing<String>);
+
+impl Secret {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(Zeroizing::new(secret.into()))
+    }
+
+    /// Plain value of the secret, to be used for signing only
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Secret(<redacted>)")
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Access key and secret key signing the requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Credentials {
+    pub access_key: String,
+    pub secret_key: Secret,
+}
+
+impl Credentials {
+    pub fn new(access_key: &str, secret_key: impl Into<Secret>) -> Self {
+        Self {
+            access_key: access_key.to_owned(),
+            secret_key: secret_key.into(),
+        }
+    }
+}
+
+/// Source of the [Credentials] consulted every time a request is signed
+///
+/// Closures returning `Result<Credentials, ResponseError>` are providers as well.
+pub trait CredentialProvider: Send + Sync {
+    fn credentials(&self) -> Result<Credentials, ResponseError>;
+}
+
+/// Explicit credentials
+impl CredentialProvider for Credentials {
+    fn credentials(&self) -> Result<Credentials, ResponseError> {
+        Ok(self.clone())
+    }
+}
+
+/// Credentials returned by a callback
+impl<F> CredentialProvider for F
+where
+    F: Fn() -> Result<Credentials, ResponseError> + Send + Sync,
+{
+    fn credentials(&self) -> Result<Credentials, ResponseError> {
+        self()
+    }
+}
+
+/// Credentials read from environment variables
+#[derive(Debug, Clone)]
+pub struct EnvCredentials {
+    access_key_var: String,
+    secret_key_var: String,
+}
+
+impl EnvCredentials {
+    pub fn new(access_key_var: &str, secret_key_var: &str) -> Self {
+        Self {
+            access_key_var: access_key_var.to_owned(),
+            secret_key_var: secret_key_var.to_owned(),
+        }
+    }
+
+    fn var(name: &str) -> Result<String, ResponseError> {
+        if !envmnt::exists(name) {
+            return Err(response_error_internal_credential_error(format!(
+                "environment variable {name} is not set"
+            )));
+        }
+
+        Ok(envmnt::get_or(name, ""))
+    }
+}
+
+/// Reads `ACCESS_KEY` and `SECRET_KEY`
+impl Default for EnvCredentials {
+    fn default() -> Self {
+        Self::new(ACCESS_KEY_VAR, SECRET_KEY_VAR)
+    }
+}
+
+impl CredentialProvider for EnvCredentials {
+    fn credentials(&self) -> Result<Credentials, ResponseError> {
+        Ok(Credentials::new(
+            &Self::var(&self.access_key_var)?,
+            Self::var(&self.secret_key_var)?,
+        ))
+    }
+}
+
+/// Credentials read from files, such as a Kubernetes secret mount
+///
+/// Files are read every time a request is signed, so rotated keys are picked up without a restart.
+/// Leading and trailing whitespace is trimmed.
+#[derive(Debug, Clone)]
+pub struct FileCredentials {
+    access_key_path: PathBuf,
+    secret_key_path: PathBuf,
+}
+
+impl FileCredentials {
+    pub fn new(access_key_path: impl Into<PathBuf>, secret_key_path: impl Into<PathBuf>) -> Self {
+        Self {
+            access_key_path: access_key_path.into(),
+            secret_key_path: secret_key_path.into(),
+        }
+    }
+
+    fn read(path: &PathBuf) -> Result<Zeroizing<String>, ResponseError> {
+        std::fs::read_to_string(path)
+            .map(Zeroizing::new)
+            .map_err(|e| {
+                response_error_internal_credential_error(format!("{}: {e}", path.display()))
+            })
+    }
+}
+
+impl CredentialProvider for FileCredentials {
+    fn credentials(&self) -> Result<Credentials, ResponseError> {
+        let access_key = Self::read(&self.access_key_path)?;
+        let secret_key = Self::read(&self.secret_key_path)?;
+
+        Ok(Credentials::new(access_key.trim(), secret_key.trim()))
+    }
+}
+
+/// Install the provider consulted by every request instead of the keys set by [crate::set_access_key] and [crate::set_secret_key]
+pub fn set_credential_provider(provider: impl CredentialProvider + 'static) {
+    *CREDENTIAL_PROVIDER.write().unwrap() = Some(Arc::new(provider));
+}
+
+/// Remove the provider installed by [set_credential_provider]
+pub fn clear_credential_provider() {
+    *CREDENTIAL_PROVIDER.write().unwrap() = None;
+}
+
+/// Run `future` signing every request made in it with `credentials`, regardless of the installed provider
+pub async fn scope<F: Future>(credentials: Credentials, future: F) -> F::Output {
+    SCOPED_CREDENTIALS.scope(credentials, future).await
+}
+
+pub(crate) fn set_access_key(access_key: &str) {
+    *ACCESS_KEY.write().unwrap() = Some(access_key.to_owned());
+}
+
+pub(crate) fn set_secret_key(secret_key: &str) {
+    *SECRET_KEY.write().unwrap() = Some(Secret::new(secret_key));
+}
+
+/// Credentials of the current [scope], the installed provider, or the keys set by [crate::set_access_key] and [crate::set_secret_key],
+/// falling back to [EnvCredentials::default] for the keys not set
+pub(crate) fn credentials() -> Result<Credentials, ResponseError> {
+    if let Ok(credentials) = SCOPED_CREDENTIALS.try_with(Credentials::clone) {
+        return Ok(credentials);
+    }
+
+    let provider = CREDENTIAL_PROVIDER.read().unwrap().clone();
+
+    if let Some(provider) = provider {
+        return provider.credentials();
+    }
+
+    let access_key = match ACCESS_KEY.read().unwrap().clone() {
+        Some(access_key) => access_key,
+        None => EnvCredentials::var(ACCESS_KEY_VAR)?,
+    };
+    let secret_key = match SECRET_KEY.read().unwrap().clone() {
+        Some(secret_key) => secret_key,
+        None => Secret::new(EnvCredentials::var(SECRET_KEY_VAR)?),
+    };
+
+    Ok(Credentials {
+        access_key,
+        secret_key,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_scoped_credentials() {
+        let credentials = scope(Credentials::new("scoped access", "scoped secret"), async {
+            super::credentials().unwrap()
+        })
+        .await;
+
+        assert_eq!(credentials.access_key, "scoped access");
+    }
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let credentials = Credentials::new("access", "top secret");
+
+        assert_eq!(credentials.secret_key.expose(), "top secret");
+        assert!(!format!("{credentials:?}").contains("top secret"));
+    }
+
+    #[test]
+    fn test_providers() {
+        let dir = std::env::temp_dir().join(format!("upbit-credentials-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("access_key"), "file access\n").unwrap();
+        std::fs::write(dir.join("secret_key"), "file secret\n").unwrap();
+
+        let credentials = FileCredentials::new(dir.join("access_key"), dir.join("secret_key"))
+            .credentials()
+            .unwrap();
+        assert_eq!(credentials, Credentials::new("file access", "file secret"));
+
+        assert!(
+            FileCredentials::new(dir.join("missing"), dir.join("secret_key"))
+                .credentials()
+                .is_err()
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        envmnt::set("UPBIT_TEST_ACCESS_KEY", "env access");
+        envmnt::set("UPBIT_TEST_SECRET_KEY", "env secret");
+        let credentials = EnvCredentials::new("UPBIT_TEST_ACCESS_KEY", "UPBIT_TEST_SECRET_KEY")
+            .credentials()
+            .unwrap();
+        assert_eq!(credentials, Credentials::new("env access", "env secret"));
+        assert!(
+            EnvCredentials::new("UPBIT_TEST_MISSING", "UPBIT_TEST_SECRET_KEY")
+                .credentials()
+                .is_err()
+        );
+
+        let callback = || Ok(Credentials::new("callback access", "callback secret"));
+        assert_eq!(
+            callback.credentials().unwrap().access_key,
+            "callback access"
+        );
+    }
+}
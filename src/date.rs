@@ -0,0 +1,140 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seconds of a day
+pub(crate) const DAY_SECS: i64 = 24 * 60 * 60;
+/// Offset of KST from UTC in seconds. Upbit resets its daily limits at 00:00 KST
+pub(crate) const KST_OFFSET_SECS: i64 = 9 * 60 * 60;
+
+/// Milliseconds since the unix epoch
+pub(crate) fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// Seconds since the unix epoch
+pub(crate) fn now_secs() -> i64 {
+    now_ms().div_euclid(1000)
+}
+
+/// Days since the unix epoch of a date, http://howardhinnant.github.io/date_algorithms.html
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date of days since the unix epoch, http://howardhinnant.github.io/date_algorithms.html
+#[cfg(feature = "mock")]
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/// `YYYY-MM-DD` and `hh:mm:ss` of a unix timestamp in milliseconds
+#[cfg(feature = "mock")]
+pub(crate) fn date_time(timestamp: i64) -> (String, String) {
+    let seconds = timestamp.div_euclid(1000);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(DAY_SECS));
+    let seconds = seconds.rem_euclid(DAY_SECS);
+
+    (
+        format!("{year:04}-{month:02}-{day:02}"),
+        format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        ),
+    )
+}
+
+/// `YYYY-MM-DDThh:mm:ss+09:00` of a unix timestamp in milliseconds
+#[cfg(feature = "mock")]
+pub(crate) fn kst_date_time(timestamp: i64) -> String {
+    let (date, time) = date_time(timestamp + KST_OFFSET_SECS * 1000);
+
+    format!("{date}T{time}+09:00")
+}
+
+/// Seconds since the unix epoch of a `YYYY-MM-DDThh:mm:ss(.fff)(Z|±hh:mm)` date string
+pub(crate) fn parse_rfc3339(date: &str) -> Option<i64> {
+    let number = |range: std::ops::Range<usize>| date.get(range)?.parse::<i64>().ok();
+
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+
+    let offset = match date
+        .get(19..)?
+        .trim_start_matches(|c: char| c == '.' || c.is_ascii_digit())
+    {
+        "" | "Z" => 0,
+        zone => {
+            let sign = match zone.get(..1)? {
+                "+" => 1,
+                "-" => -1,
+                _ => return None,
+            };
+
+            sign * (zone.get(1..3)?.parse::<i64>().ok()? * 3600
+                + zone.get(4..6)?.parse::<i64>().ok()? * 60)
+        }
+    };
+
+    Some(days_from_civil(year, month, day) * DAY_SECS + hour * 3600 + minute * 60 + second - offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_rfc3339("2021-03-24T11:26:40+09:00"),
+            Some(1_616_552_800)
+        );
+        assert_eq!(
+            parse_rfc3339("2024-06-25T06:43:48.392+00:00"),
+            Some(1_719_297_828)
+        );
+        assert_eq!(parse_rfc3339("not a date"), None);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_date_time() {
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+        assert_eq!(
+            date_time(1_719_297_828_392),
+            ("2024-06-25".to_owned(), "06:43:48".to_owned())
+        );
+        assert_eq!(
+            kst_date_time(1_616_552_800_000),
+            "2021-03-24T11:26:40+09:00"
+        );
+        assert_eq!(
+            parse_rfc3339(&kst_date_time(1_616_552_800_000)),
+            Some(1_616_552_800)
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::credential::{self, Credentials};
+use crate::response::{
+    response_error_internal_io_error, response_error_internal_transport_error, ResponseError,
+};
+use crate::transport::{
+    HttpRequest, HttpResponse, HttpTransport, ReqwestTransport, TransportFuture,
+};
+
+/// Environment variable selecting the [FixtureMode] of [scope]
+const FIXTURE_MODE_VAR: &str = "UPBIT_FIXTURES";
+/// Fields of the response bodies replaced by [SCRUBBED] before being recorded
+const SCRUBBED_FIELDS: &[&str] = &["access_key"];
+/// Value replacing the scrubbed fields
+const SCRUBBED: &str = "<scrubbed>";
+
+/// Whether [scope] records responses or replays them
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FixtureMode {
+    /// Send requests with the configured credentials and record the responses
+    Record,
+    /// Answer requests with the recorded responses, without network
+    Replay,
+}
+
+impl FixtureMode {
+    /// [FixtureMode::Record] if `UPBIT_FIXTURES` is `record`, [FixtureMode::Replay] otherwise
+    pub fn from_env() -> Self {
+        match envmnt::get_or(FIXTURE_MODE_VAR, "").as_str() {
+            "record" => Self::Record,
+            _ => Self::Replay,
+        }
+    }
+}
+
+/// Request and the response recorded for it, without the `Authorization` header
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Interaction {
+    pub method: String,
+    pub url: String,
+    pub body: Option<Value>,
+    pub status: u16,
+    /// Headers of the response, such as `Remaining-Req`
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Body of the response, as JSON if it parses
+    pub response: Value,
+}
+
+impl Interaction {
+    fn matches(&self, request: &HttpRequest) -> bool {
+        self.method == request.method.as_str()
+            && self.url == request.url
+            && self.body == request_body(request)
+    }
+
+    fn to_response(&self) -> Result<HttpResponse, ResponseError> {
+        let status = reqwest::StatusCode::from_u16(self.status)
+            .map_err(response_error_internal_transport_error)?;
+        let body = match &self.response {
+            Value::String(body) => body.clone(),
+            response => response.to_string(),
+        };
+
+        let mut headers = HeaderMap::new();
+
+        for (name, value) in &self.headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes())
+                    .map_err(response_error_internal_transport_error)?,
+                HeaderValue::from_str(value).map_err(response_error_internal_transport_error)?,
+            );
+        }
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Transport sending requests with another transport and recording them to a fixture file
+pub struct RecordingTransport<T: HttpTransport> {
+    inner: T,
+    path: PathBuf,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl<T: HttpTransport> RecordingTransport<T> {
+    /// Record to `path`, overwritten by the first request
+    pub fn new(inner: T, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+            interactions: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn record(&self, interaction: Interaction) -> Result<(), ResponseError> {
+        let mut interactions = self.interactions.lock().unwrap();
+        interactions.push(interaction);
+
+        let serialized = serde_json::to_string_pretty(&*interactions)
+            .map_err(crate::response::response_error_from_json)?;
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(response_error_internal_io_error)?;
+        }
+
+        std::fs::write(&self.path, serialized + "\n").map_err(response_error_internal_io_error)
+    }
+}
+
+impl<T: HttpTransport> HttpTransport for RecordingTransport<T> {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let method = request.method.to_string();
+            let url = request.url.clone();
+            let body = request_body(&request);
+
+            let res = self.inner.send(request).await?;
+
+            self.record(Interaction {
+                method,
+                url,
+                body,
+                status: res.status.as_u16(),
+                headers: recorded_headers(&res.headers),
+                response: scrub(
+                    serde_json::from_str(&res.body).unwrap_or(Value::String(res.body.clone())),
+                ),
+            })?;
+
+            Ok(res)
+        })
+    }
+}
+
+/// Transport answering requests with the interactions of a fixture file, each at most once, in order
+#[derive(Debug, Default)]
+pub struct ReplayTransport {
+    interactions: Mutex<Vec<Option<Interaction>>>,
+}
+
+impl ReplayTransport {
+    pub fn new(interactions: Vec<Interaction>) -> Self {
+        Self {
+            interactions: Mutex::new(interactions.into_iter().map(Some).collect()),
+        }
+    }
+
+    /// Replay the fixture recorded to `path` by [RecordingTransport]
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ResponseError> {
+        let serialized = std::fs::read_to_string(path).map_err(response_error_internal_io_error)?;
+        let interactions =
+            serde_json::from_str(&serialized).map_err(crate::response::response_error_from_json)?;
+
+        Ok(Self::new(interactions))
+    }
+}
+
+impl HttpTransport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut interactions = self.interactions.lock().unwrap();
+
+            let interaction = interactions
+                .iter_mut()
+                .find(|interaction| {
+                    interaction
+                        .as_ref()
+                        .is_some_and(|interaction| interaction.matches(&request))
+                })
+                .and_then(Option::take)
+                .ok_or_else(|| {
+                    response_error_internal_transport_error(format!(
+                        "no recorded response for {} {}",
+                        request.method, request.url
+                    ))
+                })?;
+
+            interaction.to_response()
+        })
+    }
+}
+
+/// Run `future` recording or replaying its requests to the fixture of `path`, depending on [FixtureMode::from_env]
+///
+/// Requests are signed with the configured credentials while recording, and with dummy ones while replaying.
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be read while replaying.
+pub async fn scope<F: Future>(path: impl AsRef<Path>, future: F) -> F::Output {
+    let path = path.as_ref();
+
+    match FixtureMode::from_env() {
+        FixtureMode::Record => {
+            let transport = RecordingTransport::new(ReqwestTransport::new(), path);
+
+            crate::transport::scope(transport, future).await
+        }
+        FixtureMode::Replay => {
+            let transport = ReplayTransport::open(path)
+                .unwrap_or_else(|e| panic!("failed to read the fixture {}: {e:?}", path.display()));
+            let credentials = Credentials::new("replay access key", "replay secret key");
+
+            crate::transport::scope(transport, credential::scope(credentials, future)).await
+        }
+    }
+}
+
+/// Run `future` of a test of this crate against the fixture `tests/fixtures/{name}.json`
+///
+/// The fixture is recorded with `TEST_ACCESS_KEY` and `TEST_SECRET_KEY` if `UPBIT_FIXTURES` is `record`.
+pub async fn test_scope<F: Future>(name: &str, future: F) -> F::Output {
+    if FixtureMode::from_env() == FixtureMode::Record {
+        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
+        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+    }
+
+    let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+
+    scope(path, future).await
+}
+
+/// Headers of a response to record, without `Authorization` and the values which are not text
+fn recorded_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    let mut recorded = BTreeMap::<String, String>::new();
+
+    for (name, value) in headers {
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+
+        if name == AUTHORIZATION {
+            continue;
+        }
+
+        recorded
+            .entry(name.to_string())
+            .and_modify(|recorded| {
+                recorded.push_str(", ");
+                recorded.push_str(value);
+            })
+            .or_insert_with(|| value.to_owned());
+    }
+
+    recorded
+}
+
+fn request_body(request: &HttpRequest) -> Option<Value> {
+    request
+        .body
+        .as_deref()
+        .map(|body| serde_json::from_str(body).unwrap_or_else(|_| Value::String(body.to_owned())))
+}
+
+/// Replace the values of [SCRUBBED_FIELDS] at any depth
+fn scrub(mut value: Value) -> Value {
+    match &mut value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                *field = if SCRUBBED_FIELDS.contains(&key.as_str()) {
+                    Value::from(SCRUBBED)
+                } else {
+                    scrub(field.take())
+                };
+            }
+        }
+        Value::Array(items) => {
+            for item in items.iter_mut() {
+                *item = scrub(item.take());
+            }
+        }
+        _ => {}
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Transport answering every request with the same body and headers
+    struct FixedTransport(&'static str);
+
+    impl HttpTransport for FixedTransport {
+        fn send(&self, _request: HttpRequest) -> TransportFuture<'_> {
+            Box::pin(async {
+                let mut res = HttpResponse::new(reqwest::StatusCode::OK, self.0);
+                res.headers.insert(
+                    HeaderName::from_static("remaining-req"),
+                    HeaderValue::from_static("group=default; min=1800; sec=29"),
+                );
+                res.headers
+                    .insert(AUTHORIZATION, HeaderValue::from_static("Bearer token"));
+
+                Ok(res)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let path = std::env::temp_dir().join(format!("upbit-fixture-{}.json", std::process::id()));
+
+        let keys = crate::transport::scope(
+            RecordingTransport::new(
+                FixedTransport(r#"[{"access_key":"xxxxxxxxxxxxxxxxxxxxxxxx","expire_at":"2025-06-17T10:00:00+09:00"}]"#),
+                &path,
+            ),
+            credential::scope(
+                Credentials::new("access key", "secret key"),
+                crate::api_exchange::get_api_keys(),
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(keys[0].access_key, "xxxxxxxxxxxxxxxxxxxxxxxx");
+
+        let recorded = std::fs::read_to_string(&path).unwrap();
+        assert!(!recorded.contains("xxxxxxxxxxxxxxxxxxxxxxxx"));
+        assert!(!recorded.contains("Bearer"));
+
+        let replayed = ReplayTransport::open(&path)
+            .unwrap()
+            .send(HttpRequest::get("https://api.upbit.com/v1/api_keys"))
+            .await
+            .unwrap();
+        assert_eq!(
+            replayed.headers["remaining-req"],
+            "group=default; min=1800; sec=29"
+        );
+        assert!(!replayed.headers.contains_key(AUTHORIZATION));
+
+        let transport = ReplayTransport::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let keys = crate::transport::scope(
+            transport,
+            credential::scope(
+                Credentials::new("replay access key", "replay secret key"),
+                async {
+                    (
+                        crate::api_exchange::get_api_keys().await,
+                        crate::api_exchange::get_api_keys().await,
+                    )
+                },
+            ),
+        )
+        .await;
+        assert_eq!(keys.0.unwrap()[0].access_key, SCRUBBED);
+        assert!(keys.1.is_err());
+    }
+
+    #[test]
+    fn test_scrub() {
+        assert_eq!(
+            scrub(json!({"keys": [{"access_key": "key", "expire_at": "2025-06-17"}]})),
+            json!({"keys": [{"access_key": SCRUBBED, "expire_at": "2025-06-17"}]})
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use reqwest::Url;
+use serde_json::Value;
+use tracing::{field, Instrument};
+
+use crate::response::{ResponseError, ResponseErrorSource};
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+
+/// Header of the request quota left in the group of the API
+const REMAINING_REQ: &str = "Remaining-Req";
+/// Parameters recorded on the span of a request
+const RECORDED_PARAMS: &[&str] = &["market", "side", "uuid", "identifier"];
+
+/// Send `request` in a span of the API, recording the result once received
+///
+/// Only the path, the parameters of [RECORDED_PARAMS] and the response metadata are recorded;
+/// headers, including the `Authorization` token, are never.
+pub(crate) async fn send(
+    request: HttpRequest,
+    transport: Arc<dyn HttpTransport>,
+) -> Result<HttpResponse, ResponseError> {
+    let url = Url::parse(&request.url).ok();
+    let body = request
+        .body
+        .as_deref()
+        .and_then(|body| serde_json::from_str::<Value>(body).ok());
+
+    let span = tracing::info_span!(
+        "upbit_api",
+        endpoint = url.as_ref().map_or("", |url| url.path()),
+        method = %request.method,
+        market = field::Empty,
+        side = field::Empty,
+        uuid = field::Empty,
+        identifier = field::Empty,
+        status = field::Empty,
+        duration_ms = field::Empty,
+        remaining_req = field::Empty,
+    );
+
+    for key in RECORDED_PARAMS {
+        if let Some(value) = param(url.as_ref(), body.as_ref(), key) {
+            span.record(*key, value.as_str());
+        }
+    }
+
+    let started_at = Instant::now();
+    let result = crate::middleware::send(request, transport)
+        .instrument(span.clone())
+        .await;
+
+    span.record("duration_ms", started_at.elapsed().as_millis() as u64);
+
+    let _entered = span.enter();
+
+    match &result {
+        Ok(res) => {
+            span.record("status", res.status.as_u16());
+
+            if let Some(remaining_req) = res
+                .headers
+                .get(REMAINING_REQ)
+                .and_then(|value| value.to_str().ok())
+            {
+                span.record("remaining_req", remaining_req);
+            }
+
+            if let Ok(source) = serde_json::from_str::<ResponseErrorSource>(&res.body) {
+                tracing::warn!(
+                    name = %source.error.name,
+                    message = %source.error.message,
+                    "error response"
+                );
+            }
+        }
+        Err(e) => {
+            tracing::warn!(
+                name = %e.error.name,
+                message = %e.error.message,
+                "request failed"
+            );
+        }
+    }
+
+    result
+}
+
+/// Value of the parameter `key` in the query string or the JSON body
+fn param(url: Option<&Url>, body: Option<&Value>, key: &str) -> Option<String> {
+    let from_query = url.and_then(|url| {
+        url.query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.into_owned())
+    });
+
+    from_query.or_else(|| {
+        body.and_then(|body| body.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_owned)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+    use std::sync::Mutex;
+
+    use reqwest::header::{HeaderValue, AUTHORIZATION};
+    use reqwest::StatusCode;
+    use serde_json::json;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::transport::TransportFuture;
+
+    use super::*;
+
+    /// Transport answering every request with an error body
+    struct ErrorTransport;
+
+    impl HttpTransport for ErrorTransport {
+        fn send(&self, _request: HttpRequest) -> TransportFuture<'_> {
+            Box::pin(async {
+                let mut res = HttpResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    r#"{"error":{"name":"insufficient_funds_bid","message":"주문가능한 금액(KRW)이 부족합니다."}}"#,
+                );
+                res.headers.insert(
+                    REMAINING_REQ,
+                    HeaderValue::from_static("group=order; min=59; sec=7"),
+                );
+
+                Ok(res)
+            })
+        }
+    }
+
+    /// Subscriber keeping every field recorded on spans and events
+    #[derive(Default)]
+    struct RecordingSubscriber {
+        fields: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl Visit for RecordingSubscriber {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.fields
+                .lock()
+                .unwrap()
+                .push((field.name().to_owned(), format!("{value:?}")));
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut Self {
+                fields: self.fields.clone(),
+            });
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, values: &Record<'_>) {
+            values.record(&mut Self {
+                fields: self.fields.clone(),
+            });
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut Self {
+                fields: self.fields.clone(),
+            });
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[tokio::test]
+    async fn test_send_records_span() {
+        let subscriber = RecordingSubscriber::default();
+        let fields = subscriber.fields.clone();
+        let _default = tracing::subscriber::set_default(subscriber);
+
+        let request = HttpRequest::post("https://api.upbit.com/v1/orders")
+            .header(AUTHORIZATION, "Bearer secret.jwt.token")
+            .json(&json!({
+                "market": "KRW-BTC",
+                "side": "bid",
+                "identifier": "grid-1",
+            }));
+
+        send(request, Arc::new(ErrorTransport)).await.unwrap();
+
+        let fields = fields.lock().unwrap();
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        assert_eq!(field("endpoint"), Some("\"/v1/orders\""));
+        assert_eq!(field("market"), Some("\"KRW-BTC\""));
+        assert_eq!(field("side"), Some("\"bid\""));
+        assert_eq!(field("identifier"), Some("\"grid-1\""));
+        assert_eq!(field("status"), Some("400"));
+        assert_eq!(
+            field("remaining_req"),
+            Some("\"group=order; min=59; sec=7\"")
+        );
+        assert_eq!(field("name"), Some("insufficient_funds_bid"));
+        assert!(fields.iter().all(|(_, value)| !value.contains("secret")));
+    }
+}
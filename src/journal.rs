@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::response::ResponseError;
+
+/// Records keyed by id, optionally persisted as a json file
+///
+/// The file is rewritten through a temporary file and a rename on every [Journal::flush],
+/// so a crash leaves either the previous or the new contents, never a partial write.
+#[derive(Debug)]
+pub(crate) struct Journal<V> {
+    path: Option<PathBuf>,
+    pub(crate) records: BTreeMap<String, V>,
+}
+
+impl<V: Serialize + DeserializeOwned> Journal<V> {
+    /// Journal kept in memory only
+    pub(crate) fn in_memory() -> Self {
+        Self {
+            path: None,
+            records: BTreeMap::new(),
+        }
+    }
+
+    /// Open the journal stored at `path`, creating it on first flush if it does not exist
+    pub(crate) fn open(path: impl AsRef<Path>) -> Result<Self, ResponseError> {
+        let path = path.as_ref().to_path_buf();
+        let records = match std::fs::read_to_string(&path) {
+            Ok(contents) if !contents.trim().is_empty() => serde_json::from_str(&contents)
+                .map_err(crate::response::response_error_from_json)?,
+            Ok(_) => BTreeMap::new(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(crate::response::response_error_internal_io_error(e)),
+        };
+
+        Ok(Self {
+            path: Some(path),
+            records,
+        })
+    }
+
+    pub(crate) fn flush(&self) -> Result<(), ResponseError> {
+        self.write(&self.records)
+    }
+
+    /// Persist `records` and keep them in place of the current records, which are left untouched if it fails
+    pub(crate) fn replace(&mut self, records: BTreeMap<String, V>) -> Result<(), ResponseError> {
+        self.write(&records)?;
+        self.records = records;
+
+        Ok(())
+    }
+
+    fn write(&self, records: &BTreeMap<String, V>) -> Result<(), ResponseError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let contents = serde_json::to_string_pretty(records)
+            .map_err(crate::response::response_error_from_json)?;
+        let path_tmp = path.with_extension("tmp");
+
+        std::fs::write(&path_tmp, contents)
+            .and_then(|_| std::fs::rename(&path_tmp, path))
+            .map_err(crate::response::response_error_internal_io_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flush_and_reopen() {
+        let path =
+            std::env::temp_dir().join(format!("upbit-journal-{}.json", uuid::Uuid::new_v4()));
+
+        let mut journal = Journal::open(&path).unwrap();
+        assert!(journal.records.is_empty());
+
+        journal.records.insert("a".to_owned(), 1.5);
+        journal.flush().unwrap();
+
+        let reopened = Journal::<f64>::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(reopened.records.get("a"), Some(&1.5));
+        assert!(!path.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn test_failed_replace_keeps_records() {
+        // the directory of the file does not exist, so the write fails
+        let path = std::env::temp_dir()
+            .join(uuid::Uuid::new_v4().to_string())
+            .join("journal.json");
+        let mut journal = Journal::open(path).unwrap();
+        journal.records.insert("a".to_owned(), 1.5);
+
+        assert!(journal.replace(BTreeMap::new()).is_err());
+        assert_eq!(journal.records.get("a"), Some(&1.5));
+
+        let mut journal = Journal::in_memory();
+        journal
+            .replace(BTreeMap::from([("b".to_owned(), 2.5)]))
+            .unwrap();
+        assert_eq!(journal.records.get("b"), Some(&2.5));
+    }
+}
@@ -1,3 +1,5 @@
+/// Module for managing multiple accounts
+pub mod account_manager;
 /// Module for deposit APIs
 pub mod api_deposit;
 /// Module for exchange APIs
@@ -6,21 +8,54 @@ pub mod api_exchange;
 pub mod api_quotation;
 /// Module for withdrawal APIs
 pub mod api_withdraw;
+/// Module for blocking APIs, enabled by the `blocking` feature
+#[cfg(feature = "blocking")]
+pub mod blocking;
 /// Set of constants
 pub mod constant;
+/// Set of credential providers
+pub mod credential;
+/// Calendar helpers for the dates sent by the API
+mod date;
+/// Module for recording and replaying HTTP fixtures
+pub mod fixture;
+/// Json file journals persisting client-side state
+mod journal;
+/// Spans and events of the `tracing` feature
+#[cfg(feature = "tracing")]
+mod instrument;
+/// Prometheus metrics of the `metrics` feature
+///
+/// WebSocket reconnects are not counted, as the crate has no WebSocket client yet.
+#[cfg(feature = "metrics")]
+pub mod metrics;
+/// Module for request and response hooks
+pub mod middleware;
+/// In-process mock server of the `mock` feature
+#[cfg(feature = "mock")]
+pub mod mock;
 /// Set of concrete request bodies
 pub mod request;
 /// Set of concrete response bodies
 pub mod response;
+/// Module for pluggable HTTP transports
+pub mod transport;
 
+pub use credential::{clear_credential_provider, set_credential_provider};
+pub use middleware::{add_middleware, clear_middlewares};
 pub use request::{Request, RequestWithQuery};
+pub use transport::{clear_base_url, clear_transport, set_base_url, set_transport};
 
 /// function for setting secret key
+///
+/// The key is kept in the process, not in the environment, and is ignored while a provider is set by [set_credential_provider].
 pub fn set_secret_key(secret_key: &str) {
-    envmnt::set("SECRET_KEY", secret_key);
+    credential::set_secret_key(secret_key);
 }
 
 /// function for setting access_key
+///
+/// The key is kept in the process, not in the environment, and is ignored while a provider is set by [set_credential_provider].
 pub fn set_access_key(access_key: &str) {
-    envmnt::set("ACCESS_KEY", access_key);
+    credential::set_access_key(access_key);
 }
@@ -0,0 +1,558 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use reqwest::Method;
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, ToSocketAddrs};
+
+use crate::middleware::{Middleware, RequestContext, ResponseContext};
+use crate::response::{ObjectTrades, ResponseError};
+
+/// Upper bounds in seconds of the buckets of `upbit_request_duration_seconds`
+const DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+/// Header of the request quota left in the group of the API
+const REMAINING_REQ: &str = "Remaining-Req";
+
+/// Metrics recorded since the start of the process
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry::new());
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Histogram {
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; DURATION_BUCKETS.len()];
+        }
+
+        for (bucket, upper_bound) in self.buckets.iter_mut().zip(DURATION_BUCKETS) {
+            if value <= *upper_bound {
+                *bucket += 1;
+            }
+        }
+
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    /// by endpoint, method and status
+    requests: BTreeMap<(String, String, String), u64>,
+    /// by endpoint and method
+    durations: BTreeMap<(String, String), Histogram>,
+    /// by endpoint and [crate::response::ResponseErrorState]
+    errors: BTreeMap<(String, String), u64>,
+    /// by group
+    rate_limit_remaining: BTreeMap<String, u64>,
+    /// by action, market and side
+    orders: BTreeMap<(String, String, String), u64>,
+    /// by market and side
+    fills: BTreeMap<(String, String), u64>,
+}
+
+impl Registry {
+    const fn new() -> Self {
+        Self {
+            requests: BTreeMap::new(),
+            durations: BTreeMap::new(),
+            errors: BTreeMap::new(),
+            rate_limit_remaining: BTreeMap::new(),
+            orders: BTreeMap::new(),
+            fills: BTreeMap::new(),
+        }
+    }
+
+    fn record_response(
+        &mut self,
+        request: &RequestContext,
+        response: &ResponseContext,
+        error: Option<&ResponseError>,
+    ) {
+        let endpoint = request.endpoint.clone();
+        let method = request.method.to_string();
+        let status = response
+            .status
+            .map_or_else(|| "none".to_owned(), |status| status.as_u16().to_string());
+
+        *self
+            .requests
+            .entry((endpoint.clone(), method.clone(), status))
+            .or_default() += 1;
+        self.durations
+            .entry((endpoint.clone(), method))
+            .or_default()
+            .observe(response.duration.as_secs_f64());
+
+        if let Some((group, remaining)) = response
+            .headers
+            .get(REMAINING_REQ)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_remaining_req)
+        {
+            self.rate_limit_remaining.insert(group, remaining);
+        }
+
+        if let Some(error) = error {
+            *self
+                .errors
+                .entry((endpoint, format!("{:?}", error.state)))
+                .or_default() += 1;
+
+            return;
+        }
+
+        let Some(body) = &response.body else {
+            return;
+        };
+
+        match (&request.method, request.endpoint.as_str()) {
+            (&Method::POST, "/v1/orders") => self.record_order("place", body),
+            (&Method::DELETE, "/v1/order") => self.record_order("cancel", body),
+            // the new order is placed in the market and on the side of the canceled one
+            (&Method::POST, "/v1/orders/cancel_and_new") => {
+                self.record_order("cancel", body);
+                self.record_order("place", body);
+            }
+            // only the canceled orders are listed, without their side
+            (&Method::DELETE, "/v1/orders/uuids" | "/v1/orders/open") => {
+                for order in body["success"]["orders"].as_array().into_iter().flatten() {
+                    self.record_order("cancel", order);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn record_order(&mut self, action: &str, order: &Value) {
+        *self
+            .orders
+            .entry((
+                action.to_owned(),
+                field(order, "market"),
+                field(order, "side"),
+            ))
+            .or_default() += 1;
+    }
+
+    fn record_fills(&mut self, trades: &[ObjectTrades]) {
+        for trade in trades {
+            *self
+                .fills
+                .entry((trade.market.clone(), trade.side.to_string()))
+                .or_default() += 1;
+        }
+    }
+
+    /// Metrics in the Prometheus text exposition format
+    fn render(&self) -> String {
+        let mut text = String::new();
+
+        header(
+            &mut text,
+            "upbit_requests_total",
+            "counter",
+            "Requests sent to the Upbit APIs",
+        );
+        for ((endpoint, method, status), value) in &self.requests {
+            sample(
+                &mut text,
+                "upbit_requests_total",
+                &[
+                    ("endpoint", endpoint),
+                    ("method", method),
+                    ("status", status),
+                ],
+                value,
+            );
+        }
+
+        header(
+            &mut text,
+            "upbit_request_duration_seconds",
+            "histogram",
+            "Latency of the requests to the Upbit APIs",
+        );
+        for ((endpoint, method), histogram) in &self.durations {
+            for (upper_bound, value) in DURATION_BUCKETS.iter().zip(&histogram.buckets) {
+                sample(
+                    &mut text,
+                    "upbit_request_duration_seconds_bucket",
+                    &[
+                        ("endpoint", endpoint),
+                        ("method", method),
+                        ("le", &upper_bound.to_string()),
+                    ],
+                    value,
+                );
+            }
+            let labels = [("endpoint", endpoint.as_str()), ("method", method.as_str())];
+            sample(
+                &mut text,
+                "upbit_request_duration_seconds_bucket",
+                &[labels[0], labels[1], ("le", "+Inf")],
+                histogram.count,
+            );
+            sample(
+                &mut text,
+                "upbit_request_duration_seconds_sum",
+                &labels,
+                histogram.sum,
+            );
+            sample(
+                &mut text,
+                "upbit_request_duration_seconds_count",
+                &labels,
+                histogram.count,
+            );
+        }
+
+        header(
+            &mut text,
+            "upbit_errors_total",
+            "counter",
+            "Failed requests and error responses of the Upbit APIs",
+        );
+        for ((endpoint, state), value) in &self.errors {
+            sample(
+                &mut text,
+                "upbit_errors_total",
+                &[("endpoint", endpoint), ("state", state)],
+                value,
+            );
+        }
+
+        header(
+            &mut text,
+            "upbit_rate_limit_remaining",
+            "gauge",
+            "Requests left in the current second of the rate limit group",
+        );
+        for (group, value) in &self.rate_limit_remaining {
+            sample(
+                &mut text,
+                "upbit_rate_limit_remaining",
+                &[("group", group)],
+                value,
+            );
+        }
+
+        header(
+            &mut text,
+            "upbit_orders_total",
+            "counter",
+            "Orders placed and canceled",
+        );
+        for ((action, market, side), value) in &self.orders {
+            sample(
+                &mut text,
+                "upbit_orders_total",
+                &[("action", action), ("market", market), ("side", side)],
+                value,
+            );
+        }
+
+        header(
+            &mut text,
+            "upbit_order_fills_total",
+            "counter",
+            "Trades of the orders tracked by the order tracker",
+        );
+        for ((market, side), value) in &self.fills {
+            sample(
+                &mut text,
+                "upbit_order_fills_total",
+                &[("market", market), ("side", side)],
+                value,
+            );
+        }
+
+        text
+    }
+}
+
+/// Middleware recording every request into the registry
+struct Recorder;
+
+impl Middleware for Recorder {
+    fn after_receive(&self, request: &RequestContext, response: &ResponseContext) {
+        REGISTRY
+            .lock()
+            .unwrap()
+            .record_response(request, response, None);
+    }
+
+    fn on_error(
+        &self,
+        request: &RequestContext,
+        response: &ResponseContext,
+        error: &ResponseError,
+    ) {
+        REGISTRY
+            .lock()
+            .unwrap()
+            .record_response(request, response, Some(error));
+    }
+}
+
+/// Middleware called before the added ones for every request
+pub(crate) fn recorder() -> Arc<dyn Middleware> {
+    static RECORDER: OnceLock<Arc<dyn Middleware>> = OnceLock::new();
+
+    RECORDER.get_or_init(|| Arc::new(Recorder)).clone()
+}
+
+/// Record the new trades observed by [crate::api_exchange::OrderTracker]
+pub(crate) fn record_fills(trades: &[ObjectTrades]) {
+    REGISTRY.lock().unwrap().record_fills(trades);
+}
+
+/// Every metric recorded so far, in the Prometheus text exposition format
+pub fn render() -> String {
+    REGISTRY.lock().unwrap().render()
+}
+
+/// Serve [render] to every HTTP request accepted on `addr`, such as `0.0.0.0:9100`
+pub async fn serve(addr: impl ToSocketAddrs) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+
+        tokio::spawn(async move {
+            let mut buf = [0; 1024];
+            let _ = stream.read(&mut buf).await;
+
+            let body = render();
+            let res = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(res.as_bytes()).await;
+        });
+    }
+}
+
+/// Group and requests left in the current second of a `Remaining-Req` header, such as `group=default; min=1800; sec=29`
+fn parse_remaining_req(value: &str) -> Option<(String, u64)> {
+    let mut group = None;
+    let mut remaining = None;
+
+    for pair in value.split(';') {
+        match pair.trim().split_once('=') {
+            Some(("group", value)) => group = Some(value.to_owned()),
+            Some(("sec", value)) => remaining = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    Some((group?, remaining?))
+}
+
+fn field(body: &Value, key: &str) -> String {
+    body.get(key)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_owned()
+}
+
+fn header(text: &mut String, name: &str, r#type: &str, help: &str) {
+    let _ = writeln!(text, "# HELP {name} {help}");
+    let _ = writeln!(text, "# TYPE {name} {type}");
+}
+
+fn sample(text: &mut String, name: &str, labels: &[(&str, &str)], value: impl std::fmt::Display) {
+    let labels = labels
+        .iter()
+        .map(|(key, value)| {
+            let value = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+
+            format!("{key}=\"{value}\"")
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let _ = writeln!(text, "{name}{{{labels}}} {value}");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use crate::response::{response_error_custom_timeout, ResponseErrorState};
+
+    use super::*;
+
+    fn request(method: Method, endpoint: &str) -> RequestContext {
+        RequestContext {
+            id: 1,
+            endpoint: endpoint.to_owned(),
+            method,
+            url: format!("https://api.upbit.com{endpoint}"),
+            body: None,
+            headers: Vec::new(),
+        }
+    }
+
+    fn response(body: Value) -> ResponseContext {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            REMAINING_REQ,
+            HeaderValue::from_static("group=order; min=59; sec=7"),
+        );
+
+        ResponseContext {
+            status: Some(StatusCode::CREATED),
+            headers,
+            duration: Duration::from_millis(30),
+            body: Some(body),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let mut registry = Registry::new();
+
+        registry.record_response(
+            &request(Method::POST, "/v1/orders"),
+            &response(json!({"market": "KRW-BTC", "side": "bid"})),
+            None,
+        );
+        registry.record_response(
+            &request(Method::GET, "/v1/accounts"),
+            &ResponseContext {
+                status: None,
+                headers: HeaderMap::new(),
+                duration: Duration::from_secs(3),
+                body: None,
+            },
+            Some(&response_error_custom_timeout("timed out")),
+        );
+
+        let text = registry.render();
+
+        assert!(text.contains(
+            "upbit_requests_total{endpoint=\"/v1/orders\",method=\"POST\",status=\"201\"} 1"
+        ));
+        assert!(text.contains(
+            "upbit_request_duration_seconds_bucket{endpoint=\"/v1/orders\",method=\"POST\",le=\"0.05\"} 1"
+        ));
+        assert!(text.contains(
+            "upbit_request_duration_seconds_bucket{endpoint=\"/v1/accounts\",method=\"GET\",le=\"2.5\"} 0"
+        ));
+        assert!(text.contains(&format!(
+            "upbit_errors_total{{endpoint=\"/v1/accounts\",state=\"{:?}\"}} 1",
+            ResponseErrorState::CustomErrorTimeout
+        )));
+        assert!(text.contains("upbit_rate_limit_remaining{group=\"order\"} 7"));
+        assert!(
+            text.contains("upbit_orders_total{action=\"place\",market=\"KRW-BTC\",side=\"bid\"} 1")
+        );
+    }
+
+    #[test]
+    fn test_cancel_and_new_counts_cancel_and_place() {
+        let mut registry = Registry::new();
+
+        registry.record_response(
+            &request(Method::POST, "/v1/orders/cancel_and_new"),
+            &response(json!({
+                "uuid": "cdd92199-2897-4e14-9448-f923320408ad",
+                "market": "KRW-BTC",
+                "side": "bid",
+                "new_order_uuid": "d60dfc8a-db0a-4087-9974-fed6433eb8f1"
+            })),
+            None,
+        );
+
+        let text = registry.render();
+
+        assert!(text
+            .contains("upbit_orders_total{action=\"cancel\",market=\"KRW-BTC\",side=\"bid\"} 1"));
+        assert!(
+            text.contains("upbit_orders_total{action=\"place\",market=\"KRW-BTC\",side=\"bid\"} 1")
+        );
+    }
+
+    #[test]
+    fn test_cancel_by_uuids_counts_canceled_orders() {
+        let mut registry = Registry::new();
+
+        registry.record_response(
+            &request(Method::DELETE, "/v1/orders/uuids"),
+            &response(json!({
+                "success": {
+                    "count": 2,
+                    "orders": [
+                        {"uuid": "cdd92199-2897-4e14-9448-f923320408ad", "market": "KRW-BTC"},
+                        {"uuid": "d60dfc8a-db0a-4087-9974-fed6433eb8f1", "market": "KRW-BTC"}
+                    ]
+                },
+                "failed": {
+                    "count": 1,
+                    "orders": [
+                        {"uuid": "9ca023a5-851b-4fec-9f0a-48cd83c2eaae", "market": "KRW-ETH"}
+                    ]
+                }
+            })),
+            None,
+        );
+
+        let text = registry.render();
+
+        assert!(
+            text.contains("upbit_orders_total{action=\"cancel\",market=\"KRW-BTC\",side=\"\"} 2")
+        );
+        assert!(!text.contains("market=\"KRW-ETH\""));
+    }
+
+    #[test]
+    fn test_cancel_open_orders_counts_canceled_orders() {
+        let mut registry = Registry::new();
+
+        registry.record_response(
+            &request(Method::DELETE, "/v1/orders/open"),
+            &response(json!({
+                "success": {
+                    "count": 1,
+                    "orders": [
+                        {"uuid": "cdd92199-2897-4e14-9448-f923320408ad", "market": "KRW-ETH"}
+                    ]
+                },
+                "failed": {"count": 0, "orders": []}
+            })),
+            None,
+        );
+
+        let text = registry.render();
+
+        assert!(
+            text.contains("upbit_orders_total{action=\"cancel\",market=\"KRW-ETH\",side=\"\"} 1")
+        );
+    }
+
+    #[test]
+    fn test_parse_remaining_req() {
+        assert_eq!(
+            parse_remaining_req("group=default; min=1800; sec=29"),
+            Some(("default".to_owned(), 29))
+        );
+        assert_eq!(parse_remaining_req("min=1800"), None);
+    }
+}
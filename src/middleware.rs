@@ -0,0 +1,381 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::{Method, StatusCode, Url};
+use serde_json::Value;
+
+use crate::response::{ResponseError, ResponseErrorSource};
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+
+/// Parameters whose values are replaced by [REDACTED] before being passed to middlewares
+const REDACTED_PARAMS: &[&str] = &["address", "secondary_address"];
+/// Value replacing the redacted parameters
+const REDACTED: &str = "<redacted>";
+
+/// Middlewares added by [add_middleware]
+static MIDDLEWARES: RwLock<Vec<Arc<dyn Middleware>>> = RwLock::new(Vec::new());
+/// Id of the next request
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Request about to be sent, without its `Authorization` header
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestContext {
+    /// Id unique to the request in the process
+    pub id: u64,
+    /// Path of the API, such as `/v1/orders`
+    pub endpoint: String,
+    pub method: Method,
+    /// URL with the values of sensitive parameters redacted
+    pub url: String,
+    /// JSON body with the values of sensitive parameters redacted
+    pub body: Option<Value>,
+    /// Headers added to the request by [Middleware::before_send], such as correlation ids
+    pub headers: Vec<(HeaderName, String)>,
+}
+
+impl RequestContext {
+    fn new(request: &HttpRequest) -> Self {
+        let url = Url::parse(&request.url).ok();
+
+        Self {
+            id: NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed),
+            endpoint: url
+                .as_ref()
+                .map_or_else(String::new, |url| url.path().to_owned()),
+            method: request.method.clone(),
+            url: url.map_or_else(|| request.url.clone(), redact_url),
+            body: request
+                .body
+                .as_deref()
+                .and_then(|body| serde_json::from_str(body).ok())
+                .map(redact_body),
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Response received for a [RequestContext]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseContext {
+    /// Status of the response, or `None` if no response is received
+    pub status: Option<StatusCode>,
+    /// Headers of the response, such as `Remaining-Req`
+    pub headers: HeaderMap,
+    /// Time taken from sending the request to reading the whole response
+    pub duration: Duration,
+    /// Body of the response parsed as JSON, or `None` if it is not
+    pub body: Option<Value>,
+}
+
+/// Hooks called for the request of every API
+///
+/// Every method does nothing by default.
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent, with headers to add to it
+    fn before_send(&self, _request: &mut RequestContext) {}
+
+    /// Called when a response other than an error is received
+    fn after_receive(&self, _request: &RequestContext, _response: &ResponseContext) {}
+
+    /// Called when the request fails or an error response is received
+    fn on_error(
+        &self,
+        _request: &RequestContext,
+        _response: &ResponseContext,
+        _error: &ResponseError,
+    ) {
+    }
+}
+
+/// Add a middleware called for the request of every API, after the ones added before
+pub fn add_middleware(middleware: impl Middleware + 'static) {
+    MIDDLEWARES.write().unwrap().push(Arc::new(middleware));
+}
+
+/// Remove every middleware added by [add_middleware]
+pub fn clear_middlewares() {
+    MIDDLEWARES.write().unwrap().clear();
+}
+
+/// Send `request` with `transport`, calling the added middlewares
+pub(crate) async fn send(
+    request: HttpRequest,
+    transport: Arc<dyn HttpTransport>,
+) -> Result<HttpResponse, ResponseError> {
+    #[cfg(not(feature = "metrics"))]
+    let middlewares = MIDDLEWARES.read().unwrap().clone();
+
+    #[cfg(feature = "metrics")]
+    let middlewares = {
+        let mut middlewares = MIDDLEWARES.read().unwrap().clone();
+        middlewares.insert(0, crate::metrics::recorder());
+        middlewares
+    };
+
+    send_with(&middlewares, request, transport).await
+}
+
+async fn send_with(
+    middlewares: &[Arc<dyn Middleware>],
+    mut request: HttpRequest,
+    transport: Arc<dyn HttpTransport>,
+) -> Result<HttpResponse, ResponseError> {
+    if middlewares.is_empty() {
+        return transport.send(request).await;
+    }
+
+    let mut context = RequestContext::new(&request);
+
+    for middleware in middlewares {
+        middleware.before_send(&mut context);
+    }
+
+    request.headers.extend(context.headers.iter().cloned());
+
+    let started_at = Instant::now();
+    let result = transport.send(request).await;
+    let duration = started_at.elapsed();
+
+    let body = result
+        .as_ref()
+        .ok()
+        .and_then(|res| serde_json::from_str::<Value>(&res.body).ok());
+    let error_body = body
+        .as_ref()
+        .and_then(|body| serde_json::from_value::<ResponseErrorSource>(body.clone()).ok())
+        .map(crate::response::response_error)
+        .or_else(|| {
+            result
+                .as_ref()
+                .ok()
+                .filter(|res| !res.status.is_success())
+                .map(|res| crate::response::response_error_http_status(res.status, &res.body))
+        });
+
+    let response = ResponseContext {
+        status: result.as_ref().ok().map(|res| res.status),
+        headers: result
+            .as_ref()
+            .map(|res| res.headers.clone())
+            .unwrap_or_default(),
+        duration,
+        body,
+    };
+    let error = match &result {
+        Ok(_) => error_body.as_ref(),
+        Err(e) => Some(e),
+    };
+
+    for middleware in middlewares {
+        match error {
+            Some(error) => middleware.on_error(&context, &response, error),
+            None => middleware.after_receive(&context, &response),
+        }
+    }
+
+    result
+}
+
+fn redact_url(mut url: Url) -> String {
+    if url
+        .query_pairs()
+        .any(|(key, _)| REDACTED_PARAMS.contains(&key.as_ref()))
+    {
+        let pairs = url
+            .query_pairs()
+            .map(|(key, value)| {
+                let value = if REDACTED_PARAMS.contains(&key.as_ref()) {
+                    REDACTED.to_owned()
+                } else {
+                    value.into_owned()
+                };
+
+                (key.into_owned(), value)
+            })
+            .collect::<Vec<(String, String)>>();
+
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+
+    url.to_string()
+}
+
+fn redact_body(mut body: Value) -> Value {
+    if let Value::Object(params) = &mut body {
+        for key in REDACTED_PARAMS {
+            if let Some(value) = params.get_mut(*key) {
+                *value = Value::from(REDACTED);
+            }
+        }
+    }
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use reqwest::header::AUTHORIZATION;
+    use serde_json::json;
+
+    use crate::response::ResponseErrorState;
+    use crate::transport::TransportFuture;
+
+    use super::*;
+
+    /// Transport answering every request with `body`, keeping the requests sent
+    struct FixedTransport {
+        status: StatusCode,
+        body: &'static str,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl HttpTransport for FixedTransport {
+        fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+            self.requests.lock().unwrap().push(request);
+
+            Box::pin(async move { Ok(HttpResponse::new(self.status, self.body)) })
+        }
+    }
+
+    /// Middleware adding a correlation id and keeping the contexts it receives
+    #[derive(Default)]
+    struct RecordingMiddleware {
+        received: Mutex<Vec<(RequestContext, ResponseContext, Option<ResponseErrorState>)>>,
+    }
+
+    impl Middleware for RecordingMiddleware {
+        fn before_send(&self, request: &mut RequestContext) {
+            request.headers.push((
+                HeaderName::from_static("x-correlation-id"),
+                format!("correlation-{}", request.id),
+            ));
+        }
+
+        fn after_receive(&self, request: &RequestContext, response: &ResponseContext) {
+            self.received
+                .lock()
+                .unwrap()
+                .push((request.clone(), response.clone(), None));
+        }
+
+        fn on_error(
+            &self,
+            request: &RequestContext,
+            response: &ResponseContext,
+            error: &ResponseError,
+        ) {
+            self.received.lock().unwrap().push((
+                request.clone(),
+                response.clone(),
+                Some(error.state),
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_hooks() {
+        let middleware = Arc::new(RecordingMiddleware::default());
+        let middlewares: Vec<Arc<dyn Middleware>> = vec![middleware.clone()];
+        let transport = Arc::new(FixedTransport {
+            status: StatusCode::BAD_REQUEST,
+            body: r#"{"error":{"name":"insufficient_funds_bid","message":"주문가능한 금액(KRW)이 부족합니다."}}"#,
+            requests: Mutex::new(Vec::new()),
+        });
+
+        let request = HttpRequest::post("https://api.upbit.com/v1/withdraws/coin")
+            .header(AUTHORIZATION, "Bearer token")
+            .json(&json!({
+                "currency": "XRP",
+                "amount": "10",
+                "address": "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1",
+            }));
+
+        let res = send_with(&middlewares, request, transport.clone())
+            .await
+            .unwrap();
+        assert_eq!(res.status, StatusCode::BAD_REQUEST);
+
+        let sent = &transport.requests.lock().unwrap()[0];
+        let received = middleware.received.lock().unwrap();
+        let (request, response, error) = &received[0];
+
+        assert!(sent.headers.iter().any(|(name, value)| {
+            name == "x-correlation-id" && *value == format!("correlation-{}", request.id)
+        }));
+        assert_eq!(request.endpoint, "/v1/withdraws/coin");
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.body.as_ref().unwrap()["address"], REDACTED);
+        assert_eq!(request.body.as_ref().unwrap()["currency"], "XRP");
+        assert!(request
+            .headers
+            .iter()
+            .all(|(name, _)| name != AUTHORIZATION));
+        assert_eq!(response.status, Some(StatusCode::BAD_REQUEST));
+        assert_eq!(*error, Some(ResponseErrorState::InsufficientFundsBid));
+    }
+
+    #[tokio::test]
+    async fn test_middleware_on_error_without_error_body() {
+        let middleware = Arc::new(RecordingMiddleware::default());
+        let middlewares: Vec<Arc<dyn Middleware>> = vec![middleware.clone()];
+
+        for (status, body) in [
+            (StatusCode::TOO_MANY_REQUESTS, ""),
+            (
+                StatusCode::BAD_GATEWAY,
+                "<html><body>502 Bad Gateway</body></html>",
+            ),
+        ] {
+            let transport = Arc::new(FixedTransport {
+                status,
+                body,
+                requests: Mutex::new(Vec::new()),
+            });
+
+            send_with(
+                &middlewares,
+                HttpRequest::get("https://api.upbit.com/v1/market/all"),
+                transport,
+            )
+            .await
+            .unwrap();
+        }
+
+        let received = middleware.received.lock().unwrap();
+        let errors = received
+            .iter()
+            .map(|(_, response, error)| (response.status, *error))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    Some(StatusCode::TOO_MANY_REQUESTS),
+                    Some(ResponseErrorState::UnexpectedError)
+                ),
+                (
+                    Some(StatusCode::BAD_GATEWAY),
+                    Some(ResponseErrorState::ServerError)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_redact_url() {
+        let url = Url::parse(
+            "https://api.upbit.com/v1/withdraws/coin_address?currency=XRP&address=rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1",
+        )
+        .unwrap();
+
+        assert_eq!(
+            redact_url(url),
+            "https://api.upbit.com/v1/withdraws/coin_address?currency=XRP&address=%3Credacted%3E"
+        );
+    }
+}
@@ -0,0 +1,920 @@
+use std::collections::BTreeMap;
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::api_exchange::price_unit;
+use crate::date::{kst_date_time, now_ms};
+
+use super::{decimal, MockError};
+
+/// Fee rate of every order, maker and taker alike
+const FEE: f64 = 0.0005;
+/// Minimum total of an order in the quote currency
+const MIN_TOTAL: f64 = 5000.0;
+/// Levels on each side of the order book synthesized around the price of a market
+const BOOK_DEPTH: usize = 15;
+/// Volume of each level of the synthesized order book
+const LEVEL_SIZE: f64 = 1.0;
+/// Volumes and amounts closer to zero are considered zero
+const EPSILON: f64 = 1e-9;
+
+/// Balance of a currency
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Balance {
+    /// Amount available for orders and withdrawals
+    pub balance: f64,
+    /// Amount locked by open orders
+    pub locked: f64,
+    pub avg_buy_price: f64,
+}
+
+/// Price level of an order book
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Level {
+    pub(super) price: f64,
+    pub(super) size: f64,
+}
+
+/// Trade of a market, made by an order of the mock account or not
+#[derive(Debug, Clone)]
+pub(super) struct Tick {
+    pub(super) timestamp: i64,
+    pub(super) price: f64,
+    pub(super) volume: f64,
+    /// `ASK` or `BID`, the side of the taker
+    pub(super) ask_bid: &'static str,
+    pub(super) sequential_id: i64,
+}
+
+/// Market with an order book synthesized around its price
+#[derive(Debug, Clone)]
+pub(super) struct Market {
+    pub(super) price: f64,
+    pub(super) opening_price: f64,
+    pub(super) high_price: f64,
+    pub(super) low_price: f64,
+    pub(super) prev_closing_price: f64,
+    pub(super) acc_trade_price: f64,
+    pub(super) acc_trade_volume: f64,
+    /// Asks by ascending price
+    pub(super) asks: Vec<Level>,
+    /// Bids by descending price
+    pub(super) bids: Vec<Level>,
+    /// Trades by ascending time
+    pub(super) ticks: Vec<Tick>,
+}
+
+impl Market {
+    fn new(price: f64) -> Self {
+        let mut market = Self {
+            price,
+            opening_price: price,
+            high_price: price,
+            low_price: price,
+            prev_closing_price: price,
+            acc_trade_price: 0.0,
+            acc_trade_volume: 0.0,
+            asks: Vec::new(),
+            bids: Vec::new(),
+            ticks: Vec::new(),
+        };
+        market.synthesize_book();
+
+        market
+    }
+
+    /// Fill [BOOK_DEPTH] levels of [LEVEL_SIZE] on each side, one tick apart from the price
+    fn synthesize_book(&mut self) {
+        let tick = price_unit(self.price);
+        let level = |i: usize| tick * (i + 1) as f64;
+
+        self.asks = (0..BOOK_DEPTH)
+            .map(|i| Level {
+                price: self.price + level(i),
+                size: LEVEL_SIZE,
+            })
+            .collect();
+        self.bids = (0..BOOK_DEPTH)
+            .map(|i| Level {
+                price: self.price - level(i),
+                size: LEVEL_SIZE,
+            })
+            .filter(|level| level.price > 0.0)
+            .collect();
+    }
+
+    fn record_trade(&mut self, price: f64, volume: f64, ask_bid: &'static str) {
+        let timestamp = now_ms();
+
+        self.price = price;
+        self.high_price = self.high_price.max(price);
+        self.low_price = self.low_price.min(price);
+        self.acc_trade_price += price * volume;
+        self.acc_trade_volume += volume;
+        self.ticks.push(Tick {
+            timestamp,
+            price,
+            volume,
+            ask_bid,
+            sequential_id: timestamp * 1000 + self.ticks.len() as i64 % 1000,
+        });
+    }
+}
+
+/// Trade of an order of the mock account
+#[derive(Debug, Clone)]
+struct Fill {
+    uuid: String,
+    price: f64,
+    volume: f64,
+    created_at: String,
+}
+
+/// Order of the mock account
+#[derive(Debug, Clone)]
+struct Order {
+    uuid: String,
+    identifier: Option<String>,
+    market: String,
+    side: &'static str,
+    ord_type: String,
+    price: Option<f64>,
+    volume: Option<f64>,
+    state: &'static str,
+    created_at: String,
+    created_ms: i64,
+    executed_volume: f64,
+    executed_funds: f64,
+    reserved_fee: f64,
+    paid_fee: f64,
+    /// Amount of the quote currency for bids or of the base currency for asks still locked
+    locked: f64,
+    fills: Vec<Fill>,
+}
+
+impl Order {
+    fn remaining_volume(&self) -> f64 {
+        self.volume
+            .map_or(0.0, |volume| (volume - self.executed_volume).max(0.0))
+    }
+
+    fn to_json(&self) -> Value {
+        let mut order = json!({
+            "uuid": self.uuid,
+            "side": self.side,
+            "ord_type": self.ord_type,
+            "price": self.price.map(decimal),
+            "state": self.state,
+            "market": self.market,
+            "created_at": self.created_at,
+            "volume": decimal(self.volume.unwrap_or(self.executed_volume)),
+            "remaining_volume": decimal(self.remaining_volume()),
+            "reserved_fee": decimal(self.reserved_fee),
+            "remaining_fee": decimal(if self.state == "wait" {
+                (self.reserved_fee - self.paid_fee).max(0.0)
+            } else {
+                0.0
+            }),
+            "paid_fee": decimal(self.paid_fee),
+            "locked": decimal(self.locked),
+            "executed_volume": decimal(self.executed_volume),
+            "executed_funds": decimal(self.executed_funds),
+            "trades_count": self.fills.len(),
+        });
+
+        if let Some(identifier) = &self.identifier {
+            order["identifier"] = json!(identifier);
+        }
+
+        order
+    }
+
+    fn to_json_with_trades(&self) -> Value {
+        let mut order = self.to_json();
+        order["trades"] = self
+            .fills
+            .iter()
+            .map(|fill| {
+                json!({
+                    "market": self.market,
+                    "uuid": fill.uuid,
+                    "price": decimal(fill.price),
+                    "volume": decimal(fill.volume),
+                    "funds": decimal(fill.price * fill.volume),
+                    "side": self.side,
+                    "created_at": fill.created_at,
+                })
+            })
+            .collect();
+
+        order
+    }
+}
+
+/// Parameters of `POST /v1/orders`
+#[derive(Debug, Clone, Default)]
+pub(super) struct OrderRequest {
+    pub(super) market: String,
+    pub(super) side: String,
+    pub(super) ord_type: String,
+    pub(super) price: Option<f64>,
+    pub(super) volume: Option<f64>,
+    pub(super) identifier: Option<String>,
+}
+
+/// Deposit or withdrawal of the mock account
+#[derive(Debug, Clone)]
+struct Transfer {
+    r#type: &'static str,
+    uuid: String,
+    currency: String,
+    net_type: Option<String>,
+    txid: String,
+    state: &'static str,
+    created_at: String,
+    created_ms: i64,
+    amount: f64,
+    fee: f64,
+    transaction_type: String,
+}
+
+impl Transfer {
+    fn to_json(&self) -> Value {
+        json!({
+            "type": self.r#type,
+            "uuid": self.uuid,
+            "currency": self.currency,
+            "net_type": self.net_type,
+            "txid": self.txid,
+            "state": self.state,
+            "created_at": self.created_at,
+            "done_at": self.created_at,
+            "amount": decimal(self.amount),
+            "fee": decimal(self.fee),
+            "transaction_type": self.transaction_type,
+        })
+    }
+}
+
+/// Address of a currency on a network
+#[derive(Debug, Clone)]
+pub(super) struct Address {
+    pub(super) currency: String,
+    pub(super) net_type: String,
+    pub(super) address: String,
+    pub(super) secondary_address: Option<String>,
+}
+
+/// Accounts, markets, orders and transfers of the mock exchange
+#[derive(Debug, Clone, Default)]
+pub(super) struct Exchange {
+    balances: BTreeMap<String, Balance>,
+    pub(super) markets: BTreeMap<String, Market>,
+    orders: Vec<Order>,
+    transfers: Vec<Transfer>,
+    deposit_addresses: Vec<Address>,
+    withdraw_addresses: Vec<Address>,
+    withdraw_fees: BTreeMap<String, f64>,
+}
+
+impl Exchange {
+    pub(super) fn balance(&self, currency: &str) -> Balance {
+        self.balances.get(currency).copied().unwrap_or_default()
+    }
+
+    pub(super) fn set_balance(&mut self, currency: &str, balance: f64) {
+        self.balances
+            .entry(currency.to_owned())
+            .or_default()
+            .balance = balance;
+    }
+
+    pub(super) fn add_market(&mut self, market: &str, price: f64) {
+        self.markets.insert(market.to_owned(), Market::new(price));
+    }
+
+    pub(super) fn add_deposit_address(&mut self, address: Address) {
+        self.deposit_addresses.push(address);
+    }
+
+    pub(super) fn add_withdraw_address(&mut self, address: Address) {
+        self.withdraw_addresses.push(address);
+    }
+
+    pub(super) fn set_withdraw_fee(&mut self, currency: &str, fee: f64) {
+        self.withdraw_fees.insert(currency.to_owned(), fee);
+    }
+
+    /// Move the price of `market`, filling the open orders crossed by it at their price
+    pub(super) fn set_price(&mut self, market: &str, price: f64) {
+        let Some(state) = self.markets.get_mut(market) else {
+            return;
+        };
+        state.price = price;
+        state.synthesize_book();
+
+        let crossed = self
+            .orders
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| order.market == market && order.state == "wait")
+            .filter(|(_, order)| match (order.side, order.price) {
+                ("bid", Some(limit)) => limit >= price,
+                ("ask", Some(limit)) => limit <= price,
+                _ => false,
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<usize>>();
+
+        for index in crossed {
+            let order = &self.orders[index];
+            let (limit, volume) = (order.price.unwrap_or(price), order.remaining_volume());
+
+            self.fill(index, limit, volume);
+            self.finish(index, "done");
+        }
+
+        if let Some(state) = self.markets.get_mut(market) {
+            state.price = price;
+        }
+    }
+
+    pub(super) fn deposit(
+        &mut self,
+        currency: &str,
+        net_type: Option<&str>,
+        amount: f64,
+    ) -> String {
+        let transfer = self.transfer("deposit", currency, net_type, amount, 0.0, "default");
+        self.balances
+            .entry(currency.to_owned())
+            .or_default()
+            .balance += amount;
+
+        transfer
+    }
+
+    pub(super) fn accounts(&self) -> Value {
+        self.balances
+            .iter()
+            .filter(|(currency, balance)| {
+                currency.as_str() == "KRW" || balance.balance > EPSILON || balance.locked > EPSILON
+            })
+            .map(|(currency, _)| self.account(currency))
+            .collect()
+    }
+
+    fn account(&self, currency: &str) -> Value {
+        let balance = self.balance(currency);
+
+        json!({
+            "currency": currency,
+            "balance": decimal(balance.balance),
+            "locked": decimal(balance.locked),
+            "avg_buy_price": decimal(balance.avg_buy_price),
+            "avg_buy_price_modified": false,
+            "unit_currency": "KRW",
+        })
+    }
+
+    pub(super) fn order_chance(&self, market: &str) -> Result<Value, MockError> {
+        let (quote, base) = self.currencies(market)?;
+
+        Ok(json!({
+            "bid_fee": decimal(FEE),
+            "ask_fee": decimal(FEE),
+            "maker_bid_fee": decimal(FEE),
+            "maker_ask_fee": decimal(FEE),
+            "market": {
+                "id": market,
+                "name": format!("{base}/{quote}"),
+                "order_types": [],
+                "order_sides": ["ask", "bid"],
+                "ask_types": ["limit", "market"],
+                "bid_types": ["limit", "price"],
+                "bid": { "currency": quote, "min_total": decimal(MIN_TOTAL) },
+                "ask": { "currency": base, "min_total": decimal(MIN_TOTAL) },
+                "max_total": "1000000000",
+                "state": "active",
+            },
+            "bid_account": self.account(quote),
+            "ask_account": self.account(base),
+        }))
+    }
+
+    /// Place an order, matching it against the order book and leaving the rest of a limit order open
+    pub(super) fn place_order(&mut self, request: OrderRequest) -> Result<Value, MockError> {
+        let (quote, base) = self.currencies(&request.market)?;
+        let (quote, base) = (quote.to_owned(), base.to_owned());
+        let best_bid = self.markets[&request.market]
+            .bids
+            .first()
+            .map_or(0.0, |level| level.price);
+
+        let side = match request.side.as_str() {
+            "bid" => "bid",
+            "ask" => "ask",
+            _ => return Err(MockError::validation("side")),
+        };
+        let (price, volume) = match (request.ord_type.as_str(), side) {
+            ("limit", _) => {
+                let price = request.price.ok_or_else(|| MockError::validation("price"))?;
+                let volume = request.volume.ok_or_else(|| MockError::validation("volume"))?;
+
+                if ((price / price_unit(price)).round() * price_unit(price) - price).abs()
+                    > EPSILON * price
+                {
+                    return Err(MockError::custom(
+                        StatusCode::BAD_REQUEST,
+                        &format!("invalid_price_{side}"),
+                        "주문가격 단위를 잘못 입력하셨습니다. 확인 후 시도해주세요.",
+                    ));
+                }
+
+                (Some(price), Some(volume))
+            }
+            ("price", "bid") => (
+                Some(request.price.ok_or_else(|| MockError::validation("price"))?),
+                None,
+            ),
+            ("market", "ask") => (
+                None,
+                Some(request.volume.ok_or_else(|| MockError::validation("volume"))?),
+            ),
+            _ => {
+                return Err(MockError::custom(
+                    StatusCode::BAD_REQUEST,
+                    "not_supported_ord_type",
+                    "현재 해당 마켓에서 지원하지 않는 주문입니다. 주문 조건을 다시 확인해주시기 바랍니다.",
+                ))
+            }
+        };
+
+        let total = match (price, volume) {
+            (Some(price), Some(volume)) => price * volume,
+            (Some(price), None) => price,
+            (None, volume) => best_bid * volume.unwrap_or_default(),
+        };
+
+        if total < MIN_TOTAL {
+            return Err(MockError::custom(
+                StatusCode::BAD_REQUEST,
+                &format!("under_min_total_{side}"),
+                "최소주문금액 이상으로 주문해주세요",
+            ));
+        }
+
+        if let Some(identifier) = &request.identifier {
+            if self
+                .orders
+                .iter()
+                .any(|order| order.identifier.as_ref() == Some(identifier))
+            {
+                return Err(MockError::custom(
+                    StatusCode::BAD_REQUEST,
+                    "duplicated_identifier",
+                    "이미 등록된 identifier입니다.",
+                ));
+            }
+        }
+
+        let locked = match side {
+            "bid" => total * (1.0 + FEE),
+            _ => volume.unwrap_or_default(),
+        };
+        let currency = if side == "bid" { &quote } else { &base };
+        let balance = self.balances.entry(currency.clone()).or_default();
+
+        if balance.balance + EPSILON < locked {
+            return Err(match side {
+                "bid" => MockError::InsufficientFundsBid,
+                _ => MockError::custom(
+                    StatusCode::BAD_REQUEST,
+                    "insufficient_funds_ask",
+                    "매도가능 금액이 부족합니다.",
+                ),
+            });
+        }
+
+        balance.balance -= locked;
+        balance.locked += locked;
+
+        let now = now_ms();
+        self.orders.push(Order {
+            uuid: Uuid::new_v4().to_string(),
+            identifier: request.identifier,
+            market: request.market,
+            side,
+            ord_type: request.ord_type,
+            price,
+            volume,
+            state: "wait",
+            created_at: kst_date_time(now),
+            created_ms: now,
+            executed_volume: 0.0,
+            executed_funds: 0.0,
+            reserved_fee: if side == "bid" { total * FEE } else { 0.0 },
+            paid_fee: 0.0,
+            locked,
+            fills: Vec::new(),
+        });
+
+        let index = self.orders.len() - 1;
+        self.match_order(index);
+
+        Ok(self.orders[index].to_json())
+    }
+
+    /// Take the levels of the order book crossed by the order at `index`
+    fn match_order(&mut self, index: usize) {
+        let order = self.orders[index].clone();
+        let market = order.market.clone();
+
+        loop {
+            let order = &self.orders[index];
+            let book = &self.markets[&market];
+            let level = match order.side {
+                "bid" => book.asks.first(),
+                _ => book.bids.first(),
+            };
+            let Some(level) = level.copied() else {
+                break;
+            };
+
+            let crosses = match (order.side, order.ord_type.as_str(), order.price) {
+                (_, "price" | "market", _) => true,
+                ("bid", _, Some(limit)) => level.price <= limit,
+                (_, _, Some(limit)) => level.price >= limit,
+                _ => false,
+            };
+            let wanted = match order.ord_type.as_str() {
+                "price" => (order.price.unwrap_or_default() - order.executed_funds) / level.price,
+                _ => order.remaining_volume(),
+            };
+
+            if !crosses || wanted <= EPSILON {
+                break;
+            }
+
+            let volume = wanted.min(level.size);
+            let book = self.markets.get_mut(&market).unwrap();
+            let levels = match order.side {
+                "bid" => &mut book.asks,
+                _ => &mut book.bids,
+            };
+
+            levels[0].size -= volume;
+            if levels[0].size <= EPSILON {
+                levels.remove(0);
+            }
+
+            self.fill(index, level.price, volume);
+        }
+
+        let order = &self.orders[index];
+        let executed = match order.ord_type.as_str() {
+            "price" => order.price.unwrap_or_default() - order.executed_funds <= EPSILON,
+            _ => order.remaining_volume() <= EPSILON,
+        };
+
+        match (executed, order.ord_type.as_str()) {
+            (true, _) => self.finish(index, "done"),
+            (false, "price" | "market") => self.finish(index, "cancel"),
+            _ => {}
+        }
+    }
+
+    /// Trade `volume` of the order at `index` at `price`, settling the balances
+    fn fill(&mut self, index: usize, price: f64, volume: f64) {
+        let order = &mut self.orders[index];
+        let funds = price * volume;
+        let fee = funds * FEE;
+        let (quote, base) = order.market.split_once('-').unwrap_or_default();
+        let (quote, base) = (quote.to_owned(), base.to_owned());
+
+        order.executed_volume += volume;
+        order.executed_funds += funds;
+        order.paid_fee += fee;
+        order.fills.push(Fill {
+            uuid: Uuid::new_v4().to_string(),
+            price,
+            volume,
+            created_at: kst_date_time(now_ms()),
+        });
+
+        let side = order.side;
+        let market = order.market.clone();
+
+        if side == "bid" {
+            order.locked -= funds + fee;
+            self.balances.entry(quote).or_default().locked -= funds + fee;
+
+            let bought = self.balances.entry(base).or_default();
+            let held = bought.balance + bought.locked;
+            bought.avg_buy_price = (bought.avg_buy_price * held + funds) / (held + volume);
+            bought.balance += volume;
+        } else {
+            order.locked -= volume;
+            self.balances.entry(base).or_default().locked -= volume;
+            self.balances.entry(quote).or_default().balance += funds - fee;
+        }
+
+        if let Some(market) = self.markets.get_mut(&market) {
+            market.record_trade(price, volume, if side == "bid" { "BID" } else { "ASK" });
+        }
+    }
+
+    /// Close the order at `index` in `state`, unlocking what is left of it
+    fn finish(&mut self, index: usize, state: &'static str) {
+        let order = &mut self.orders[index];
+        let (quote, base) = order.market.split_once('-').unwrap_or_default();
+        let currency = if order.side == "bid" { quote } else { base }.to_owned();
+        let locked = std::mem::take(&mut order.locked);
+
+        order.state = state;
+
+        let balance = self.balances.entry(currency).or_default();
+        balance.locked -= locked;
+        balance.balance += locked;
+    }
+
+    pub(super) fn order(
+        &self,
+        uuid: Option<&str>,
+        identifier: Option<&str>,
+    ) -> Result<Value, MockError> {
+        self.find_order(uuid, identifier)
+            .map(|index| self.orders[index].to_json_with_trades())
+    }
+
+    pub(super) fn cancel_order(
+        &mut self,
+        uuid: Option<&str>,
+        identifier: Option<&str>,
+    ) -> Result<Value, MockError> {
+        let index = self.find_order(uuid, identifier)?;
+
+        if self.orders[index].state != "wait" {
+            return Err(MockError::order_not_found());
+        }
+
+        self.finish(index, "cancel");
+
+        Ok(self.orders[index].to_json())
+    }
+
+    fn find_order(&self, uuid: Option<&str>, identifier: Option<&str>) -> Result<usize, MockError> {
+        self.orders
+            .iter()
+            .position(|order| match (uuid, identifier) {
+                (Some(uuid), _) => order.uuid == uuid,
+                (None, Some(identifier)) => order.identifier.as_deref() == Some(identifier),
+                (None, None) => false,
+            })
+            .ok_or_else(MockError::order_not_found)
+    }
+
+    /// Orders of `market`, or of every market if `None`, in `states` and accepted by `filter`
+    pub(super) fn orders(
+        &self,
+        market: Option<&str>,
+        states: &[&str],
+        filter: impl Fn(&str, Option<&str>) -> bool,
+        ascending: bool,
+    ) -> Vec<Value> {
+        let mut orders = self
+            .orders
+            .iter()
+            .filter(|order| market.is_none_or(|market| order.market == market))
+            .filter(|order| states.contains(&order.state))
+            .filter(|order| filter(&order.uuid, order.identifier.as_deref()))
+            .collect::<Vec<&Order>>();
+
+        orders.sort_by_key(|order| order.created_ms);
+
+        if !ascending {
+            orders.reverse();
+        }
+
+        orders.into_iter().map(Order::to_json).collect()
+    }
+
+    /// Withdraw `amount` of `currency` to a registered address
+    pub(super) fn withdraw_coin(
+        &mut self,
+        currency: &str,
+        net_type: &str,
+        amount: f64,
+        address: &str,
+        transaction_type: &str,
+    ) -> Result<Value, MockError> {
+        if !self.withdraw_addresses.iter().any(|registered| {
+            registered.currency == currency
+                && registered.net_type == net_type
+                && registered.address == address
+        }) {
+            return Err(MockError::custom(
+                StatusCode::BAD_REQUEST,
+                "withdraw_address_not_registered",
+                "등록된 출금 주소가 아닙니다.",
+            ));
+        }
+
+        let fee = self
+            .withdraw_fees
+            .get(currency)
+            .copied()
+            .unwrap_or_default();
+        let balance = self.balances.entry(currency.to_owned()).or_default();
+
+        if balance.balance + EPSILON < amount + fee {
+            return Err(MockError::custom(
+                StatusCode::BAD_REQUEST,
+                "withdraw_insufficient_balance",
+                "출금 금액이 부족합니다.",
+            ));
+        }
+
+        balance.balance -= amount + fee;
+        let uuid = self.transfer(
+            "withdraw",
+            currency,
+            Some(net_type),
+            amount,
+            fee,
+            transaction_type,
+        );
+
+        self.transfer_by("withdraw", Some(&uuid), None, None)
+    }
+
+    fn transfer(
+        &mut self,
+        r#type: &'static str,
+        currency: &str,
+        net_type: Option<&str>,
+        amount: f64,
+        fee: f64,
+        transaction_type: &str,
+    ) -> String {
+        let now = now_ms();
+        let uuid = Uuid::new_v4().to_string();
+
+        self.transfers.push(Transfer {
+            r#type,
+            uuid: uuid.clone(),
+            currency: currency.to_owned(),
+            net_type: net_type.map(str::to_owned),
+            txid: format!("{}-{}", r#type, Uuid::new_v4().simple()),
+            state: if r#type == "deposit" {
+                "ACCEPTED"
+            } else {
+                "DONE"
+            },
+            created_at: kst_date_time(now),
+            created_ms: now,
+            amount,
+            fee,
+            transaction_type: transaction_type.to_owned(),
+        });
+
+        uuid
+    }
+
+    /// Latest deposit or withdrawal with the given uuid, txid or currency
+    pub(super) fn transfer_by(
+        &self,
+        r#type: &str,
+        uuid: Option<&str>,
+        txid: Option<&str>,
+        currency: Option<&str>,
+    ) -> Result<Value, MockError> {
+        self.transfers
+            .iter()
+            .rev()
+            .filter(|transfer| transfer.r#type == r#type)
+            .find(|transfer| {
+                uuid.is_none_or(|uuid| transfer.uuid == uuid)
+                    && txid.is_none_or(|txid| transfer.txid == txid)
+                    && currency.is_none_or(|currency| transfer.currency == currency)
+            })
+            .map(Transfer::to_json)
+            .ok_or_else(|| {
+                MockError::custom(
+                    StatusCode::NOT_FOUND,
+                    &format!("{type}_not_found"),
+                    "내역을 찾지 못했습니다.",
+                )
+            })
+    }
+
+    /// Deposits or withdrawals of `currency` in `state`, or in any state if `None`
+    pub(super) fn transfers(
+        &self,
+        r#type: &str,
+        currency: Option<&str>,
+        state: Option<&str>,
+        ascending: bool,
+    ) -> Vec<Value> {
+        let mut transfers = self
+            .transfers
+            .iter()
+            .filter(|transfer| transfer.r#type == r#type)
+            .filter(|transfer| currency.is_none_or(|currency| transfer.currency == currency))
+            .filter(|transfer| state.is_none_or(|state| transfer.state.eq_ignore_ascii_case(state)))
+            .collect::<Vec<&Transfer>>();
+
+        transfers.sort_by_key(|transfer| transfer.created_ms);
+
+        if !ascending {
+            transfers.reverse();
+        }
+
+        transfers.into_iter().map(Transfer::to_json).collect()
+    }
+
+    pub(super) fn withdraw_chance(&self, currency: &str) -> Value {
+        let fee = self
+            .withdraw_fees
+            .get(currency)
+            .copied()
+            .unwrap_or_default();
+
+        json!({
+            "member_level": {
+                "security_level": 4,
+                "fee_level": 0,
+                "email_verified": true,
+                "identity_auth_verified": true,
+                "bank_account_verified": true,
+                "two_factor_auth_verified": true,
+                "locked": false,
+                "wallet_locked": false,
+            },
+            "currency": {
+                "code": currency,
+                "withdraw_fee": decimal(fee),
+                "is_coin": currency != "KRW",
+                "wallet_state": "working",
+                "wallet_support": ["deposit", "withdraw"],
+            },
+            "account": self.account(currency),
+            "withdraw_limit": {
+                "currency": currency,
+                "minimum": "0",
+                "onetime": null,
+                "daily": null,
+                "remaining_daily": "0",
+                "remaining_daily_krw": "0",
+                "remaining_daily_fiat": "1000000000",
+                "fixed": 8,
+                "can_withdraw": true,
+            },
+        })
+    }
+
+    pub(super) fn withdraw_addresses(&self) -> Vec<Value> {
+        self.withdraw_addresses
+            .iter()
+            .map(|address| {
+                json!({
+                    "currency": address.currency,
+                    "net_type": address.net_type,
+                    "network_name": address.net_type,
+                    "withdraw_address": address.address,
+                    "secondary_address": address.secondary_address,
+                })
+            })
+            .collect()
+    }
+
+    /// Deposit addresses of `currency` on `net_type`, or of every currency if `None`
+    pub(super) fn deposit_addresses(&self, currency: Option<(&str, &str)>) -> Vec<Value> {
+        self.deposit_addresses
+            .iter()
+            .filter(|address| {
+                currency.is_none_or(|(currency, net_type)| {
+                    address.currency == currency && address.net_type == net_type
+                })
+            })
+            .map(|address| {
+                json!({
+                    "currency": address.currency,
+                    "net_type": address.net_type,
+                    "deposit_address": address.address,
+                    "secondary_address": address.secondary_address,
+                })
+            })
+            .collect()
+    }
+
+    /// Quote and base currencies of `market`, such as `KRW` and `ETH` of `KRW-ETH`
+    fn currencies<'a>(&self, market: &'a str) -> Result<(&'a str, &'a str), MockError> {
+        match market.split_once('-') {
+            Some(currencies) if self.markets.contains_key(market) => Ok(currencies),
+            _ => Err(MockError::market_not_found(market)),
+        }
+    }
+}
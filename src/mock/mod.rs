@@ -0,0 +1,916 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use reqwest::header::{HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Method, StatusCode, Url};
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::constant::{
+    URL_ACCOUNTS, URL_CANDLE_DAY, URL_CANDLE_MINUTE, URL_CANDLE_MONTH, URL_CANDLE_WEEK,
+    URL_DEPOSIT, URL_DEPOSITS, URL_DEPOSITS_COIN_ADDRESS, URL_DEPOSITS_COIN_ADDRESSES,
+    URL_MARKET_STATE, URL_ORDER, URL_ORDERBOOK, URL_ORDER_CHANCE, URL_ORDER_STATUS,
+    URL_ORDER_STATUS_BY_UUID, URL_ORDER_STATUS_CLOSED, URL_ORDER_STATUS_OPEN, URL_SERVER,
+    URL_TICKER, URL_TRADES_TICKS, URL_WITHDRAW, URL_WITHDRAWS, URL_WITHDRAWS_CHANCE,
+    URL_WITHDRAWS_COIN, URL_WITHDRAWS_COIN_ADDRESS,
+};
+use crate::request::RequestParams;
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, TransportFuture};
+
+use exchange::{Address, Exchange, OrderRequest};
+use quotation::CandlePeriod;
+
+pub use exchange::Balance;
+
+/// Accounts, orders and the matching engine
+mod exchange;
+/// Market data synthesized from the markets
+mod quotation;
+
+/// Header of the request quota left in the group of the API
+const REMAINING_REQ: &str = "Remaining-Req";
+
+/// Error response of [MockServer], returned by its matching engine or injected by [MockServer::fail_next]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockError {
+    /// `429 too_many_requests`, as when the request quota of the group is exhausted
+    TooManyRequests,
+    /// `400 insufficient_funds_bid`
+    InsufficientFundsBid,
+    /// `500 server_error`
+    ServerError,
+    /// Any other error response, with the `error` body of `name` and `message`
+    Custom {
+        status: StatusCode,
+        name: String,
+        message: String,
+    },
+}
+
+impl MockError {
+    pub fn custom(status: StatusCode, name: &str, message: &str) -> Self {
+        Self::Custom {
+            status,
+            name: name.to_owned(),
+            message: message.to_owned(),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            Self::InsufficientFundsBid => StatusCode::BAD_REQUEST,
+            Self::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Custom { status, .. } => *status,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::TooManyRequests => "too_many_requests",
+            Self::InsufficientFundsBid => "insufficient_funds_bid",
+            Self::ServerError => "server_error",
+            Self::Custom { name, .. } => name,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Self::TooManyRequests => "Too many API requests.",
+            Self::InsufficientFundsBid => "주문가능한 금액(KRW)이 부족합니다.",
+            Self::ServerError => "서버 에러",
+            Self::Custom { message, .. } => message,
+        }
+    }
+
+    fn validation(param: &str) -> Self {
+        Self::custom(
+            StatusCode::BAD_REQUEST,
+            "validation_error",
+            &format!("{param} is missing or invalid"),
+        )
+    }
+
+    fn order_not_found() -> Self {
+        Self::custom(
+            StatusCode::NOT_FOUND,
+            "order_not_found",
+            "주문을 찾지 못했습니다.",
+        )
+    }
+
+    fn market_not_found(market: &str) -> Self {
+        Self::custom(
+            StatusCode::NOT_FOUND,
+            "notfoundmarket",
+            &format!("not found market marketId: {market}"),
+        )
+    }
+
+    fn body(&self) -> Value {
+        json!({
+            "error": {
+                "name": self.name(),
+                "message": self.message(),
+            }
+        })
+    }
+}
+
+/// State shared by the clones of a [MockServer]
+#[derive(Debug)]
+struct State {
+    access_key: String,
+    secret_key: String,
+    exchange: Exchange,
+    /// Errors injected by [MockServer::fail_next], with the path of the API they answer
+    errors: Vec<(String, MockError)>,
+    nonces: HashSet<String>,
+}
+
+/// In-process Upbit server for testing without network
+///
+/// Requests are answered from balances, markets and transfers kept in memory, after verifying their JWT
+/// with the same `query_hash` as [crate::request::sign]. Orders are matched against an order book synthesized
+/// around the price of each market, and the part of a limit order left open is filled once [MockServer::set_price]
+/// moves the price across it.
+///
+/// Install it with [crate::set_transport] or [crate::transport::scope] to answer the APIs of this crate,
+/// or [MockServer::serve] it over HTTP and point the APIs at it with [crate::set_base_url].
+///
+/// Only the REST APIs are served. There is no WebSocket server, as this crate has no WebSocket client to test.
+///
+/// # Example
+/// ```
+/// let mock = upbit::mock::MockServer::new("access key", "secret key")
+///     .with_balance("KRW", 1_000_000.0)
+///     .with_market("KRW-ETH", 3_572_000.0);
+///
+/// upbit::set_transport(mock.clone());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockServer {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockServer {
+    /// Server accepting requests signed with `access_key` and `secret_key`
+    pub fn new(access_key: &str, secret_key: &str) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                access_key: access_key.to_owned(),
+                secret_key: secret_key.to_owned(),
+                exchange: Exchange::default(),
+                errors: Vec::new(),
+                nonces: HashSet::new(),
+            })),
+        }
+    }
+
+    pub fn with_balance(self, currency: &str, balance: f64) -> Self {
+        self.set_balance(currency, balance);
+        self
+    }
+
+    /// Add `market`, such as `KRW-ETH`, last traded at `price`
+    pub fn with_market(self, market: &str, price: f64) -> Self {
+        self.exchange(|exchange| exchange.add_market(market, price));
+        self
+    }
+
+    pub fn with_deposit_address(
+        self,
+        currency: &str,
+        net_type: &str,
+        address: &str,
+        secondary_address: Option<&str>,
+    ) -> Self {
+        self.exchange(|exchange| {
+            exchange.add_deposit_address(address_of(currency, net_type, address, secondary_address))
+        });
+        self
+    }
+
+    /// Register an address to which `withdraw_coin` is allowed
+    pub fn with_withdraw_address(
+        self,
+        currency: &str,
+        net_type: &str,
+        address: &str,
+        secondary_address: Option<&str>,
+    ) -> Self {
+        self.exchange(|exchange| {
+            exchange.add_withdraw_address(address_of(
+                currency,
+                net_type,
+                address,
+                secondary_address,
+            ))
+        });
+        self
+    }
+
+    pub fn with_withdraw_fee(self, currency: &str, fee: f64) -> Self {
+        self.exchange(|exchange| exchange.set_withdraw_fee(currency, fee));
+        self
+    }
+
+    /// Set the balance of `currency` available for orders and withdrawals
+    pub fn set_balance(&self, currency: &str, balance: f64) {
+        self.exchange(|exchange| exchange.set_balance(currency, balance));
+    }
+
+    pub fn balance(&self, currency: &str) -> Balance {
+        self.exchange(|exchange| exchange.balance(currency))
+    }
+
+    /// Move the price of `market`, filling the open orders crossed by it at their price
+    pub fn set_price(&self, market: &str, price: f64) {
+        self.exchange(|exchange| exchange.set_price(market, price));
+    }
+
+    /// Credit `amount` of `currency` as an accepted deposit, returning its uuid
+    pub fn deposit(&self, currency: &str, amount: f64) -> String {
+        let net_type = (currency != "KRW").then_some(currency);
+
+        self.exchange(|exchange| exchange.deposit(currency, net_type, amount))
+    }
+
+    /// Answer the next request to the API of `endpoint`, such as [crate::constant::URL_ORDER], with `error`
+    ///
+    /// Errors injected for the same API answer its requests in the order they are injected.
+    pub fn fail_next(&self, endpoint: &str, error: MockError) {
+        self.state
+            .lock()
+            .unwrap()
+            .errors
+            .push((endpoint.to_owned(), error));
+    }
+
+    /// Serve the APIs over HTTP on `listener`, such as one bound to `127.0.0.1:0`
+    ///
+    /// The APIs of this crate send their requests to it once its address is installed by [crate::set_base_url]
+    /// or [crate::transport::scope_base_url].
+    pub async fn serve(self, listener: TcpListener) -> std::io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = self.clone();
+
+            tokio::spawn(async move {
+                let _ = server.serve_connection(stream).await;
+            });
+        }
+    }
+
+    async fn serve_connection(&self, stream: TcpStream) -> std::io::Result<()> {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).await?;
+
+        let mut request_line = line.split_whitespace();
+        let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+            return Ok(());
+        };
+        let method = Method::from_bytes(method.as_bytes())
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        let mut request = HttpRequest::new(method, format!("{URL_SERVER}{target}"));
+        let mut content_length = 0;
+
+        loop {
+            line.clear();
+            reader.read_line(&mut line).await?;
+
+            let Some((name, value)) = line.trim_end().split_once(':') else {
+                break;
+            };
+
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or_default();
+            }
+
+            if let Ok(name) = HeaderName::from_bytes(name.as_bytes()) {
+                request.headers.push((name, value.trim().to_owned()));
+            }
+        }
+
+        if content_length > 0 {
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).await?;
+            request.body = Some(String::from_utf8_lossy(&body).into_owned());
+        }
+
+        let res = self.handle(&request);
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+            res.status.as_u16(),
+            res.status.canonical_reason().unwrap_or_default(),
+            res.body.len()
+        );
+
+        for (name, value) in &res.headers {
+            head += &format!("{name}: {}\r\n", value.to_str().unwrap_or_default());
+        }
+
+        let stream = reader.get_mut();
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(b"\r\n").await?;
+        stream.write_all(res.body.as_bytes()).await
+    }
+
+    /// Answer `request` as the API would
+    fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        let mut state = self.state.lock().unwrap();
+        let call = Call::new(request);
+
+        let injected = state
+            .errors
+            .iter()
+            .position(|(endpoint, _)| *endpoint == call.path)
+            .map(|index| state.errors.remove(index).1);
+
+        let result = match injected {
+            Some(error) => Err(error),
+            None => state.route(request, &call),
+        };
+        let (status, body) = match &result {
+            Ok((status, body)) => (*status, body.clone()),
+            Err(error) => (error.status(), error.body()),
+        };
+
+        let group = match (&call.method, call.path.as_str()) {
+            (&Method::POST, URL_ORDER) | (&Method::DELETE, URL_ORDER_STATUS) => "order",
+            _ => "default",
+        };
+        let remaining = if status == StatusCode::TOO_MANY_REQUESTS {
+            0
+        } else {
+            7
+        };
+
+        let mut res = HttpResponse::new(status, body.to_string());
+        res.headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/json; charset=utf-8"),
+        );
+
+        if let Ok(value) = HeaderValue::from_str(&format!("group={group}; min=59; sec={remaining}"))
+        {
+            res.headers.insert(REMAINING_REQ, value);
+        }
+
+        res
+    }
+
+    fn exchange<T>(&self, f: impl FnOnce(&mut Exchange) -> T) -> T {
+        f(&mut self.state.lock().unwrap().exchange)
+    }
+}
+
+impl HttpTransport for MockServer {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        let res = self.handle(&request);
+
+        Box::pin(async move { Ok(res) })
+    }
+}
+
+impl State {
+    fn route(
+        &mut self,
+        request: &HttpRequest,
+        call: &Call,
+    ) -> Result<(StatusCode, Value), MockError> {
+        let ok = |body: Value| Ok((StatusCode::OK, body));
+        let ascending = call.param("order_by").as_deref() == Some("asc");
+        let path = call.path.as_str();
+
+        match (&call.method, path) {
+            (&Method::GET, URL_MARKET_STATE) => {
+                let is_details = call.param("isDetails").as_deref() == Some("true");
+
+                return ok(quotation::market_all(
+                    self.exchange.markets.keys(),
+                    is_details,
+                ));
+            }
+            (&Method::GET, URL_TICKER) => {
+                return self.markets(call).map(|markets| {
+                    (
+                        StatusCode::OK,
+                        markets
+                            .iter()
+                            .map(|(id, market)| quotation::ticker(id, market))
+                            .collect(),
+                    )
+                });
+            }
+            (&Method::GET, URL_ORDERBOOK) => {
+                return self.markets(call).map(|markets| {
+                    (
+                        StatusCode::OK,
+                        markets
+                            .iter()
+                            .map(|(id, market)| quotation::orderbook(id, market))
+                            .collect(),
+                    )
+                });
+            }
+            (&Method::GET, URL_TRADES_TICKS) => {
+                let market_id = call.required("market")?;
+                let count = call.number("count").unwrap_or(1.0) as usize;
+                let market = self.market(&market_id)?;
+
+                return ok(quotation::trades(&market_id, market, count));
+            }
+            (&Method::GET, _) if path.starts_with("/v1/candles/") => {
+                let period = match path {
+                    URL_CANDLE_DAY => CandlePeriod::Day,
+                    URL_CANDLE_WEEK => CandlePeriod::Week,
+                    URL_CANDLE_MONTH => CandlePeriod::Month,
+                    _ => path
+                        .strip_prefix(URL_CANDLE_MINUTE)
+                        .and_then(|unit| unit.parse().ok())
+                        .map(CandlePeriod::Minutes)
+                        .ok_or_else(not_found)?,
+                };
+                let market_id = call.required("market")?;
+                let count = call.number("count").unwrap_or(1.0) as usize;
+                let market = self.market(&market_id)?;
+
+                return ok(quotation::candles(&market_id, market, period, count));
+            }
+            _ => {}
+        }
+
+        self.authorize(request, call)?;
+
+        let exchange = &mut self.exchange;
+
+        match (&call.method, path) {
+            (&Method::GET, URL_ACCOUNTS) => ok(exchange.accounts()),
+            (&Method::GET, URL_ORDER_CHANCE) => {
+                ok(exchange.order_chance(&call.required("market")?)?)
+            }
+            (&Method::POST, URL_ORDER) => {
+                let order = exchange.place_order(OrderRequest {
+                    market: call.required("market")?,
+                    side: call.required("side")?,
+                    ord_type: call.required("ord_type")?,
+                    price: call.number("price"),
+                    volume: call.number("volume"),
+                    identifier: call.param("identifier"),
+                })?;
+
+                Ok((StatusCode::CREATED, order))
+            }
+            (&Method::GET, URL_ORDER_STATUS) => ok(exchange.order(
+                call.param("uuid").as_deref(),
+                call.param("identifier").as_deref(),
+            )?),
+            (&Method::DELETE, URL_ORDER_STATUS) => ok(exchange.cancel_order(
+                call.param("uuid").as_deref(),
+                call.param("identifier").as_deref(),
+            )?),
+            (&Method::GET, URL_ORDER_STATUS_BY_UUID) => {
+                let (uuids, identifiers) = (call.params("uuids"), call.params("identifiers"));
+                let orders = exchange.orders(
+                    call.param("market").as_deref(),
+                    &["wait", "watch", "done", "cancel"],
+                    |uuid, identifier| {
+                        uuids.iter().any(|x| x == uuid)
+                            || identifier.is_some_and(|identifier| {
+                                identifiers.iter().any(|x| x == identifier)
+                            })
+                    },
+                    ascending,
+                );
+
+                ok(Value::Array(orders))
+            }
+            (&Method::GET, URL_ORDER_STATUS_OPEN | URL_ORDER_STATUS_CLOSED) => {
+                let states = call.params("states");
+                let states = match (states.is_empty(), path) {
+                    (false, _) => states.iter().map(String::as_str).collect(),
+                    (true, URL_ORDER_STATUS_OPEN) => vec!["wait", "watch"],
+                    (true, _) => vec!["done", "cancel"],
+                };
+                let orders = exchange.orders(
+                    call.param("market").as_deref(),
+                    &states,
+                    |_, _| true,
+                    ascending,
+                );
+
+                ok(call.page(orders))
+            }
+            (&Method::GET, URL_DEPOSIT | URL_WITHDRAW) => {
+                let r#type = if path == URL_DEPOSIT {
+                    "deposit"
+                } else {
+                    "withdraw"
+                };
+
+                ok(exchange.transfer_by(
+                    r#type,
+                    call.param("uuid").as_deref(),
+                    call.param("txid").as_deref(),
+                    call.param("currency").as_deref(),
+                )?)
+            }
+            (&Method::GET, URL_DEPOSITS | URL_WITHDRAWS) => {
+                let r#type = if path == URL_DEPOSITS {
+                    "deposit"
+                } else {
+                    "withdraw"
+                };
+                let transfers = exchange.transfers(
+                    r#type,
+                    call.param("currency").as_deref(),
+                    call.param("state").as_deref(),
+                    ascending,
+                );
+
+                ok(call.page(transfers))
+            }
+            (&Method::GET, URL_DEPOSITS_COIN_ADDRESS) => {
+                let (currency, net_type) = (call.required("currency")?, call.required("net_type")?);
+
+                exchange
+                    .deposit_addresses(Some((&currency, &net_type)))
+                    .into_iter()
+                    .next()
+                    .map(|address| (StatusCode::OK, address))
+                    .ok_or_else(|| {
+                        MockError::custom(
+                            StatusCode::NOT_FOUND,
+                            "coin_address_not_found",
+                            "디지털 자산 지갑정보를 찾지 못했습니다.",
+                        )
+                    })
+            }
+            (&Method::GET, URL_DEPOSITS_COIN_ADDRESSES) => {
+                ok(Value::Array(exchange.deposit_addresses(None)))
+            }
+            (&Method::GET, URL_WITHDRAWS_CHANCE) => {
+                ok(exchange.withdraw_chance(&call.required("currency")?))
+            }
+            (&Method::GET, URL_WITHDRAWS_COIN_ADDRESS) => {
+                ok(Value::Array(exchange.withdraw_addresses()))
+            }
+            (&Method::POST, URL_WITHDRAWS_COIN) => {
+                let withdrawal = exchange.withdraw_coin(
+                    &call.required("currency")?,
+                    &call.required("net_type")?,
+                    call.number("amount")
+                        .ok_or_else(|| MockError::validation("amount"))?,
+                    &call.required("address")?,
+                    &call
+                        .param("transaction_type")
+                        .unwrap_or("default".to_owned()),
+                )?;
+
+                Ok((StatusCode::CREATED, withdrawal))
+            }
+            _ => Err(not_found()),
+        }
+    }
+
+    /// Verify the JWT of `request`, signed with the keys of the server for its parameters and a new nonce
+    fn authorize(&mut self, request: &HttpRequest, call: &Call) -> Result<(), MockError> {
+        let unauthorized = |e: crate::response::ResponseError| {
+            MockError::custom(StatusCode::UNAUTHORIZED, &e.error.name, &e.error.message)
+        };
+
+        let authorization = request
+            .headers
+            .iter()
+            .find(|(name, _)| *name == AUTHORIZATION)
+            .map(|(_, value)| value.as_str())
+            .unwrap_or_default();
+        let params = match (&call.body, &request.body) {
+            (Some(_), Some(body)) => RequestParams::from_json(body),
+            _ => RequestParams::from_url(&request.url),
+        }
+        .map_err(unauthorized)?;
+        let claims = crate::request::verify(authorization, &params, &self.secret_key)
+            .map_err(unauthorized)?;
+
+        if claims["access_key"] != self.access_key.as_str() {
+            return Err(MockError::custom(
+                StatusCode::UNAUTHORIZED,
+                "invalid_access_key",
+                "잘못된 엑세스 키입니다.",
+            ));
+        }
+
+        let nonce = claims["nonce"].as_str().unwrap_or_default().to_owned();
+
+        if !self.nonces.insert(nonce) {
+            return Err(MockError::custom(
+                StatusCode::UNAUTHORIZED,
+                "nonce_used",
+                "이미 요청한 nonce값이 다시 사용되었습니다.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn market(&self, market: &str) -> Result<&exchange::Market, MockError> {
+        self.exchange
+            .markets
+            .get(market)
+            .ok_or_else(|| MockError::market_not_found(market))
+    }
+
+    /// Markets of the comma separated `markets` parameter
+    fn markets(&self, call: &Call) -> Result<Vec<(String, &exchange::Market)>, MockError> {
+        call.required("markets")?
+            .split(',')
+            .map(|market| Ok((market.to_owned(), self.market(market)?)))
+            .collect()
+    }
+}
+
+/// Path and parameters of a request
+struct Call {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Option<Value>,
+}
+
+impl Call {
+    fn new(request: &HttpRequest) -> Self {
+        let url = Url::parse(&request.url).ok();
+
+        Self {
+            method: request.method.clone(),
+            path: url
+                .as_ref()
+                .map_or_else(String::new, |url| url.path().to_owned()),
+            query: url.map_or_else(Vec::new, |url| url.query_pairs().into_owned().collect()),
+            body: request
+                .body
+                .as_deref()
+                .and_then(|body| serde_json::from_str(body).ok()),
+        }
+    }
+
+    /// Value of `key` in the query string or the JSON body
+    fn param(&self, key: &str) -> Option<String> {
+        let from_query = self
+            .query
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.clone());
+
+        from_query.or_else(|| match self.body.as_ref()?.get(key)? {
+            Value::String(value) => Some(value.clone()),
+            Value::Null => None,
+            value => Some(value.to_string()),
+        })
+    }
+
+    fn required(&self, key: &str) -> Result<String, MockError> {
+        self.param(key).ok_or_else(|| MockError::validation(key))
+    }
+
+    fn number(&self, key: &str) -> Option<f64> {
+        self.param(key)?.parse().ok()
+    }
+
+    /// Values of the array parameter `key`, sent as `key[]` in the query string
+    fn params(&self, key: &str) -> Vec<String> {
+        let array_key = format!("{key}[]");
+        let from_query = self
+            .query
+            .iter()
+            .filter(|(name, _)| *name == array_key)
+            .map(|(_, value)| value.clone());
+        let from_body = self
+            .body
+            .as_ref()
+            .and_then(|body| body.get(key)?.as_array().cloned())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|value| value.as_str().map(str::to_owned));
+
+        from_query.chain(from_body).collect()
+    }
+
+    /// Page of `items` selected by the `page` and `limit` parameters
+    fn page(&self, items: Vec<Value>) -> Value {
+        let limit = self.number("limit").unwrap_or(100.0) as usize;
+        let page = self.number("page").unwrap_or(1.0) as usize;
+
+        items
+            .into_iter()
+            .skip(page.saturating_sub(1) * limit)
+            .take(limit)
+            .collect()
+    }
+}
+
+fn not_found() -> MockError {
+    MockError::custom(StatusCode::NOT_FOUND, "not_found", "Not Found")
+}
+
+fn address_of(
+    currency: &str,
+    net_type: &str,
+    address: &str,
+    secondary_address: Option<&str>,
+) -> Address {
+    Address {
+        currency: currency.to_owned(),
+        net_type: net_type.to_owned(),
+        address: address.to_owned(),
+        secondary_address: secondary_address.map(str::to_owned),
+    }
+}
+
+/// Number string as the API sends, without trailing zeros
+fn decimal(value: f64) -> String {
+    let value = format!("{value:.8}");
+    let value = value.trim_end_matches('0').trim_end_matches('.');
+
+    match value {
+        "-0" => "0".to_owned(),
+        value => value.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_exchange::{OrderSide, OrderState, OrderType};
+    use crate::credential::{self, Credentials};
+    use crate::response::ResponseErrorState;
+
+    use super::*;
+
+    fn mock() -> MockServer {
+        MockServer::new("access key", "secret key")
+            .with_balance("KRW", 1_000_000.0)
+            .with_market("KRW-ETH", 3_572_000.0)
+    }
+
+    #[tokio::test]
+    async fn test_order_matching() {
+        let mock = mock();
+
+        let (taken, resting, rejected) = crate::transport::scope(
+            mock.clone(),
+            credential::scope(Credentials::new("access key", "secret key"), async {
+                // crosses the best ask at 3,573,000
+                let taken = crate::api_exchange::order_by_price(
+                    "KRW-ETH",
+                    OrderSide::Bid,
+                    357_300.0,
+                    3_574_000.0,
+                    OrderType::Limit,
+                    None,
+                )
+                .await
+                .unwrap();
+                let resting = crate::api_exchange::order_by_price(
+                    "KRW-ETH",
+                    OrderSide::Bid,
+                    100_000.0,
+                    3_500_000.0,
+                    OrderType::Limit,
+                    Some("resting"),
+                )
+                .await
+                .unwrap();
+                let rejected = crate::api_exchange::order_by_price(
+                    "KRW-ETH",
+                    OrderSide::Bid,
+                    1_000_000.0,
+                    3_500_000.0,
+                    OrderType::Limit,
+                    None,
+                )
+                .await;
+
+                (taken, resting, rejected)
+            }),
+        )
+        .await;
+
+        assert_eq!(taken.state, OrderState::Done);
+        assert_eq!(resting.state, OrderState::Wait);
+        assert_eq!(
+            rejected.unwrap_err().state,
+            ResponseErrorState::InsufficientFundsBid
+        );
+
+        let krw = mock.balance("KRW");
+        assert!((krw.locked - resting.locked).abs() < 1e-6);
+        assert!(
+            (krw.balance
+                - (1_000_000.0 - taken.executed_volume * 3_573_000.0 * 1.0005 - resting.locked))
+                .abs()
+                < 1e-6
+        );
+
+        mock.set_price("KRW-ETH", 3_500_000.0);
+
+        let resting = crate::transport::scope(
+            mock.clone(),
+            credential::scope(
+                Credentials::new("access key", "secret key"),
+                crate::api_exchange::get_order_status_by_identifier("resting"),
+            ),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resting.order_info.state, OrderState::Done);
+        assert_eq!(resting.trades.len(), 1);
+        assert_eq!(mock.balance("KRW").locked, 0.0);
+        assert!(
+            (mock.balance("ETH").balance - (taken.executed_volume + resting.order_info.volume))
+                .abs()
+                < 1e-8
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_and_inject_errors() {
+        let mock = mock();
+        mock.fail_next(URL_TICKER, MockError::TooManyRequests);
+        mock.fail_next(URL_TICKER, MockError::ServerError);
+
+        let (too_many, server_error, ticker, unverified) = crate::transport::scope(
+            mock,
+            credential::scope(Credentials::new("access key", "wrong secret key"), async {
+                (
+                    crate::api_quotation::get_ticker_snapshot_list(&["KRW-ETH"]).await,
+                    crate::api_quotation::get_ticker_snapshot_list(&["KRW-ETH"]).await,
+                    crate::api_quotation::get_ticker_snapshot_list(&["KRW-ETH"]).await,
+                    crate::api_exchange::get_account_info().await,
+                )
+            }),
+        )
+        .await;
+
+        assert_eq!(too_many.unwrap_err().error.name, "too_many_requests");
+        assert_eq!(
+            server_error.unwrap_err().state,
+            ResponseErrorState::ServerError
+        );
+        assert_eq!(ticker.unwrap()[0].trade_price, 3_572_000.0);
+        assert_eq!(
+            unverified.unwrap_err().state,
+            ResponseErrorState::JwtVerificationError
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serve() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(mock().serve(listener));
+
+        let client = reqwest::Client::builder().no_proxy().build().unwrap();
+        let res = client
+            .get(format!("http://{addr}{URL_TICKER}?markets=KRW-ETH"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(res.headers().contains_key(REMAINING_REQ));
+        assert_eq!(
+            res.json::<Value>().await.unwrap()[0]["trade_price"],
+            3_572_000.0
+        );
+
+        let res = client
+            .get(format!("http://{addr}{URL_ACCOUNTS}"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+        let (tickers, accounts) = crate::transport::scope(
+            crate::transport::ReqwestTransport::with_client(client),
+            crate::transport::scope_base_url(
+                format!("http://{addr}"),
+                credential::scope(Credentials::new("access key", "secret key"), async {
+                    (
+                        crate::api_quotation::get_ticker_snapshot_list(&["KRW-ETH"]).await,
+                        crate::api_exchange::get_account_info().await,
+                    )
+                }),
+            ),
+        )
+        .await;
+        assert_eq!(tickers.unwrap()[0].trade_price, 3_572_000.0);
+        assert!(accounts
+            .unwrap()
+            .iter()
+            .any(|account| account.currency == "KRW" && account.balance == 1_000_000.0));
+    }
+}
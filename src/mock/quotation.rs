@@ -0,0 +1,241 @@
+use serde_json::{json, Value};
+
+use super::exchange::Market;
+use crate::date::{civil_from_days, date_time, days_from_civil, now_ms, KST_OFFSET_SECS};
+
+/// Milliseconds of a day
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Period of a candle
+#[derive(Debug, Clone, Copy)]
+pub(super) enum CandlePeriod {
+    Minutes(i64),
+    Day,
+    Week,
+    Month,
+}
+
+pub(super) fn market_all<'a>(markets: impl Iterator<Item = &'a String>, is_details: bool) -> Value {
+    markets
+        .map(|market| {
+            let (_, base) = market.split_once('-').unwrap_or_default();
+            let mut state = json!({
+                "market": market,
+                "korean_name": base,
+                "english_name": base,
+            });
+
+            if is_details {
+                state["market_warning"] = json!("NONE");
+                state["market_event"] = json!({
+                    "warning": false,
+                    "caution": {
+                        "PRICE_FLUCTUATIONS": false,
+                        "TRADING_VOLUME_SOARING": false,
+                        "DEPOSIT_AMOUNT_SOARING": false,
+                        "GLOBAL_PRICE_DIFFERENCES": false,
+                        "CONCENTRATION_OF_SMALL_ACCOUNTS": false,
+                    },
+                });
+            }
+
+            state
+        })
+        .collect()
+}
+
+pub(super) fn ticker(market_id: &str, market: &Market) -> Value {
+    let now = now_ms();
+    let trade_timestamp = market.ticks.last().map_or(now, |tick| tick.timestamp);
+    let trade_volume = market.ticks.last().map_or(0.0, |tick| tick.volume);
+    let (trade_date, trade_time) = date_time(trade_timestamp);
+    let (trade_date_kst, trade_time_kst) = date_time(trade_timestamp + KST_OFFSET_SECS * 1000);
+    let change_price = market.price - market.prev_closing_price;
+    let change_rate = change_price / market.prev_closing_price;
+
+    json!({
+        "market": market_id,
+        "trade_date": trade_date.replace('-', ""),
+        "trade_time": trade_time.replace(':', ""),
+        "trade_date_kst": trade_date_kst.replace('-', ""),
+        "trade_time_kst": trade_time_kst.replace(':', ""),
+        "trade_timestamp": trade_timestamp,
+        "opening_price": market.opening_price,
+        "high_price": market.high_price,
+        "low_price": market.low_price,
+        "trade_price": market.price,
+        "prev_closing_price": market.prev_closing_price,
+        "change": if change_price > 0.0 { "RISE" } else if change_price < 0.0 { "FALL" } else { "EVEN" },
+        "change_price": change_price.abs(),
+        "change_rate": change_rate.abs(),
+        "signed_change_price": change_price,
+        "signed_change_rate": change_rate,
+        "trade_volume": trade_volume,
+        "acc_trade_price": market.acc_trade_price,
+        "acc_trade_price_24h": market.acc_trade_price,
+        "acc_trade_volume": market.acc_trade_volume,
+        "acc_trade_volume_24h": market.acc_trade_volume,
+        "highest_52_week_price": market.high_price,
+        "highest_52_week_date": trade_date,
+        "lowest_52_week_price": market.low_price,
+        "lowest_52_week_date": trade_date,
+        "timestamp": now,
+    })
+}
+
+pub(super) fn orderbook(market_id: &str, market: &Market) -> Value {
+    let units = market
+        .asks
+        .iter()
+        .zip(market.bids.iter())
+        .map(|(ask, bid)| {
+            json!({
+                "ask_price": ask.price,
+                "bid_price": bid.price,
+                "ask_size": ask.size,
+                "bid_size": bid.size,
+            })
+        })
+        .collect::<Vec<Value>>();
+
+    json!({
+        "market": market_id,
+        "timestamp": now_ms(),
+        "total_ask_size": market.asks.iter().map(|level| level.size).sum::<f64>(),
+        "total_bid_size": market.bids.iter().map(|level| level.size).sum::<f64>(),
+        "orderbook_units": units,
+        "level": 0,
+    })
+}
+
+/// Latest `count` trades of `market`, the latest first
+pub(super) fn trades(market_id: &str, market: &Market, count: usize) -> Value {
+    market
+        .ticks
+        .iter()
+        .rev()
+        .take(count)
+        .map(|tick| {
+            let (date, time) = date_time(tick.timestamp);
+
+            json!({
+                "market": market_id,
+                "trade_date_utc": date,
+                "trade_time_utc": time,
+                "timestamp": tick.timestamp,
+                "trade_price": tick.price,
+                "trade_volume": tick.volume,
+                "prev_closing_price": market.prev_closing_price,
+                "change_price": tick.price - market.prev_closing_price,
+                "ask_bid": tick.ask_bid,
+                "sequential_id": tick.sequential_id,
+            })
+        })
+        .collect()
+}
+
+/// Latest `count` candles of `market`, the latest first
+///
+/// Candles are flat at the current price, with the trades of the mock account in the latest one.
+pub(super) fn candles(
+    market_id: &str,
+    market: &Market,
+    period: CandlePeriod,
+    count: usize,
+) -> Value {
+    let now = now_ms();
+    let mut start = period_start(period, now);
+    let mut candles = Vec::new();
+
+    for i in 0..count {
+        let (date, time) = date_time(start);
+        let (date_kst, time_kst) = date_time(start + KST_OFFSET_SECS * 1000);
+        let ticks = market
+            .ticks
+            .iter()
+            .filter(|tick| i == 0 && tick.timestamp >= start);
+
+        let mut candle = json!({
+            "market": market_id,
+            "candle_date_time_utc": format!("{date}T{time}"),
+            "candle_date_time_kst": format!("{date_kst}T{time_kst}"),
+            "opening_price": market.price,
+            "high_price": market.price,
+            "low_price": market.price,
+            "trade_price": market.price,
+            "timestamp": if i == 0 { now } else { start },
+            "candle_acc_trade_price": ticks.clone().map(|tick| tick.price * tick.volume).sum::<f64>(),
+            "candle_acc_trade_volume": ticks.map(|tick| tick.volume).sum::<f64>(),
+        });
+
+        match period {
+            CandlePeriod::Minutes(unit) => candle["unit"] = json!(unit),
+            CandlePeriod::Day => {
+                candle["prev_closing_price"] = json!(market.price);
+                candle["change_price"] = json!(0.0);
+                candle["change_rate"] = json!(0.0);
+            }
+            CandlePeriod::Week | CandlePeriod::Month => {
+                candle["first_day_of_period"] = json!(date);
+            }
+        }
+
+        candles.push(candle);
+        start = period_start(period, start - 1);
+    }
+
+    Value::Array(candles)
+}
+
+/// Start of the period including `timestamp`, in UTC
+fn period_start(period: CandlePeriod, timestamp: i64) -> i64 {
+    let days = timestamp.div_euclid(DAY_MS);
+
+    match period {
+        CandlePeriod::Minutes(unit) => timestamp - timestamp.rem_euclid(unit * 60 * 1000),
+        CandlePeriod::Day => days * DAY_MS,
+        // 1970-01-01 is a Thursday
+        CandlePeriod::Week => (days - (days + 3).rem_euclid(7)) * DAY_MS,
+        CandlePeriod::Month => {
+            let (year, month, _) = civil_from_days(days);
+
+            days_from_civil(year, month, 1) * DAY_MS
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_period_start() {
+        // 2025-06-18T12:34:56.789Z, a Wednesday
+        let timestamp = 1_750_250_096_789;
+
+        assert_eq!(
+            date_time(period_start(CandlePeriod::Minutes(15), timestamp)),
+            ("2025-06-18".to_owned(), "12:30:00".to_owned())
+        );
+        assert_eq!(
+            date_time(period_start(CandlePeriod::Day, timestamp)).0,
+            "2025-06-18"
+        );
+        assert_eq!(
+            date_time(period_start(CandlePeriod::Week, timestamp)).0,
+            "2025-06-16"
+        );
+        assert_eq!(
+            date_time(period_start(CandlePeriod::Month, timestamp)).0,
+            "2025-06-01"
+        );
+        assert_eq!(
+            date_time(period_start(
+                CandlePeriod::Month,
+                days_from_civil(2025, 1, 1) * DAY_MS - 1
+            ))
+            .0,
+            "2024-12-01"
+        );
+    }
+}
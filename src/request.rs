@@ -1,66 +1,365 @@
 use jsonwebtokens as jwt;
 use jwt::{Algorithm, AlgorithmID};
 use reqwest::Url;
-use serde_json::json;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::{json, Value};
 use sha2::{Digest, Sha512};
 use uuid::Uuid;
 
 use crate::response::{
     response_error_internal_hmac_error, response_error_internal_token_encode_error, ResponseError,
 };
+#[cfg(feature = "mock")]
+use crate::response::{ResponseErrorBody, ResponseErrorSource};
 
-pub trait Request {
-    fn set_token() -> Result<String, ResponseError> {
-        let access_key = envmnt::get_or_panic("ACCESS_KEY");
-        let secret_key = envmnt::get_or_panic("SECRET_KEY");
-        let alg = Algorithm::new_hmac(AlgorithmID::HS256, secret_key)
-            .map_err(response_error_internal_hmac_error)?;
+/// Parameter value of [RequestParams]
+#[derive(Debug, Clone, PartialEq)]
+enum RequestParam {
+    Value(String),
+    Array(Vec<String>),
+}
+
+/// Parameters of a request in the order they are sent, either as the query string or as the JSON body
+///
+/// Both forms are signed with the same `query_hash`, the SHA512 of [RequestParams::canonical_query].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequestParams {
+    params: Vec<(String, RequestParam)>,
+}
+
+impl RequestParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parameters of the query string of `url`, decoded
+    pub fn from_url(url: &str) -> Result<Self, ResponseError> {
+        let url =
+            Url::parse(url).map_err(crate::response::response_error_internal_url_parse_error)?;
+        let mut params = Self::new();
+
+        for (key, value) in url.query_pairs() {
+            params.append(&key, value);
+        }
+
+        Ok(params)
+    }
+
+    /// Parameters of a JSON object body in the order of its fields, with arrays as array parameters
+    pub fn from_json(body: &str) -> Result<Self, ResponseError> {
+        let JsonFields(fields) =
+            serde_json::from_str(body).map_err(crate::response::response_error_from_json)?;
+        let mut params = Self::new();
+        let to_string = |value: &Value| match value {
+            Value::String(value) => value.clone(),
+            value => value.to_string(),
+        };
+
+        for (key, value) in fields {
+            match value {
+                Value::Array(values) => params.append_array(&key, values.iter().map(to_string)),
+                value => params.append(&key, to_string(&value)),
+            };
+        }
+
+        Ok(params)
+    }
+
+    pub fn append(&mut self, key: &str, value: impl ToString) -> &mut Self {
+        self.params
+            .push((key.to_owned(), RequestParam::Value(value.to_string())));
+        self
+    }
+
+    /// Append an array parameter, sent as `key[]=value` for each value in the query string
+    pub fn append_array<T: ToString>(
+        &mut self,
+        key: &str,
+        values: impl IntoIterator<Item = T>,
+    ) -> &mut Self {
+        self.params.push((
+            key.to_owned(),
+            RequestParam::Array(values.into_iter().map(|x| x.to_string()).collect()),
+        ));
+        self
+    }
+
+    /// Whether no value is sent, counting each value of array parameters
+    pub fn is_empty(&self) -> bool {
+        self.pairs().next().is_none()
+    }
+
+    fn pairs(&self) -> impl Iterator<Item = (String, &str)> {
+        self.params.iter().flat_map(|(key, param)| match param {
+            RequestParam::Value(value) => vec![(key.clone(), value.as_str())],
+            RequestParam::Array(values) => values
+                .iter()
+                .map(|value| (format!("{key}[]"), value.as_str()))
+                .collect(),
+        })
+    }
+
+    /// Unencoded `key=value&key[]=value` string hashed into `query_hash`
+    pub fn canonical_query(&self) -> String {
+        self.pairs()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<String>>()
+            .join("&")
+    }
+
+    /// Append the parameters to the query string of `url`, percent-encoded
+    pub fn append_to_url(&self, url: &mut Url) {
+        if self.is_empty() {
+            return;
+        }
+
+        let mut query_pairs = url.query_pairs_mut();
+
+        for (key, value) in self.pairs() {
+            query_pairs.append_pair(&key, value);
+        }
+    }
+
+    /// JSON body of the parameters, with array parameters as JSON arrays
+    pub fn json_body(&self) -> JsonBody<'_> {
+        JsonBody(self)
+    }
+}
+
+/// JSON body of [RequestParams], serialized with the fields in the order hashed into `query_hash`
+#[derive(Debug, Clone, Copy)]
+pub struct JsonBody<'a>(&'a RequestParams);
+
+impl Serialize for JsonBody<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.params.len()))?;
+
+        for (key, param) in &self.0.params {
+            match param {
+                RequestParam::Value(value) => map.serialize_entry(key, value)?,
+                RequestParam::Array(values) => map.serialize_entry(key, values)?,
+            }
+        }
+
+        map.end()
+    }
+}
+
+/// Fields of a JSON object in the order they are written
+struct JsonFields(Vec<(String, Value)>);
 
-        let header = json!({
-            "alg": alg.name()
-        });
+impl<'de> Deserialize<'de> for JsonFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldsVisitor;
 
-        let payload = json!({
-            "access_key": access_key,
-            "nonce": Uuid::new_v4(),
-        });
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = JsonFields;
 
-        let token = jwt::encode(&header, &payload, &alg)
-            .map_err(response_error_internal_token_encode_error)?;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a JSON object")
+            }
 
-        Ok(format!("Bearer {token}"))
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonFields, A::Error> {
+                let mut fields = Vec::new();
+
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+
+                Ok(JsonFields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
+
+/// SHA512 hex digest of the canonical query string of `params`
+fn query_hash(params: &RequestParams) -> String {
+    let mut hasher = Sha512::new();
+    hasher.update(params.canonical_query().as_bytes());
+
+    format!("{:x}", hasher.finalize())
+}
+
+/// Sign a request carrying `params` into an `Authorization` header value
+///
+/// `query_hash` is left out of the token if there is no parameter.
+pub fn sign(params: &RequestParams) -> Result<String, ResponseError> {
+    let credentials = crate::credential::credentials()?;
+    let alg = Algorithm::new_hmac(AlgorithmID::HS256, credentials.secret_key.expose())
+        .map_err(response_error_internal_hmac_error)?;
+
+    let header = json!({
+        "alg": alg.name()
+    });
+
+    let mut payload = json!({
+        "access_key": credentials.access_key,
+        "nonce": Uuid::new_v4(),
+    });
+
+    if !params.is_empty() {
+        payload["query_hash"] = json!(query_hash(params));
+        payload["query_hash_alg"] = json!("SHA512");
+    }
+
+    let token =
+        jwt::encode(&header, &payload, &alg).map_err(response_error_internal_token_encode_error)?;
+
+    Ok(format!("Bearer {token}"))
+}
+
+/// Verify an `Authorization` header value made by [sign] for a request carrying `params`, returning the claims
+///
+/// Fails with `jwt_verification` for a token not signed with `secret_key`,
+/// and with `invalid_query_payload` for a `query_hash` other than the one of `params`.
+#[cfg(feature = "mock")]
+pub(crate) fn verify(
+    authorization: &str,
+    params: &RequestParams,
+    secret_key: &str,
+) -> Result<Value, ResponseError> {
+    let error = |name: &str, message: &str| {
+        crate::response::response_error(ResponseErrorSource {
+            error: ResponseErrorBody {
+                name: name.to_owned(),
+                message: message.to_owned(),
+            },
+        })
+    };
+
+    let token = authorization
+        .strip_prefix("Bearer ")
+        .ok_or_else(|| error("jwt_verification", "Failed to verify Jwt token."))?;
+    let alg = Algorithm::new_hmac(AlgorithmID::HS256, secret_key)
+        .map_err(response_error_internal_hmac_error)?;
+    let claims = jwt::Verifier::create()
+        .build()
+        .and_then(|verifier| verifier.verify(token, &alg))
+        .map_err(|_| error("jwt_verification", "Failed to verify Jwt token."))?;
+
+    let expected_hash = (!params.is_empty()).then(|| query_hash(params));
+
+    if claims.get("query_hash").and_then(Value::as_str) != expected_hash.as_deref() {
+        return Err(error(
+            "invalid_query_payload",
+            "JWT 헤더의 페이로드가 올바르지 않습니다.",
+        ));
+    }
+
+    Ok(claims)
+}
+
+pub trait Request {
+    fn set_token() -> Result<String, ResponseError> {
+        sign(&RequestParams::new())
     }
 }
 
 pub trait RequestWithQuery {
     fn set_token_with_query(url: &str) -> Result<String, ResponseError> {
-        let access_key = envmnt::get_or_panic("ACCESS_KEY");
-        let secret_key = envmnt::get_or_panic("SECRET_KEY");
-        let url =
-            Url::parse(url).map_err(crate::response::response_error_internal_url_parse_error)?;
-        let url_parsed = url.query().unwrap_or("");
+        sign(&RequestParams::from_url(url)?)
+    }
+
+    fn set_token_with_params(params: &RequestParams) -> Result<String, ResponseError> {
+        sign(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::credential::{self, Credentials};
+
+    use super::*;
+
+    #[test]
+    fn test_canonical_query() {
+        // example of the order API reference
+        let mut params = RequestParams::new();
+        params
+            .append("market", "KRW-BTC")
+            .append("side", "bid")
+            .append("volume", "0.01")
+            .append("price", "100.0")
+            .append("ord_type", "limit");
+
+        assert_eq!(
+            params.canonical_query(),
+            "market=KRW-BTC&side=bid&volume=0.01&price=100.0&ord_type=limit"
+        );
+        assert_eq!(
+            query_hash(&params),
+            "1db802a392c559d55c99662a20c6911ba9ea31a9f58bf92156af243ca1462b004c6e6b27c934afefbde5ca15d28deb67e90cd619b466c9a3c2fe020ad2bbdd24"
+        );
+        // the body must keep the order hashed into query_hash
+        assert_eq!(
+            serde_json::to_string(&params.json_body()).unwrap(),
+            r#"{"market":"KRW-BTC","side":"bid","volume":"0.01","price":"100.0","ord_type":"limit"}"#
+        );
+    }
+
+    #[test]
+    fn test_canonical_query_with_array() {
+        // example of the order list API reference
+        let mut params = RequestParams::new();
+        params
+            .append("market", "KRW-BTC")
+            .append_array("states", ["done", "cancel"])
+            .append("order_by", "desc");
+
+        assert_eq!(
+            params.canonical_query(),
+            "market=KRW-BTC&states[]=done&states[]=cancel&order_by=desc"
+        );
+        assert_eq!(
+            query_hash(&params),
+            "6dec754a70b1a6ea0762981802bb6f855c2ccb47b528fdd6cd656e1ed0ee258196f4d8fedc7fd3eb095ff86b694c6be5922725868d8412bdf118ea935c214667"
+        );
 
-        let mut hasher = Sha512::new();
-        hasher.update(url_parsed.as_bytes());
+        let mut url = Url::parse("https://api.upbit.com/v1/orders").unwrap();
+        params.append_to_url(&mut url);
+
+        assert_eq!(
+            url.query(),
+            Some("market=KRW-BTC&states%5B%5D=done&states%5B%5D=cancel&order_by=desc")
+        );
+        assert_eq!(
+            RequestParams::from_url(url.as_str())
+                .unwrap()
+                .canonical_query(),
+            params.canonical_query()
+        );
+    }
 
-        let hasher_hex = format!("{:x}", hasher.finalize());
-        let alg = Algorithm::new_hmac(AlgorithmID::HS256, secret_key)
-            .map_err(response_error_internal_hmac_error)?;
+    #[tokio::test]
+    async fn test_sign() {
+        let credentials = Credentials::new("access key", "secret key");
+        let mut params = RequestParams::new();
+        params.append_array("states", ["done", "cancel"]);
 
-        let header = json!({
-            "alg": alg.name()
-        });
+        let (with_params, without_params) = credential::scope(credentials, async {
+            (sign(&params).unwrap(), sign(&RequestParams::new()).unwrap())
+        })
+        .await;
 
-        let payload = json!({
-            "access_key": access_key,
-            "nonce": Uuid::new_v4(),
-            "query_hash": hasher_hex,
-            "query_hash_alg": "SHA512",
-        });
+        let alg = Algorithm::new_hmac(AlgorithmID::HS256, "secret key").unwrap();
+        let verifier = jwt::Verifier::create().build().unwrap();
 
-        let token = jwt::encode(&header, &payload, &alg)
-            .map_err(response_error_internal_token_encode_error)?;
+        let claims = verifier
+            .verify(with_params.trim_start_matches("Bearer "), &alg)
+            .unwrap();
+        assert_eq!(claims["access_key"], "access key");
+        assert_eq!(
+            claims["query_hash"],
+            "5cb9b99982a49a203e4e43489719a9f8ec468a395dc5a6d9e48ca2810ddf5d400e2b29688da8e974ae1505d37d73de7199471227d639caf2b3a39b979338bc65"
+        );
+        assert_eq!(claims["query_hash_alg"], "SHA512");
 
-        Ok(format!("Bearer {token}"))
+        let claims = verifier
+            .verify(without_params.trim_start_matches("Bearer "), &alg)
+            .unwrap();
+        assert!(claims.get("query_hash").is_none());
     }
 }
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::request::Request;
+
+/// Deserialized API key data
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub access_key: String,
+
+    #[cfg(feature = "chrono")]
+    pub expire_at: chrono::NaiveDateTime,
+    #[cfg(not(any(feature = "chrono")))]
+    pub expire_at: String,
+
+    expire_at_timestamp: Option<i64>,
+}
+
+impl Request for ApiKey {}
+
+impl ApiKey {
+    /// Time left until the key expires, `None` if it is already expired
+    pub fn expires_in(&self) -> Option<Duration> {
+        let expires_in = self.expire_at_timestamp? - crate::date::now_secs();
+
+        u64::try_from(expires_in).ok().map(Duration::from_secs)
+    }
+
+    /// Whether the key is expired or expires within `days` days
+    pub fn expires_within(&self, days: u64) -> bool {
+        self.expires_in()
+            .is_none_or(|expires_in| expires_in.as_secs() <= days * 24 * 60 * 60)
+    }
+}
+
+/// Raw API key from serialized data
+#[derive(Deserialize)]
+pub struct ApiKeySource {
+    access_key: String,
+    expire_at: String,
+}
+
+impl ApiKeySource {
+    /// Get access_key
+    pub fn access_key(&self) -> String {
+        self.access_key.clone()
+    }
+
+    #[cfg(not(any(feature = "chrono")))]
+    /// Get expire_at
+    pub fn expire_at(&self) -> String {
+        self.expire_at.clone()
+    }
+
+    #[cfg(feature = "chrono")]
+    /// Convert [String] expire_at value into [chrono::NaiveDateTime]
+    pub fn expire_at(&self) -> chrono::NaiveDateTime {
+        chrono::DateTime::parse_from_rfc3339(&self.expire_at)
+            .map(|dt| dt.naive_local())
+            .unwrap()
+    }
+}
+
+impl From<ApiKeySource> for ApiKey {
+    fn from(x: ApiKeySource) -> Self {
+        Self {
+            access_key: x.access_key(),
+            expire_at: x.expire_at(),
+            expire_at_timestamp: crate::date::parse_rfc3339(&x.expire_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_key_expiry() {
+        let api_key = ApiKey::from(ApiKeySource {
+            access_key: "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_owned(),
+            expire_at: "2021-03-24T11:26:40+09:00".to_owned(),
+        });
+
+        assert_eq!(api_key.expires_in(), None);
+        assert!(api_key.expires_within(30));
+    }
+}
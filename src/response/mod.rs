@@ -1,5 +1,9 @@
 /// Module of account info
 mod accounts_info;
+/// Module of API key info
+mod api_key;
+/// Module of order cancel and new
+mod order_cancel_and_new;
 /// Module of order chance
 mod order_chance;
 /// Module of order info
@@ -10,10 +14,27 @@ mod order_status;
 mod response_error;
 /// Module of transform info
 mod transaction_info;
+/// Module of travel rule verification
+mod travel_rule;
+/// Module of wallet status
+mod wallet_status;
+/// Module of withdrawal and deposit records
+mod transfer_record;
+/// Module of withdrawal and deposit history sync
+mod transfer_history;
+/// Module of KRW transfer two factor tracking
+mod two_factor_tracker;
 
 pub use accounts_info::*;
+pub use api_key::*;
+pub use order_cancel_and_new::*;
 pub use order_chance::*;
 pub use order_info::*;
 pub use order_status::*;
 pub use response_error::*;
 pub use transaction_info::*;
+pub use travel_rule::*;
+pub use wallet_status::*;
+pub use transfer_record::*;
+pub use transfer_history::*;
+pub use two_factor_tracker::*;
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::request::RequestWithQuery;
+
+use super::order_info::*;
+
+/// Deserialized OrderCancelAndNew data
+///
+/// `order_info` describes the canceled order.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OrderCancelAndNew {
+    #[serde(flatten)]
+    pub order_info: OrderInfo,
+    pub new_order_uuid: String,
+    pub new_order_identifier: Option<String>,
+}
+
+impl RequestWithQuery for OrderCancelAndNew {}
+
+/// Raw OrderCancelAndNewSource from serialized data
+#[derive(Deserialize)]
+pub struct OrderCancelAndNewSource {
+    #[serde(flatten)]
+    pub order_info: OrderInfoSource,
+    pub new_order_uuid: String,
+    pub new_order_identifier: Option<String>,
+}
@@ -4,7 +4,7 @@ use super::order_info::*;
 use serde::{Deserialize, Serialize};
 
 /// Deserialized ObjectTrades data of [OrderStatus].
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ObjectTrades {
     pub market: String,
     pub uuid: String,
@@ -20,7 +20,7 @@ pub struct ObjectTrades {
 }
 
 /// Deserialized OrderStatus data
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OrderStatus {
     #[serde(flatten)]
     pub order_info: OrderInfo,
@@ -2,7 +2,7 @@ use serde::Deserialize;
 use serde_json::Error;
 
 /// List of response error item
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ResponseErrorState {
     /// "internal_reqwest_error"
     InternalReqwestError,
@@ -18,8 +18,18 @@ pub enum ResponseErrorState {
     InternalDateFormatParseError,
     /// "custom_error_no_data_present"
     CustomErrorNoDataPresent,
+    /// "custom_error_withdrawal_rejected"
+    CustomErrorWithdrawalRejected,
+    /// "custom_error_timeout"
+    CustomErrorTimeout,
     /// "number parse error"
     InternalNumParseError,
+    /// "internal_io_error"
+    InternalIoError,
+    /// "internal_credential_error"
+    InternalCredentialError,
+    /// "internal_transport_error"
+    InternalTransportError,
     /// "JWT 헤더의 페이로드가 올바르지 않습니다."
     ///
     /// "서명에 사용한 페이로드 값을 확인해주세요."
@@ -94,7 +104,12 @@ impl From<&str> for ResponseErrorState {
             "internal_url_parse_error" => Self::InternalUrlParseError,
             "internal_date_format_parse_error" => Self::InternalDateFormatParseError,
             "internal_num_parse_error" => Self::InternalNumParseError,
+            "internal_io_error" => Self::InternalIoError,
+            "internal_credential_error" => Self::InternalCredentialError,
+            "internal_transport_error" => Self::InternalTransportError,
             "custom_error_no_data_present" => Self::CustomErrorNoDataPresent,
+            "custom_error_withdrawal_rejected" => Self::CustomErrorWithdrawalRejected,
+            "custom_error_timeout" => Self::CustomErrorTimeout,
             "jwt_verification" => Self::JwtVerificationError,
             "expired_access_key" => Self::ExpiredAccessKey,
             "invalid_query_payload" => Self::InvalidQueryPayload,
@@ -168,6 +183,9 @@ pub fn response_error_internal_num_parse_error(e: impl std::fmt::Display) -> Res
 }
 
 pub fn response_error_from_json(e: Error) -> ResponseError {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(error = %e, "failed to parse response");
+
     ResponseError {
         state: ResponseErrorState::InternalJsonParseError,
         error: ResponseErrorBody {
@@ -228,3 +246,78 @@ pub fn response_error_internal_date_format_parse_error(
         },
     }
 }
+
+pub fn response_error_internal_io_error(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError {
+        state: ResponseErrorState::InternalIoError,
+        error: ResponseErrorBody {
+            name: "internal_io_error".to_owned(),
+            message: error.to_string(),
+        },
+    }
+}
+
+pub fn response_error_internal_credential_error(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError {
+        state: ResponseErrorState::InternalCredentialError,
+        error: ResponseErrorBody {
+            name: "internal_credential_error".to_owned(),
+            message: error.to_string(),
+        },
+    }
+}
+
+pub fn response_error_internal_transport_error(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError {
+        state: ResponseErrorState::InternalTransportError,
+        error: ResponseErrorBody {
+            name: "internal_transport_error".to_owned(),
+            message: error.to_string(),
+        },
+    }
+}
+
+pub fn response_error_custom_no_data_present(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError {
+        state: ResponseErrorState::CustomErrorNoDataPresent,
+        error: ResponseErrorBody {
+            name: "custom_error_no_data_present".to_owned(),
+            message: error.to_string(),
+        },
+    }
+}
+
+pub fn response_error_custom_withdrawal_rejected(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError {
+        state: ResponseErrorState::CustomErrorWithdrawalRejected,
+        error: ResponseErrorBody {
+            name: "custom_error_withdrawal_rejected".to_owned(),
+            message: error.to_string(),
+        },
+    }
+}
+
+pub fn response_error_custom_timeout(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError {
+        state: ResponseErrorState::CustomErrorTimeout,
+        error: ResponseErrorBody {
+            name: "custom_error_timeout".to_owned(),
+            message: error.to_string(),
+        },
+    }
+}
+
+/// Error of a non-2xx response whose body is not an error of the API, such as an HTML page of a proxy
+pub fn response_error_http_status(status: reqwest::StatusCode, body: &str) -> ResponseError {
+    ResponseError {
+        state: if status.is_server_error() {
+            ResponseErrorState::ServerError
+        } else {
+            ResponseErrorState::UnexpectedError
+        },
+        error: ResponseErrorBody {
+            name: status.to_string(),
+            message: body.to_owned(),
+        },
+    }
+}
@@ -1,165 +1,15 @@
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 
 use crate::{
     api_deposit::DepositState,
+    api_withdraw::WithdrawState,
     constant::{TransactionType, TransferType},
     request::{Request, RequestWithQuery},
 };
 
 use super::{AccountsInfo, AccountsInfoSource};
 
-/// Deserialized derived TransactionInfoDerived data
-#[derive(Serialize, Deserialize, Debug)]
-pub struct TransactionInfoDerived {
-    pub r#type: TransferType,
-    pub uuid: String,
-    pub currency: String,
-    pub net_type: Option<String>,
-    pub txid: String,
-    pub state: DepositState,
-
-    #[cfg(feature = "chrono")]
-    pub created_at: chrono::NaiveDateTime,
-    #[cfg(not(any(feature = "chrono")))]
-    pub created_at: String,
-
-    #[cfg(feature = "chrono")]
-    pub done_at: Option<chrono::NaiveDateTime>,
-    #[cfg(not(any(feature = "chrono")))]
-    pub done_at: Option<String>,
-
-    pub amount: f64,
-    pub fee: f64,
-    // pub krw_amount: f64,
-    pub transaction_type: TransactionType,
-}
-
-/// Raw derived withdraw info from serialized data
-#[derive(Deserialize)]
-pub struct TransactionInfoDerivedSource {
-    r#type: String,
-    uuid: String,
-    currency: String,
-    net_type: Option<String>,
-    txid: String,
-    state: String,
-    created_at: String,
-    done_at: Option<String>,
-    amount: String,
-    fee: String,
-    // krw_amount: String,
-    transaction_type: String,
-}
-
-impl TransactionInfoDerivedSource {
-    /// Convert [String] type value into [TransactionType]
-    pub fn r#type(&self) -> TransferType {
-        self.r#type.as_str().into()
-    }
-    /// Get uuid
-    pub fn uuid(&self) -> String {
-        self.uuid.clone()
-    }
-    /// Get currency
-    pub fn currency(&self) -> String {
-        self.currency.clone()
-    }
-    /// Get net_type
-    pub fn net_type(&self) -> Option<String> {
-        self.net_type.clone().or(None)
-    }
-    /// Get txid
-    pub fn txid(&self) -> String {
-        self.txid.clone()
-    }
-    /// Convert [String] state value into [DepositState]
-    pub fn state(&self) -> DepositState {
-        self.state.as_str().into()
-    }
-
-    #[cfg(not(any(feature = "chrono")))]
-    /// Convert [String] created_at value into [chrono::NaiveDateTime]
-    pub fn created_at(&self) -> String {
-        self.created_at.clone()
-    }
-
-    #[cfg(feature = "chrono")]
-    pub fn created_at(&self) -> chrono::NaiveDateTime {
-        chrono::DateTime::parse_from_rfc3339(&self.created_at).map(|dt| dt.naive_local()).unwrap()
-    }
-
-    #[cfg(not(any(feature = "chrono")))]
-    /// Convert [String] done_at value into [chrono::NaiveDateTime]
-    pub fn done_at(&self) -> Option<String> {
-        self.done_at.clone().or(None)
-    }
-
-    #[cfg(feature = "chrono")]
-    pub fn done_at(&self) -> Option<chrono::NaiveDateTime> {
-        chrono::NaiveDateTime::parse_from_str(
-            &self.done_at.clone().or(None)?,
-            "%Y-%m-%dT%H:%M:%S%z",
-        )
-        .ok()
-    }
-    
-    /// Convert [String] amount value into [f64]
-    pub fn amount(&self) -> f64 {
-        self.amount.parse().unwrap()
-    }
-    /// Convert [String] fee value into [f64]
-    pub fn fee(&self) -> f64 {
-        self.fee.parse().unwrap()
-    }
-    /// Convert [String] krw_amount value into [f64]
-    // pub fn krw_amount(&self) -> f64 { self.krw_amount.parse().unwrap() }
-    /// Convert [String] transaction_type value into [WithdrawType]
-    pub fn transaction_type(&self) -> TransactionType {
-        self.transaction_type.as_str().into()
-    }
-}
-
-/// Deserialized TransactionInfo data
-#[derive(Debug)]
-pub struct TransactionInfo {
-    pub r#type: TransferType,
-    pub uuid: String,
-    pub currency: String,
-    pub net_type: Option<String>,
-    pub txid: String,
-    pub state: DepositState,
-
-    #[cfg(feature = "chrono")]
-    pub created_at: chrono::NaiveDateTime,
-    #[cfg(not(any(feature = "chrono")))]
-    pub created_at: String,
-
-    #[cfg(feature = "chrono")]
-    pub done_at: Option<chrono::NaiveDateTime>,
-    #[cfg(not(any(feature = "chrono")))]
-    pub done_at: Option<String>,
-
-    pub amount: f64,
-    pub fee: f64,
-    pub transaction_type: TransactionType,
-
-    pub holder: Option<String>,
-    pub bank: Option<String>,
-    pub fiat_amount: Option<String>,
-    pub memo: Option<String>,
-    pub fiat_currency: Option<String>,
-    pub confirmations: Option<String>,
-    pub krw_amount: Option<String>,
-    pub network_name: Option<String>,
-    pub cancelable: Option<String>,
-    pub blockchain_url: Option<String>,
-    pub state_i18n: Option<String>,
-    pub address: Option<String>,
-}
-
-impl RequestWithQuery for TransactionInfo {}
-
-/// Raw withdraw info from serialized data
+/// Raw withdraw or deposit info from serialized data
 #[derive(Deserialize)]
 pub struct TransactionInfoSource {
     r#type: String,
@@ -182,7 +32,8 @@ pub struct TransactionInfoSource {
     confirmations: Option<String>,
     krw_amount: Option<String>,
     network_name: Option<String>,
-    cancelable: Option<String>,
+    #[serde(default, alias = "is_cancelable")]
+    cancelable: Option<serde_json::Value>,
     blockchain_url: Option<String>,
     state_i18n: Option<String>,
     address: Option<String>,
@@ -209,9 +60,13 @@ impl TransactionInfoSource {
     pub fn txid(&self) -> String {
         self.txid.clone()
     }
-    
+
+    /// Convert [String] state value into [WithdrawState]
+    pub fn withdraw_state(&self) -> WithdrawState {
+        self.state.as_str().into()
+    }
     /// Convert [String] state value into [DepositState]
-    pub fn state(&self) -> DepositState {
+    pub fn deposit_state(&self) -> DepositState {
         self.state.as_str().into()
     }
 
@@ -277,8 +132,13 @@ impl TransactionInfoSource {
     pub fn network_name(&self) -> Option<String> {
         self.network_name.clone()
     }
-    pub fn cancelable(&self) -> Option<String> {
-        self.cancelable.clone()
+    /// Convert `cancelable` value, which may come as [bool] or [String], into [bool]
+    pub fn cancelable(&self) -> bool {
+        match &self.cancelable {
+            Some(serde_json::Value::Bool(cancelable)) => *cancelable,
+            Some(serde_json::Value::String(cancelable)) => cancelable == "true",
+            _ => false,
+        }
     }
     pub fn blockchain_url(&self) -> Option<String> {
         self.blockchain_url.clone()
@@ -492,3 +352,38 @@ pub struct CoinAddressResponse {
 
 impl Request for CoinAddressResponse {}
 impl RequestWithQuery for CoinAddressResponse {}
+
+/// Deposit address ready to receive coins
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepositAddress {
+    pub currency: String,
+    pub net_type: String,
+    pub deposit_address: String,
+    /// Memo or destination tag, required by some currencies. ex) XRP, EOS
+    pub secondary_address: Option<String>,
+}
+
+impl DepositAddress {
+    /// Returns [None] if the address has not been created yet
+    pub fn from_coin_address(coin_address: CoinAddressResponse) -> Option<Self> {
+        Some(Self {
+            currency: coin_address.currency,
+            net_type: coin_address.net_type,
+            deposit_address: coin_address.deposit_address?,
+            secondary_address: coin_address.secondary_address,
+        })
+    }
+
+    /// Returns [None] if the address is still being created
+    pub fn from_coin_address_gen(coin_address_gen: CoinAddressGen, net_type: &str) -> Option<Self> {
+        match coin_address_gen.response {
+            CoinAddressGenResponse::First(_) => None,
+            CoinAddressGenResponse::Second(x) => Some(Self {
+                currency: x.currency,
+                net_type: x.net_type.unwrap_or_else(|| net_type.to_owned()),
+                deposit_address: x.deposit_address,
+                secondary_address: x.secondary_address,
+            }),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::future::Future;
+
+use serde::{Deserialize, Serialize};
+
+use super::{AccountsInfo, ResponseError, Transfer};
+
+/// Maximum number of records accepted by a single list request
+const RECORDS_PER_REQUEST: u32 = 100;
+
+/// Transfer records listable page by page with the `from` cursor
+pub trait TransferListing: Transfer + Sized {
+    type State: Copy + Display + 'static;
+
+    /// Every state synced by [TransferHistory], in the order they are listed
+    const STATES: &'static [Self::State];
+
+    /// List the records of `state` created after the record of uuid `from`, oldest first
+    fn list_from(
+        currency: &str,
+        state: Self::State,
+        from: Option<&str>,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>>;
+}
+
+/// Last record synced by [TransferHistory] for each currency and state
+///
+/// Store it after the records are processed and pass it back to resume the sync from there.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferCheckpoint {
+    cursors: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl TransferCheckpoint {
+    /// uuid of the last record synced for `currency` in `state`
+    pub fn cursor(&self, currency: &str, state: &str) -> Option<&str> {
+        self.cursors
+            .get(currency)
+            .and_then(|states| states.get(state))
+            .map(String::as_str)
+    }
+
+    fn advance(&mut self, currency: &str, state: &str, uuid: &str) {
+        self.cursors
+            .entry(currency.to_owned())
+            .or_default()
+            .insert(state.to_owned(), uuid.to_owned());
+    }
+}
+
+/// Async stream of every transfer of the given currencies, across every state
+///
+/// Records are listed oldest first with the `from` cursor, one currency and state at a time,
+/// and yielded once per uuid. A transfer is yielded in the state it had when listed;
+/// a later sync from the checkpoint yields it again once it reaches another state.
+pub struct TransferHistory<R: TransferListing> {
+    currencies: Option<VecDeque<String>>,
+    state_index: usize,
+    from: Option<String>,
+    exhausted: bool,
+    buffer: VecDeque<R>,
+    seen: HashSet<String>,
+    checkpoint: TransferCheckpoint,
+}
+
+impl<R: TransferListing> TransferHistory<R> {
+    /// Sync `currency`, or every currency of [AccountsInfo::get_account_info] if `None`, from `checkpoint`
+    pub fn new(currency: Option<&str>, checkpoint: TransferCheckpoint) -> Self {
+        Self {
+            currencies: currency.map(|currency| VecDeque::from([currency.to_owned()])),
+            state_index: 0,
+            from: None,
+            exhausted: false,
+            buffer: VecDeque::new(),
+            seen: HashSet::new(),
+            checkpoint,
+        }
+    }
+
+    /// Checkpoint covering every record yielded so far
+    pub fn checkpoint(&self) -> &TransferCheckpoint {
+        &self.checkpoint
+    }
+
+    /// Next record, or `None` once every currency and state is synced
+    pub async fn next(&mut self) -> Result<Option<R>, ResponseError> {
+        if self.currencies.is_none() {
+            let accounts = AccountsInfo::get_account_info().await?;
+
+            self.currencies = Some(
+                accounts
+                    .into_iter()
+                    .map(|account| account.currency)
+                    .collect(),
+            );
+        }
+
+        loop {
+            if let Some(record) = self.pop() {
+                return Ok(Some(record));
+            }
+
+            let Some(currency) = self.currencies.as_ref().and_then(|x| x.front()).cloned() else {
+                return Ok(None);
+            };
+
+            let state = R::STATES[self.state_index];
+
+            if self.from.is_none() {
+                self.from = self
+                    .checkpoint
+                    .cursor(&currency, &state.to_string())
+                    .map(str::to_owned);
+            }
+
+            let page =
+                R::list_from(&currency, state, self.from.as_deref(), RECORDS_PER_REQUEST).await?;
+
+            self.push_page(page);
+        }
+    }
+
+    /// Yield the next buffered record, moving to the next state or currency once the current one is drained
+    fn pop(&mut self) -> Option<R> {
+        loop {
+            if let Some(record) = self.buffer.pop_front() {
+                let state = R::STATES[self.state_index].to_string();
+                self.checkpoint
+                    .advance(record.currency(), &state, record.uuid());
+
+                return Some(record);
+            }
+
+            if !self.exhausted {
+                return None;
+            }
+
+            self.exhausted = false;
+            self.from = None;
+            self.state_index += 1;
+
+            if self.state_index == R::STATES.len() {
+                self.state_index = 0;
+                self.currencies.as_mut()?.pop_front();
+            }
+        }
+    }
+
+    fn push_page(&mut self, page: Vec<R>) {
+        let last = page.last().map(|record| record.uuid().to_owned());
+
+        self.exhausted = page.len() < RECORDS_PER_REQUEST as usize || last == self.from;
+        self.from = last.or(self.from.take());
+        self.buffer.extend(
+            page.into_iter()
+                .filter(|record| self.seen.insert(record.uuid().to_owned())),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_withdraw::WithdrawState;
+    use crate::response::{TransactionInfoSource, WithdrawalRecord};
+
+    use super::*;
+
+    fn record(uuid: &str, state: &str) -> WithdrawalRecord {
+        serde_json::from_value::<TransactionInfoSource>(serde_json::json!({
+            "type": "withdraw",
+            "uuid": uuid,
+            "currency": "XRP",
+            "net_type": "XRP",
+            "txid": "98c15999f0bdc4ae0e8a-ed35868bb0c204fe6ec29e4058a3451e-88636d1040f4baddf943274ce37cf9cc",
+            "state": state,
+            "created_at": "2019-02-28T15:17:51+09:00",
+            "done_at": null,
+            "amount": "1.00",
+            "fee": "0.0",
+            "transaction_type": "default",
+        }))
+        .map(WithdrawalRecord::from)
+        .unwrap()
+    }
+
+    #[test]
+    fn test_history_walks_states_and_dedups() {
+        let mut history =
+            TransferHistory::<WithdrawalRecord>::new(Some("XRP"), TransferCheckpoint::default());
+
+        history.push_page(vec![record("w1", "WAITING"), record("w2", "WAITING")]);
+        assert_eq!(history.pop().unwrap().uuid, "w1");
+        assert_eq!(history.pop().unwrap().uuid, "w2");
+        assert!(history.pop().is_none());
+        assert_eq!(history.state_index, 1);
+        assert_eq!(
+            history
+                .checkpoint()
+                .cursor("XRP", &WithdrawState::Waiting.to_string()),
+            Some("w2")
+        );
+
+        history.push_page(vec![record("w2", "PROCESSING"), record("w3", "PROCESSING")]);
+        assert_eq!(history.pop().unwrap().uuid, "w3");
+        assert!(history.pop().is_none());
+        assert_eq!(
+            history
+                .checkpoint()
+                .cursor("XRP", &WithdrawState::Processing.to_string()),
+            Some("w3")
+        );
+
+        for _ in 2..WithdrawalRecord::STATES.len() {
+            history.push_page(Vec::new());
+            assert!(history.pop().is_none());
+        }
+
+        assert!(history.currencies.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let mut checkpoint = TransferCheckpoint::default();
+        checkpoint.advance("KRW", "ACCEPTED", "94332e99-3a87-4a35-ad98-28b0c969f830");
+
+        let serialized = serde_json::to_string(&checkpoint).unwrap();
+        let deserialized = serde_json::from_str::<TransferCheckpoint>(&serialized).unwrap();
+
+        assert_eq!(deserialized, checkpoint);
+        assert_eq!(
+            deserialized.cursor("KRW", "ACCEPTED"),
+            Some("94332e99-3a87-4a35-ad98-28b0c969f830")
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api_deposit::DepositState,
+    api_withdraw::WithdrawState,
+    constant::{TransactionType, TransferType},
+    request::RequestWithQuery,
+};
+
+use super::TransactionInfoSource;
+
+/// Fields shared by [WithdrawalRecord] and [DepositRecord]
+pub trait Transfer {
+    fn r#type(&self) -> TransferType;
+    fn uuid(&self) -> &str;
+    fn currency(&self) -> &str;
+    fn net_type(&self) -> Option<&str>;
+    fn txid(&self) -> &str;
+
+    #[cfg(feature = "chrono")]
+    fn created_at(&self) -> chrono::NaiveDateTime;
+    #[cfg(not(any(feature = "chrono")))]
+    fn created_at(&self) -> &str;
+
+    #[cfg(feature = "chrono")]
+    fn done_at(&self) -> Option<chrono::NaiveDateTime>;
+    #[cfg(not(any(feature = "chrono")))]
+    fn done_at(&self) -> Option<&str>;
+
+    fn amount(&self) -> f64;
+    fn fee(&self) -> f64;
+    fn transaction_type(&self) -> TransactionType;
+}
+
+macro_rules! impl_transfer {
+    ($record:ty) => {
+        impl Transfer for $record {
+            fn r#type(&self) -> TransferType {
+                self.r#type
+            }
+            fn uuid(&self) -> &str {
+                &self.uuid
+            }
+            fn currency(&self) -> &str {
+                &self.currency
+            }
+            fn net_type(&self) -> Option<&str> {
+                self.net_type.as_deref()
+            }
+            fn txid(&self) -> &str {
+                &self.txid
+            }
+
+            #[cfg(feature = "chrono")]
+            fn created_at(&self) -> chrono::NaiveDateTime {
+                self.created_at
+            }
+            #[cfg(not(any(feature = "chrono")))]
+            fn created_at(&self) -> &str {
+                &self.created_at
+            }
+
+            #[cfg(feature = "chrono")]
+            fn done_at(&self) -> Option<chrono::NaiveDateTime> {
+                self.done_at
+            }
+            #[cfg(not(any(feature = "chrono")))]
+            fn done_at(&self) -> Option<&str> {
+                self.done_at.as_deref()
+            }
+
+            fn amount(&self) -> f64 {
+                self.amount
+            }
+            fn fee(&self) -> f64 {
+                self.fee
+            }
+            fn transaction_type(&self) -> TransactionType {
+                self.transaction_type
+            }
+        }
+    };
+}
+
+/// Deserialized withdrawal data
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WithdrawalRecord {
+    pub r#type: TransferType,
+    pub uuid: String,
+    pub currency: String,
+    pub net_type: Option<String>,
+    pub txid: String,
+    pub state: WithdrawState,
+
+    #[cfg(feature = "chrono")]
+    pub created_at: chrono::NaiveDateTime,
+    #[cfg(not(any(feature = "chrono")))]
+    pub created_at: String,
+
+    #[cfg(feature = "chrono")]
+    pub done_at: Option<chrono::NaiveDateTime>,
+    #[cfg(not(any(feature = "chrono")))]
+    pub done_at: Option<String>,
+
+    pub amount: f64,
+    pub fee: f64,
+    pub transaction_type: TransactionType,
+    pub krw_amount: Option<String>,
+    pub cancelable: bool,
+
+    pub holder: Option<String>,
+    pub bank: Option<String>,
+    pub fiat_amount: Option<String>,
+    pub memo: Option<String>,
+    pub fiat_currency: Option<String>,
+    pub confirmations: Option<String>,
+    pub network_name: Option<String>,
+    pub blockchain_url: Option<String>,
+    pub state_i18n: Option<String>,
+    pub address: Option<String>,
+}
+
+impl RequestWithQuery for WithdrawalRecord {}
+impl_transfer!(WithdrawalRecord);
+
+impl From<TransactionInfoSource> for WithdrawalRecord {
+    fn from(x: TransactionInfoSource) -> Self {
+        Self {
+            r#type: x.r#type(),
+            uuid: x.uuid(),
+            currency: x.currency(),
+            net_type: x.net_type(),
+            txid: x.txid(),
+            state: x.withdraw_state(),
+            created_at: x.created_at(),
+            done_at: x.done_at(),
+            amount: x.amount(),
+            fee: x.fee(),
+            transaction_type: x.transaction_type(),
+            krw_amount: x.krw_amount(),
+            cancelable: x.cancelable(),
+
+            holder: x.holder(),
+            bank: x.bank(),
+            fiat_amount: x.fiat_amount(),
+            memo: x.memo(),
+            fiat_currency: x.fiat_currency(),
+            confirmations: x.confirmations(),
+            network_name: x.network_name(),
+            blockchain_url: x.blockchain_url(),
+            state_i18n: x.state_i18n(),
+            address: x.address(),
+        }
+    }
+}
+
+/// Deserialized deposit data
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DepositRecord {
+    pub r#type: TransferType,
+    pub uuid: String,
+    pub currency: String,
+    pub net_type: Option<String>,
+    pub txid: String,
+    pub state: DepositState,
+
+    #[cfg(feature = "chrono")]
+    pub created_at: chrono::NaiveDateTime,
+    #[cfg(not(any(feature = "chrono")))]
+    pub created_at: String,
+
+    #[cfg(feature = "chrono")]
+    pub done_at: Option<chrono::NaiveDateTime>,
+    #[cfg(not(any(feature = "chrono")))]
+    pub done_at: Option<String>,
+
+    pub amount: f64,
+    pub fee: f64,
+    pub transaction_type: TransactionType,
+    pub krw_amount: Option<String>,
+
+    pub holder: Option<String>,
+    pub bank: Option<String>,
+    pub fiat_amount: Option<String>,
+    pub memo: Option<String>,
+    pub fiat_currency: Option<String>,
+    pub confirmations: Option<String>,
+    pub network_name: Option<String>,
+    pub blockchain_url: Option<String>,
+    pub state_i18n: Option<String>,
+    pub address: Option<String>,
+}
+
+impl RequestWithQuery for DepositRecord {}
+impl_transfer!(DepositRecord);
+
+impl From<TransactionInfoSource> for DepositRecord {
+    fn from(x: TransactionInfoSource) -> Self {
+        Self {
+            r#type: x.r#type(),
+            uuid: x.uuid(),
+            currency: x.currency(),
+            net_type: x.net_type(),
+            txid: x.txid(),
+            state: x.deposit_state(),
+            created_at: x.created_at(),
+            done_at: x.done_at(),
+            amount: x.amount(),
+            fee: x.fee(),
+            transaction_type: x.transaction_type(),
+            krw_amount: x.krw_amount(),
+
+            holder: x.holder(),
+            bank: x.bank(),
+            fiat_amount: x.fiat_amount(),
+            memo: x.memo(),
+            fiat_currency: x.fiat_currency(),
+            confirmations: x.confirmations(),
+            network_name: x.network_name(),
+            blockchain_url: x.blockchain_url(),
+            state_i18n: x.state_i18n(),
+            address: x.address(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(r#type: &str, state: &str) -> TransactionInfoSource {
+        serde_json::from_value(serde_json::json!({
+            "type": r#type,
+            "uuid": "35a4f1dc-1db5-4d6b-89b5-7ec137875956",
+            "currency": "XRP",
+            "net_type": "XRP",
+            "txid": "98c15999f0bdc4ae0e8a-ed35868bb0c204fe6ec29e4058a3451e-88636d1040f4baddf943274ce37cf9cc",
+            "state": state,
+            "created_at": "2019-02-28T15:17:51+09:00",
+            "done_at": null,
+            "amount": "1.00",
+            "fee": "0.0",
+            "transaction_type": "default",
+            "is_cancelable": true,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_withdraw_state_round_trip() {
+        for state in [
+            WithdrawState::Waiting,
+            WithdrawState::Processing,
+            WithdrawState::Done,
+            WithdrawState::Failed,
+            WithdrawState::Canceled,
+            WithdrawState::Rejected,
+        ] {
+            assert_eq!(WithdrawState::from(state.to_string().as_str()), state);
+            assert_eq!(
+                WithdrawState::from(state.to_string().to_lowercase().as_str()),
+                state
+            );
+        }
+
+        assert_eq!(WithdrawState::from("CANCELLED"), WithdrawState::Canceled);
+    }
+
+    #[test]
+    fn test_deposit_state_round_trip() {
+        for state in [
+            DepositState::Done,
+            DepositState::Processing,
+            DepositState::Accepted,
+            DepositState::Canceled,
+            DepositState::Rejected,
+            DepositState::TravelRuleSuspected,
+            DepositState::Refunding,
+            DepositState::Refunded,
+            DepositState::Failed,
+        ] {
+            assert_eq!(DepositState::from(state.to_string().as_str()), state);
+            assert_eq!(
+                DepositState::from(state.to_string().to_lowercase().as_str()),
+                state
+            );
+        }
+    }
+
+    #[test]
+    fn test_records_from_source() {
+        let withdrawal = WithdrawalRecord::from(source("withdraw", "PROCESSING"));
+        assert_eq!(withdrawal.state, WithdrawState::Processing);
+        assert!(withdrawal.cancelable);
+        assert_eq!(Transfer::net_type(&withdrawal), Some("XRP"));
+
+        let deposit = DepositRecord::from(source("deposit", "TRAVEL_RULE_SUSPECTED"));
+        assert_eq!(deposit.state, DepositState::TravelRuleSuspected);
+        assert_eq!(Transfer::amount(&deposit), 1.0);
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api_deposit::DepositState,
+    request::{Request, RequestWithQuery},
+};
+
+/// Virtual asset service provider available for travel rule verification
+#[derive(Deserialize, Debug, Clone)]
+pub struct TravelRuleVasp {
+    pub vasp_name: String,
+    pub vasp_uuid: String,
+    /// Whether deposits from the VASP can be verified
+    pub depositable: bool,
+    /// Whether withdrawals to the VASP are allowed
+    pub withdrawable: bool,
+}
+
+impl Request for TravelRuleVasp {}
+
+/// Result of travel rule verification
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum TravelRuleVerificationResult {
+    /// 검증 성공
+    Verified,
+    /// 검증 실패
+    Failed,
+    /// Result not known to this version of the crate, as sent by the server
+    Unknown(String),
+}
+
+impl std::fmt::Display for TravelRuleVerificationResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Verified => write!(f, "verified"),
+            Self::Failed => write!(f, "failed"),
+            Self::Unknown(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for TravelRuleVerificationResult {
+    fn from(value: &str) -> Self {
+        match value {
+            "verified" => Self::Verified,
+            "failed" => Self::Failed,
+            a => Self::Unknown(a.to_owned()),
+        }
+    }
+}
+
+/// Deserialized travel rule verification data
+#[derive(Debug)]
+pub struct TravelRuleVerification {
+    pub deposit_uuid: String,
+    pub verification_result: TravelRuleVerificationResult,
+    pub deposit_state: DepositState,
+}
+
+impl RequestWithQuery for TravelRuleVerification {}
+
+/// Raw travel rule verification from serialized data
+#[derive(Deserialize)]
+pub struct TravelRuleVerificationSource {
+    deposit_uuid: String,
+    verification_result: String,
+    deposit_state: String,
+}
+
+impl TravelRuleVerificationSource {
+    /// Get deposit_uuid
+    pub fn deposit_uuid(&self) -> String {
+        self.deposit_uuid.clone()
+    }
+    /// Convert [String] verification_result value into [TravelRuleVerificationResult]
+    pub fn verification_result(&self) -> TravelRuleVerificationResult {
+        self.verification_result.as_str().into()
+    }
+    /// Convert [String] deposit_state value into [DepositState]
+    pub fn deposit_state(&self) -> DepositState {
+        self.deposit_state.as_str().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_verification_result() {
+        let result = TravelRuleVerificationResult::from("pending");
+
+        assert_eq!(
+            result,
+            TravelRuleVerificationResult::Unknown("pending".to_owned())
+        );
+        assert_eq!(result.to_string(), "pending");
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt::Display;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use super::{ResponseError, Transfer};
+
+/// Progress of a KRW transfer waiting for the two factor approval
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TwoFactorStatus {
+    /// The approval or the transfer is still in progress
+    Pending,
+    /// The transfer is approved and completed
+    Approved,
+    /// The transfer is rejected, canceled or failed
+    Rejected,
+}
+
+/// KRW transfer records trackable by [TwoFactorTracker]
+pub trait TwoFactorTransfer: Transfer + Clone + Sized {
+    type State: Copy + Display + PartialEq;
+
+    fn state(&self) -> Self::State;
+
+    fn two_factor_status(&self) -> TwoFactorStatus;
+
+    /// Fetch the current record of the transfer of `uuid`
+    fn fetch(uuid: &str) -> impl Future<Output = Result<Self, ResponseError>>;
+}
+
+/// Status event of a KRW transfer registered to [TwoFactorTracker]
+#[derive(Debug, Clone)]
+pub enum TwoFactorEvent<R> {
+    /// The transfer is requested and waits for the approval in Kakao or Naver
+    Requested(R),
+    /// The state of the transfer changed while pending
+    StateChanged(R),
+    /// The transfer is approved and completed
+    Approved(R),
+    /// The transfer is rejected, canceled or failed
+    Rejected(R),
+    /// The transfer is still pending after the timeout
+    TimedOut(R),
+}
+
+/// Tracks a KRW transfer requested with a [crate::constant::TwoFactorType] until it is approved, rejected or timed out
+///
+/// Events are pulled with [TwoFactorTracker::next], which polls the transfer every `poll_interval` while it is pending.
+pub struct TwoFactorTracker<R: TwoFactorTransfer> {
+    record: R,
+    requested: bool,
+    finished: bool,
+    poll_interval: Duration,
+    timeout: Duration,
+    started_at: Instant,
+}
+
+impl<R: TwoFactorTransfer> TwoFactorTracker<R> {
+    /// Track the transfer of `record`, returned by the KRW deposit or withdrawal request
+    pub fn new(record: R, poll_interval: Duration, timeout: Duration) -> Self {
+        Self {
+            record,
+            requested: false,
+            finished: false,
+            poll_interval,
+            timeout,
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Last record of the transfer
+    pub fn record(&self) -> &R {
+        &self.record
+    }
+
+    /// Next event, or `None` once the transfer is approved, rejected or timed out
+    pub async fn next(&mut self) -> Result<Option<TwoFactorEvent<R>>, ResponseError> {
+        self.next_with(|uuid| async move { R::fetch(&uuid).await })
+            .await
+    }
+
+    /// [TwoFactorTracker::next] fetching the transfer of a uuid with `fetch` on every poll
+    ///
+    /// Lets the caller wrap each request, e.g. in [crate::account_manager::Account::run].
+    pub async fn next_with<F, Fut>(
+        &mut self,
+        mut fetch: F,
+    ) -> Result<Option<TwoFactorEvent<R>>, ResponseError>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = Result<R, ResponseError>>,
+    {
+        if self.finished {
+            return Ok(None);
+        }
+
+        if !self.requested {
+            self.requested = true;
+
+            return Ok(Some(TwoFactorEvent::Requested(self.record.clone())));
+        }
+
+        loop {
+            if let Some(event) = self.finish() {
+                return Ok(Some(event));
+            }
+
+            let Some(remaining) = self.timeout.checked_sub(self.started_at.elapsed()) else {
+                self.finished = true;
+
+                return Ok(Some(TwoFactorEvent::TimedOut(self.record.clone())));
+            };
+
+            tokio::time::sleep(self.poll_interval.min(remaining)).await;
+
+            let record = fetch(self.record.uuid().to_owned()).await?;
+
+            if let Some(event) = self.apply(record) {
+                return Ok(Some(event));
+            }
+        }
+    }
+
+    /// Poll until the transfer is approved, rejected or timed out, returning every event observed meanwhile
+    pub async fn wait(&mut self) -> Result<Vec<TwoFactorEvent<R>>, ResponseError> {
+        let mut events = Vec::new();
+
+        while let Some(event) = self.next().await? {
+            events.push(event);
+        }
+
+        Ok(events)
+    }
+
+    fn finish(&mut self) -> Option<TwoFactorEvent<R>> {
+        let event = match self.record.two_factor_status() {
+            TwoFactorStatus::Pending => return None,
+            TwoFactorStatus::Approved => TwoFactorEvent::Approved(self.record.clone()),
+            TwoFactorStatus::Rejected => TwoFactorEvent::Rejected(self.record.clone()),
+        };
+
+        self.finished = true;
+
+        Some(event)
+    }
+
+    fn apply(&mut self, record: R) -> Option<TwoFactorEvent<R>> {
+        let changed = record.state() != self.record.state();
+        self.record = record;
+
+        if changed && self.record.two_factor_status() == TwoFactorStatus::Pending {
+            Some(TwoFactorEvent::StateChanged(self.record.clone()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::response::{DepositRecord, TransactionInfoSource, WithdrawalRecord};
+
+    use super::*;
+
+    fn source(r#type: &str, state: &str) -> TransactionInfoSource {
+        serde_json::from_value(serde_json::json!({
+            "type": r#type,
+            "uuid": "9f432943-54e0-40b7-825f-b6fec8b42b79",
+            "currency": "KRW",
+            "net_type": null,
+            "txid": "ebe6937b-130e-4066-8ac6-4b0e67f28adc",
+            "state": state,
+            "created_at": "2018-04-13T11:24:01+09:00",
+            "done_at": null,
+            "amount": "10000.0",
+            "fee": "0.0",
+            "transaction_type": "default",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_withdrawal_two_factor_flow() {
+        let record = WithdrawalRecord::from(source("withdraw", "WAITING"));
+        let mut tracker =
+            TwoFactorTracker::new(record, Duration::from_secs(1), Duration::from_secs(60));
+
+        assert!(tracker.finish().is_none());
+        assert!(matches!(
+            tracker.apply(WithdrawalRecord::from(source("withdraw", "PROCESSING"))),
+            Some(TwoFactorEvent::StateChanged(_))
+        ));
+        assert!(tracker
+            .apply(WithdrawalRecord::from(source("withdraw", "PROCESSING")))
+            .is_none());
+        assert!(tracker
+            .apply(WithdrawalRecord::from(source("withdraw", "DONE")))
+            .is_none());
+        assert!(matches!(
+            tracker.finish(),
+            Some(TwoFactorEvent::Approved(_))
+        ));
+    }
+
+    #[test]
+    fn test_deposit_two_factor_status() {
+        for (state, status) in [
+            ("PROCESSING", TwoFactorStatus::Pending),
+            ("ACCEPTED", TwoFactorStatus::Approved),
+            ("REJECTED", TwoFactorStatus::Rejected),
+            ("CANCELED", TwoFactorStatus::Rejected),
+        ] {
+            assert_eq!(
+                DepositRecord::from(source("deposit", state)).two_factor_status(),
+                status
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_next_with_fetches_each_poll() {
+        let record = WithdrawalRecord::from(source("withdraw", "WAITING"));
+        let mut tracker =
+            TwoFactorTracker::new(record, Duration::from_millis(1), Duration::from_secs(60));
+        let mut states = vec!["DONE", "PROCESSING", "PROCESSING"];
+        let mut fetched = 0;
+
+        let mut last_event = None;
+        while let Some(event) = tracker
+            .next_with(|_| {
+                fetched += 1;
+                let state = states.pop().unwrap();
+                async move { Ok(WithdrawalRecord::from(source("withdraw", state))) }
+            })
+            .await
+            .unwrap()
+        {
+            last_event = Some(event);
+        }
+
+        assert_eq!(fetched, 3);
+        assert!(matches!(last_event, Some(TwoFactorEvent::Approved(_))));
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::request::Request;
+
+/// State of deposits and withdrawals of a wallet
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum WalletState {
+    /// 입출금 가능
+    Working,
+    /// 출금만 가능
+    WithdrawOnly,
+    /// 입금만 가능
+    DepositOnly,
+    /// 입출금 중단
+    Paused,
+    /// 입출금 미지원
+    Unsupported,
+    /// State not known to this version of the crate, as sent by the server
+    Unknown(String),
+}
+
+impl WalletState {
+    pub fn can_deposit(&self) -> bool {
+        matches!(self, Self::Working | Self::DepositOnly)
+    }
+
+    pub fn can_withdraw(&self) -> bool {
+        matches!(self, Self::Working | Self::WithdrawOnly)
+    }
+}
+
+impl Display for WalletState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Working => write!(f, "working"),
+            Self::WithdrawOnly => write!(f, "withdraw_only"),
+            Self::DepositOnly => write!(f, "deposit_only"),
+            Self::Paused => write!(f, "paused"),
+            Self::Unsupported => write!(f, "unsupported"),
+            Self::Unknown(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for WalletState {
+    fn from(value: &str) -> Self {
+        match value {
+            "working" => Self::Working,
+            "withdraw_only" => Self::WithdrawOnly,
+            "deposit_only" => Self::DepositOnly,
+            "paused" => Self::Paused,
+            "unsupported" => Self::Unsupported,
+            a => Self::Unknown(a.to_owned()),
+        }
+    }
+}
+
+/// State of block synchronization of a wallet
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum BlockState {
+    /// 정상
+    Normal,
+    /// 지연
+    Delayed,
+    /// 비활성
+    Inactive,
+    /// State not known to this version of the crate, as sent by the server
+    Unknown(String),
+}
+
+impl Display for BlockState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::Delayed => write!(f, "delayed"),
+            Self::Inactive => write!(f, "inactive"),
+            Self::Unknown(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for BlockState {
+    fn from(value: &str) -> Self {
+        match value {
+            "normal" => Self::Normal,
+            "delayed" => Self::Delayed,
+            "inactive" => Self::Inactive,
+            a => Self::Unknown(a.to_owned()),
+        }
+    }
+}
+
+/// Deserialized wallet status data
+#[derive(Debug, Clone)]
+pub struct WalletStatus {
+    pub currency: String,
+    pub wallet_state: WalletState,
+    pub block_state: Option<BlockState>,
+    pub block_height: Option<i64>,
+
+    #[cfg(feature = "chrono")]
+    pub block_updated_at: Option<chrono::NaiveDateTime>,
+    #[cfg(not(any(feature = "chrono")))]
+    pub block_updated_at: Option<String>,
+
+    pub block_elapsed_minutes: Option<i64>,
+    pub net_type: String,
+    pub network_name: String,
+}
+
+impl Request for WalletStatus {}
+
+/// Raw wallet status from serialized data
+#[derive(Deserialize)]
+pub struct WalletStatusSource {
+    currency: String,
+    wallet_state: String,
+    block_state: Option<String>,
+    block_height: Option<i64>,
+    block_updated_at: Option<String>,
+    block_elapsed_minutes: Option<i64>,
+    net_type: String,
+    network_name: String,
+}
+
+impl WalletStatusSource {
+    /// Get currency
+    pub fn currency(&self) -> String {
+        self.currency.clone()
+    }
+    /// Convert [String] wallet_state value into [WalletState]
+    pub fn wallet_state(&self) -> WalletState {
+        self.wallet_state.as_str().into()
+    }
+    /// Convert [String] block_state value into [BlockState]
+    pub fn block_state(&self) -> Option<BlockState> {
+        self.block_state.as_deref().map(BlockState::from)
+    }
+    /// Get block_height
+    pub fn block_height(&self) -> Option<i64> {
+        self.block_height
+    }
+
+    #[cfg(not(any(feature = "chrono")))]
+    /// Get block_updated_at
+    pub fn block_updated_at(&self) -> Option<String> {
+        self.block_updated_at.clone()
+    }
+
+    #[cfg(feature = "chrono")]
+    /// Convert [String] block_updated_at value into [chrono::NaiveDateTime]
+    pub fn block_updated_at(&self) -> Option<chrono::NaiveDateTime> {
+        chrono::DateTime::parse_from_rfc3339(self.block_updated_at.as_deref()?)
+            .map(|dt| dt.naive_local())
+            .ok()
+    }
+
+    /// Get block_elapsed_minutes
+    pub fn block_elapsed_minutes(&self) -> Option<i64> {
+        self.block_elapsed_minutes
+    }
+    /// Get net_type
+    pub fn net_type(&self) -> String {
+        self.net_type.clone()
+    }
+    /// Get network_name
+    pub fn network_name(&self) -> String {
+        self.network_name.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_states() {
+        let wallet_state = WalletState::from("maintenance");
+        let block_state = BlockState::from("syncing");
+
+        assert_eq!(wallet_state, WalletState::Unknown("maintenance".to_owned()));
+        assert!(!wallet_state.can_deposit());
+        assert!(!wallet_state.can_withdraw());
+        assert_eq!(wallet_state.to_string(), "maintenance");
+        assert_eq!(block_state, BlockState::Unknown("syncing".to_owned()));
+        assert_eq!(block_state.to_string(), "syncing");
+    }
+}
@@ -0,0 +1,336 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, StatusCode};
+use serde::Serialize;
+
+use crate::constant::URL_SERVER;
+use crate::response::{
+    response_error_from_reqwest, response_error_internal_transport_error, ResponseError,
+};
+
+/// Transport installed by [set_transport]
+static TRANSPORT: RwLock<Option<Arc<dyn HttpTransport>>> = RwLock::new(None);
+
+/// Base URL installed by [set_base_url]
+static BASE_URL: RwLock<Option<String>> = RwLock::new(None);
+
+/// Client of every [ReqwestTransport] built without its own client
+static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+tokio::task_local! {
+    /// Transport of the future run by [scope]
+    static SCOPED_TRANSPORT: Arc<dyn HttpTransport>;
+    /// Client of the future run by [scope_default_client]
+    static SCOPED_CLIENT: reqwest::Client;
+    /// Base URL of the future run by [scope_base_url]
+    static SCOPED_BASE_URL: String;
+}
+
+/// Future returned by [HttpTransport::send]
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<HttpResponse, ResponseError>> + Send + 'a>>;
+
+/// Request sent by an [HttpTransport]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(HeaderName, String)>,
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::GET, url)
+    }
+
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new(Method::POST, url)
+    }
+
+    pub fn delete(url: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, url)
+    }
+
+    pub fn header(mut self, name: HeaderName, value: impl AsRef<str>) -> Self {
+        self.headers.push((name, value.as_ref().to_owned()));
+        self
+    }
+
+    /// Set `body` serialized as the body of the request
+    pub fn json(mut self, body: &impl Serialize) -> Self {
+        self.body = serde_json::to_string(body).ok();
+        self
+    }
+
+    /// Send the request with the installed [HttpTransport], calling the added [crate::middleware::Middleware]s
+    ///
+    /// A url under [URL_SERVER] is sent under the base URL of the current [scope_base_url] or [set_base_url] instead.
+    pub async fn send(mut self) -> Result<HttpResponse, ResponseError> {
+        self.url = rebase(self.url);
+
+        #[cfg(feature = "tracing")]
+        return crate::instrument::send(self, transport()).await;
+
+        #[cfg(not(feature = "tracing"))]
+        crate::middleware::send(self, transport()).await
+    }
+}
+
+/// Response received by an [HttpTransport], with the whole body read
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn new(status: StatusCode, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn text(self) -> String {
+        self.body
+    }
+}
+
+/// HTTP backend sending the request of every API
+///
+/// Install one with [set_transport] to send requests through another client, a proxy enforcing an egress policy,
+/// or an in-memory mock. [ReqwestTransport] is used otherwise.
+pub trait HttpTransport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_>;
+}
+
+/// Transport sending requests with [reqwest]
+///
+/// Requests share one client and its connection pool unless one is given by [ReqwestTransport::with_client].
+/// Pooled connections are driven by the runtime which opened them,
+/// so give each runtime its own client if several of them send requests.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: Option<reqwest::Client>,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send every request with `client`, configured with its own timeouts, proxy or TLS settings
+    pub fn with_client(client: reqwest::Client) -> Self {
+        Self {
+            client: Some(client),
+        }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let client = match &self.client {
+                Some(client) => client.clone(),
+                None => SCOPED_CLIENT
+                    .try_with(Clone::clone)
+                    .unwrap_or_else(|_| CLIENT.get_or_init(reqwest::Client::new).clone()),
+            };
+            let mut builder = client.request(request.method, request.url);
+
+            for (name, value) in request.headers {
+                let value = HeaderValue::from_str(&value)
+                    .map_err(response_error_internal_transport_error)?;
+                builder = builder.header(name, value);
+            }
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let res = builder.send().await.map_err(response_error_from_reqwest)?;
+            let status = res.status();
+            let headers = res.headers().clone();
+            let body = res.text().await.map_err(response_error_from_reqwest)?;
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+/// Install the transport sending the request of every API instead of [ReqwestTransport]
+pub fn set_transport(transport: impl HttpTransport + 'static) {
+    *TRANSPORT.write().unwrap() = Some(Arc::new(transport));
+}
+
+/// Remove the transport installed by [set_transport]
+pub fn clear_transport() {
+    *TRANSPORT.write().unwrap() = None;
+}
+
+/// Run `future` sending every request made in it with `transport`, regardless of the installed one
+pub async fn scope<F: Future>(transport: impl HttpTransport + 'static, future: F) -> F::Output {
+    SCOPED_TRANSPORT.scope(Arc::new(transport), future).await
+}
+
+/// Send the request of every API to `base_url`, such as `http://127.0.0.1:8080`, instead of [URL_SERVER]
+pub fn set_base_url(base_url: impl Into<String>) {
+    *BASE_URL.write().unwrap() = Some(base_url.into());
+}
+
+/// Remove the base URL installed by [set_base_url]
+pub fn clear_base_url() {
+    *BASE_URL.write().unwrap() = None;
+}
+
+/// Run `future` sending every request made in it to `base_url`, regardless of the installed one
+pub async fn scope_base_url<F: Future>(base_url: impl Into<String>, future: F) -> F::Output {
+    SCOPED_BASE_URL.scope(base_url.into(), future).await
+}
+
+/// Run `future` with `client` as the client of [ReqwestTransport]s built without their own client
+#[cfg(feature = "blocking")]
+pub(crate) async fn scope_default_client<F: Future>(
+    client: reqwest::Client,
+    future: F,
+) -> F::Output {
+    SCOPED_CLIENT.scope(client, future).await
+}
+
+/// `url` under the base URL of the current [scope_base_url] or the installed one, if any
+fn rebase(url: String) -> String {
+    let base_url = SCOPED_BASE_URL
+        .try_with(Clone::clone)
+        .ok()
+        .or_else(|| BASE_URL.read().unwrap().clone());
+
+    match (base_url, url.strip_prefix(URL_SERVER)) {
+        (Some(base_url), Some(path)) => format!("{}{path}", base_url.trim_end_matches('/')),
+        _ => url,
+    }
+}
+
+/// Transport of the current [scope], the installed transport, or [ReqwestTransport::new]
+fn transport() -> Arc<dyn HttpTransport> {
+    if let Ok(transport) = SCOPED_TRANSPORT.try_with(Arc::clone) {
+        return transport;
+    }
+
+    TRANSPORT
+        .read()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(|| Arc::new(ReqwestTransport::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use reqwest::header::AUTHORIZATION;
+
+    use crate::credential::{self, Credentials};
+
+    use super::*;
+
+    /// Transport answering every request with `body`, keeping the requests sent
+    struct FixedTransport {
+        body: &'static str,
+        requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl HttpTransport for FixedTransport {
+        fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+            self.requests.lock().unwrap().push(request);
+
+            Box::pin(async move { Ok(HttpResponse::new(StatusCode::OK, self.body)) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scoped_transport() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let transport = FixedTransport {
+            body: r#"[{"currency":"KRW","balance":"1000000.0","locked":"0.0","avg_buy_price":"0","avg_buy_price_modified":false,"unit_currency":"KRW"}]"#,
+            requests: requests.clone(),
+        };
+
+        let accounts = scope(
+            transport,
+            credential::scope(
+                Credentials::new("access key", "secret key"),
+                crate::api_exchange::get_account_info(),
+            ),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(accounts[0].currency, "KRW");
+        assert_eq!(accounts[0].balance, 1000000.0);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(requests[0].url, "https://api.upbit.com/v1/accounts");
+        assert!(requests[0]
+            .headers
+            .iter()
+            .any(|(name, value)| name == AUTHORIZATION && value.starts_with("Bearer ")));
+    }
+
+    #[tokio::test]
+    async fn test_scoped_base_url() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let transport = FixedTransport {
+            body: "[]",
+            requests: requests.clone(),
+        };
+
+        scope(
+            transport,
+            scope_base_url(
+                "http://127.0.0.1:8080/",
+                crate::api_quotation::get_market_state_list(true),
+            ),
+        )
+        .await
+        .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(
+            requests[0].url,
+            "http://127.0.0.1:8080/v1/market/all?isDetails=true"
+        );
+        assert_eq!(
+            rebase("https://example.com/v1/ticker".to_owned()),
+            "https://example.com/v1/ticker"
+        );
+    }
+}
@@ -6,369 +6,380 @@ use upbit::{
     api_quotation::CandleMinute,
     api_withdraw::WithdrawState,
     constant::{OrderBy, TransactionType},
+    fixture::test_scope,
 };
 
 #[tokio::test]
 async fn test_get_account_info() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_account_info", async {
+        let account_info = upbit::api_exchange::get_account_info().await;
 
-    let account_info = upbit::api_exchange::get_account_info().await;
-
-    assert!(account_info.is_ok())
+        assert!(account_info.is_ok())
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_order_chance() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let order_chance = upbit::api_exchange::get_order_chance("KRW-ETH").await;
+    test_scope("get_order_chance", async {
+        let order_chance = upbit::api_exchange::get_order_chance("KRW-ETH").await;
 
-    assert!(order_chance.is_ok())
+        assert!(order_chance.is_ok())
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_order_bid_by_price() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let order_bid = upbit::api_exchange::order_by_price(
-        "KRW-ETH",
-        OrderSide::Bid,
-        5000.0,
-        1_435_085.0,
-        OrderType::Limit,
-        None,
-    )
+    test_scope("order_bid_by_price", async {
+        let order_bid = upbit::api_exchange::order_by_price(
+            "KRW-ETH",
+            OrderSide::Bid,
+            5000.0,
+            1_435_085.0,
+            OrderType::Limit,
+            None,
+        )
+        .await;
+
+        assert!(order_bid.is_ok())
+    })
     .await;
-
-    assert!(order_bid.is_ok())
 }
 
 #[tokio::test]
 async fn test_order_ask_by_price() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let order_ask = upbit::api_exchange::order_by_price(
-        "KRW-ETH",
-        OrderSide::Ask,
-        5000.0,
-        10_435_085.0,
-        OrderType::Limit,
-        None,
-    )
+    test_scope("order_ask_by_price", async {
+        let order_ask = upbit::api_exchange::order_by_price(
+            "KRW-ETH",
+            OrderSide::Ask,
+            5000.0,
+            10_435_085.0,
+            OrderType::Limit,
+            None,
+        )
+        .await;
+
+        assert!(order_ask.is_ok())
+    })
     .await;
-
-    assert!(order_ask.is_ok())
 }
 
 #[tokio::test]
 async fn test_get_order_status_by_uuid() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_order_status_by_uuid", async {
+        let order_status =
+            upbit::api_exchange::get_order_status_by_uuid("d60dfc8a-db0a-4087-9974-fed6433eb8f1")
+                .await;
 
-    let order_status =
-        upbit::api_exchange::get_order_status_by_uuid("d60dfc8a-db0a-4087-9974-fed6433eb8f1").await;
-
-    assert!(order_status.is_ok())
+        assert!(order_status.is_ok())
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_order_status_by_uuids() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let order_status = upbit::api_exchange::get_order_status_list_by_uuids(
-        "KRW-ETH",
-        &["d60dfc8a-db0a-4087-9974-fed6433eb8f1"],
-        OrderBy::Desc,
-    )
+    test_scope("get_order_status_by_uuids", async {
+        let order_status = upbit::api_exchange::get_order_status_list_by_uuids(
+            "KRW-ETH",
+            &["d60dfc8a-db0a-4087-9974-fed6433eb8f1"],
+            OrderBy::Desc,
+        )
+        .await;
+
+        assert!(order_status.is_ok())
+    })
     .await;
-
-    assert!(order_status.is_ok())
 }
 
 #[tokio::test]
 async fn test_get_order_status_opened() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let order_open = upbit::api_exchange::get_order_status_list_opened(
-        "KRW-ETH",
-        &[OrderState::Wait],
-        1,
-        10,
-        OrderBy::Desc,
-    )
+    test_scope("get_order_status_opened", async {
+        let order_open = upbit::api_exchange::get_order_status_list_opened(
+            "KRW-ETH",
+            &[OrderState::Wait],
+            1,
+            10,
+            OrderBy::Desc,
+        )
+        .await;
+
+        assert!(order_open.is_ok())
+    })
     .await;
-
-    assert!(order_open.is_ok())
 }
 
 #[tokio::test]
 async fn test_get_order_status_closed() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let order_closed = upbit::api_exchange::get_order_status_list_closed(
-        "KRW-ETH",
-        &[OrderState::Done],
-        None,
-        None,
-        10,
-        OrderBy::Desc,
-    )
+    test_scope("get_order_status_closed", async {
+        let order_closed = upbit::api_exchange::get_order_status_list_closed(
+            "KRW-ETH",
+            &[OrderState::Done],
+            None,
+            None,
+            10,
+            OrderBy::Desc,
+        )
+        .await;
+
+        assert!(order_closed.is_ok())
+    })
     .await;
-
-    assert!(order_closed.is_ok())
 }
 
 #[tokio::test]
 async fn test_get_candle_of_minute() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_candle_of_minute", async {
+        let candle_1 =
+            upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min1)
+                .await;
 
-    let candle_1 =
-        upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min1).await;
+        assert!(candle_1.is_ok());
 
-    assert!(candle_1.is_ok());
+        let candle_3 =
+            upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min3)
+                .await;
 
-    let candle_3 =
-        upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min3).await;
+        assert!(candle_3.is_ok());
 
-    assert!(candle_3.is_ok());
+        let candle_5 =
+            upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min5)
+                .await;
 
-    let candle_5 =
-        upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min5).await;
+        assert!(candle_5.is_ok());
 
-    assert!(candle_5.is_ok());
+        let candle_10 =
+            upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min10)
+                .await;
 
-    let candle_10 =
-        upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min10).await;
+        assert!(candle_10.is_ok());
 
-    assert!(candle_10.is_ok());
+        let candle_15 =
+            upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min15)
+                .await;
 
-    let candle_15 =
-        upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min15).await;
+        assert!(candle_15.is_ok());
 
-    assert!(candle_15.is_ok());
+        let candle_30 =
+            upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min30)
+                .await;
 
-    let candle_30 =
-        upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min30).await;
+        assert!(candle_30.is_ok());
 
-    assert!(candle_30.is_ok());
+        let candle_60 =
+            upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min60)
+                .await;
 
-    let candle_60 =
-        upbit::api_quotation::get_candle_minute_list("KRW-ETH", None, 1, CandleMinute::Min60).await;
+        assert!(candle_60.is_ok());
 
-    assert!(candle_60.is_ok());
+        // let candle_240 = upbit::api_quotation::get_candle_minute("KRW-ETH", None, 1, CandleMinute::Min240).await;
 
-    // let candle_240 = upbit::api_quotation::get_candle_minute("KRW-ETH", None, 1, CandleMinute::Min240).await;
-
-    // assert!(candle_240.is_ok());
+        // assert!(candle_240.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_candle_of_day() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let candle = upbit::api_quotation::get_candle_day_list("KRW-ETH", 1, None, None).await;
+    test_scope("get_candle_of_day", async {
+        let candle = upbit::api_quotation::get_candle_day_list("KRW-ETH", 1, None, None).await;
 
-    assert!(candle.is_ok())
+        assert!(candle.is_ok())
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_candle_of_week() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let candle = upbit::api_quotation::get_candle_week_list("KRW-ETH", 1, None).await;
+    test_scope("get_candle_of_week", async {
+        let candle = upbit::api_quotation::get_candle_week_list("KRW-ETH", 1, None).await;
 
-    assert!(candle.is_ok())
+        assert!(candle.is_ok())
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_candle_of_month() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let candle = upbit::api_quotation::get_candle_month_list("KRW-ETH", 1, None).await;
+    test_scope("get_candle_of_month", async {
+        let candle = upbit::api_quotation::get_candle_month_list("KRW-ETH", 1, None).await;
 
-    assert!(candle.is_ok())
+        assert!(candle.is_ok())
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_market_state() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_market_state", async {
+        let state = upbit::api_quotation::get_market_state_list(true).await;
 
-    let state = upbit::api_quotation::get_market_state_list(true).await;
-
-    assert!(state.is_ok())
+        assert!(state.is_ok())
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_ticker_snapshot() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_ticker_snapshot", async {
+        let list = upbit::api_quotation::get_ticker_snapshot_list(&["KRW-ETH"]).await;
 
-    let list = upbit::api_quotation::get_ticker_snapshot_list(&["KRW-ETH"]).await;
+        assert!(list.is_ok());
 
-    assert!(list.is_ok());
+        let list_bunch =
+            upbit::api_quotation::get_ticker_snapshot_list(&["KRW-BTC", "KRW-ETH"]).await;
 
-    let list_bunch = upbit::api_quotation::get_ticker_snapshot_list(&["KRW-BTC", "KRW-ETH"]).await;
-
-    assert!(list_bunch.is_ok());
+        assert!(list_bunch.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_trade_recent_list() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let list =
-        upbit::api_quotation::get_trade_recent_list("KRW-ETH", Some("120101"), 1, "0", None).await;
+    test_scope("get_trade_recent_list", async {
+        let list =
+            upbit::api_quotation::get_trade_recent_list("KRW-ETH", Some("120101"), 1, "0", None)
+                .await;
 
-    assert!(list.is_ok());
+        assert!(list.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_order_book() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let order_book = upbit::api_quotation::get_order_book_info_list(&["KRW-ETH"]).await;
+    test_scope("get_order_book", async {
+        let order_book = upbit::api_quotation::get_order_book_info_list(&["KRW-ETH"]).await;
 
-    assert!(order_book.is_ok());
+        assert!(order_book.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_withdraw_address() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_withdraw_address", async {
+        let address = upbit::api_withdraw::get_withdraw_address_list().await;
 
-    let address = upbit::api_withdraw::get_withdraw_address_list().await;
-
-    assert!(address.is_ok());
+        assert!(address.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_withdraw_chance() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let chance = upbit::api_withdraw::get_withdraw_chance("ETH", "ETH").await;
+    test_scope("get_withdraw_chance", async {
+        let chance = upbit::api_withdraw::get_withdraw_chance("ETH", "ETH").await;
 
-    assert!(chance.is_ok());
+        assert!(chance.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_withdraw_coin() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let info = upbit::api_withdraw::withdraw_coin(
-        "ETH",
-        "ETH",
-        0.02,
-        "0x40268F1e99F76b658c6D52d89166EE289EfC225d",
-        None,
-        TransactionType::Default,
-    )
+    test_scope("withdraw_coin", async {
+        let info = upbit::api_withdraw::withdraw_coin(
+            "ETH",
+            "ETH",
+            0.02,
+            "0x40268F1e99F76b658c6D52d89166EE289EfC225d",
+            None,
+            TransactionType::Default,
+        )
+        .await;
+
+        assert!(info.is_ok());
+    })
     .await;
-
-    assert!(info.is_ok());
 }
 
 #[tokio::test]
 async fn test_get_withdraw_info() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_withdraw_info", async {
+        let info = upbit::api_withdraw::get_withdraw_info(Some("KRW"), None, None).await;
 
-    let info = upbit::api_withdraw::get_withdraw_info(Some("KRW"), None, None).await;
+        assert!(info.is_ok());
 
-    assert!(info.is_ok());
+        let uuid = info.unwrap().uuid;
 
-    let uuid = info.unwrap().uuid;
+        let info = upbit::api_withdraw::get_withdraw_info(None, Some(&uuid), None).await;
 
-    let info = upbit::api_withdraw::get_withdraw_info(None, Some(&uuid), None).await;
+        assert!(info.is_ok());
 
-    assert!(info.is_ok());
+        let txid = info.unwrap().txid;
 
-    let txid = info.unwrap().txid;
+        let info = upbit::api_withdraw::get_withdraw_info(None, None, Some(&txid)).await;
 
-    let info = upbit::api_withdraw::get_withdraw_info(None, None, Some(&txid)).await;
-
-    assert!(info.is_ok());
+        assert!(info.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_withdraw_list() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let infos = upbit::api_withdraw::get_withdraw_info_list(
-        "KRW",
-        WithdrawState::Done,
-        None,
-        None,
-        10,
-        0,
-        OrderBy::Asc,
-    )
+    test_scope("get_withdraw_list", async {
+        let infos = upbit::api_withdraw::get_withdraw_info_list(
+            "KRW",
+            WithdrawState::Done,
+            None,
+            None,
+            10,
+            0,
+            OrderBy::Asc,
+        )
+        .await;
+
+        assert!(infos.is_ok());
+    })
     .await;
-
-    assert!(infos.is_ok());
 }
 
 #[tokio::test]
 async fn test_get_deposit_info() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_deposit_info", async {
+        let info = upbit::api_deposit::get_deposit_info_by_currency("KRW").await;
 
-    let info = upbit::api_deposit::get_deposit_info_by_currency("KRW").await;
-
-    assert!(info.is_ok());
+        assert!(info.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_deposit_list() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let infos = upbit::api_deposit::get_deposit_info_list(
-        "KRW",
-        DepositState::Accepted,
-        None,
-        None,
-        10,
-        0,
-        OrderBy::Asc,
-    )
+    test_scope("get_deposit_list", async {
+        let infos = upbit::api_deposit::get_deposit_info_list(
+            "KRW",
+            DepositState::Accepted,
+            None,
+            None,
+            10,
+            0,
+            OrderBy::Asc,
+        )
+        .await;
+
+        assert!(infos.is_ok());
+    })
     .await;
-
-    assert!(infos.is_ok());
 }
 
 #[tokio::test]
 async fn test_get_coin_address_info() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
-
-    let info = upbit::api_deposit::get_coin_address_info("ETH", "ETH").await;
+    test_scope("get_coin_address_info", async {
+        let info = upbit::api_deposit::get_coin_address_info("ETH", "ETH").await;
 
-    assert!(info.is_ok());
+        assert!(info.is_ok());
+    })
+    .await;
 }
 
 #[tokio::test]
 async fn test_get_coin_address_list() {
-    upbit::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-    upbit::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_ACCESS_KEY not set"));
+    test_scope("get_coin_address_list", async {
+        let infos = upbit::api_deposit::get_coin_address_info_list().await;
 
-    let infos = upbit::api_deposit::get_coin_address_info_list().await;
-
-    assert!(infos.is_ok());
+        assert!(infos.is_ok());
+    })
+    .await;
 }